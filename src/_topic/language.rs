@@ -136,7 +136,7 @@
 #![doc = include_str!("../../examples/string/parser.rs")]
 //! ```
 //!
-//! See also [`take_escaped`] and [`escaped`].
+//! See also [`take_escaped`], [`escaped`] and [`escaped_cow`].
 //!
 //! ### Integers
 //!
@@ -315,12 +315,15 @@
 //! }
 //! ```
 //!
-//! See also [`float`]
+//! See also [`float`] and [`decimal`]
 
 #![allow(unused_imports)]
 use crate::ascii::dec_int;
 use crate::ascii::dec_uint;
+use crate::ascii::decimal;
 use crate::ascii::escaped;
+#[cfg(feature = "alloc")]
+use crate::ascii::escaped_cow;
 use crate::ascii::float;
 use crate::ascii::hex_uint;
 use crate::ascii::take_escaped;
@@ -18,6 +18,11 @@
 //! If the chunks are not homogeneous, a state machine will be needed to track what the expected
 //! parser is for the next chunk.
 //!
+//! For data coming from a [`std::io::Read`], [`ReadIterator`] manages the buffer for you,
+//! reading more whenever a parser reports [`Incomplete`] and marking the input as complete once
+//! the reader is exhausted.  With the `futures-io` feature, [`AsyncReadStream`] does the same for
+//! an `AsyncRead`.
+//!
 //! Caveats:
 //! - `winnow` takes the approach of re-parsing from scratch. Chunks should be relatively small to
 //!   prevent the re-parsing overhead from dominating.
@@ -42,5 +47,7 @@ use crate::binary::length_and_then;
 use crate::combinator::repeat;
 use crate::error::ErrMode::Incomplete;
 use crate::error::Needed;
+use crate::io::AsyncReadStream;
+use crate::io::ReadIterator;
 use crate::stream::Partial;
 use crate::stream::StreamIsPartial;
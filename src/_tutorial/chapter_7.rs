@@ -566,8 +566,9 @@
 //!
 //! ## Error Adaptation and Rendering
 //!
-//! While Winnow can provide basic rendering of errors, your application can have various demands
-//! beyond the basics provided like
+//! While Winnow can provide basic rendering of errors, including
+//! [rustc-like snippets][crate::error::ParseError::snippet], your application can have various
+//! demands beyond the basics provided like
 //! - Correctly reporting columns with unicode
 //! - Conforming to a specific layout
 //!
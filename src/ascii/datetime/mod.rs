@@ -0,0 +1,693 @@
+//! Date, time and duration parsers
+//!
+//! These recognize the textual formats, producing plain structs with the fields as written, for
+//! conversion into the date and time types of your choice.
+
+#[cfg(test)]
+mod tests;
+
+use crate::ascii::dec_uint;
+use crate::combinator::alt;
+use crate::combinator::fail;
+use crate::combinator::opt;
+use crate::combinator::preceded;
+use crate::combinator::terminated;
+use crate::combinator::trace;
+use crate::error::{AddContext, ParserError, StrContext, StrContextValue};
+use crate::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
+use crate::token::one_of;
+use crate::token::take_while;
+use crate::Parser;
+use crate::Result;
+
+/// A calendar date
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    /// `0..=9999`
+    pub year: u16,
+    /// `1..=12`
+    pub month: u8,
+    /// `1..=31`, valid for the month and year
+    pub day: u8,
+}
+
+/// A time of day
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    /// `0..=23`
+    pub hour: u8,
+    /// `0..=59`
+    pub minute: u8,
+    /// `0..=60`, with `60` for a leap second
+    pub second: u8,
+    /// `0..1_000_000_000`, from the fraction of a second truncated to 9 digits
+    pub nanosecond: u32,
+}
+
+/// A date and time with an offset from UTC, see [`rfc3339`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    /// The local date
+    pub date: Date,
+    /// The local time
+    pub time: Time,
+    /// Minutes east of UTC, see [`rfc3339_offset`]
+    pub offset: i16,
+}
+
+/// A duration split into its designated components, see [`iso8601_duration`]
+///
+/// Components are kept as written, e.g. `PT90M` has 90 `minutes` and no `hours`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Duration {
+    /// `nY`
+    pub years: u32,
+    /// `nM` before the `T`
+    pub months: u32,
+    /// `nW`
+    pub weeks: u32,
+    /// `nD`
+    pub days: u32,
+    /// `nH`
+    pub hours: u32,
+    /// `nM` after the `T`
+    pub minutes: u32,
+    /// `nS`
+    pub seconds: u32,
+    /// `0..1_000_000_000`, from the fraction of `seconds` truncated to 9 digits
+    pub nanoseconds: u32,
+}
+
+/// A day of the week, see [`http_date`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    /// `Mon`
+    Monday,
+    /// `Tue`
+    Tuesday,
+    /// `Wed`
+    Wednesday,
+    /// `Thu`
+    Thursday,
+    /// `Fri`
+    Friday,
+    /// `Sat`
+    Saturday,
+    /// `Sun`
+    Sunday,
+}
+
+/// An HTTP date, see [`http_date`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HttpDate {
+    /// The day of the week, as written
+    pub weekday: Weekday,
+    /// The date in UTC
+    pub date: Date,
+    /// The time in UTC, without fractional seconds
+    pub time: Time,
+}
+
+/// Recognizes an [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339#section-5.6) `date-time`,
+/// like `1985-04-12T23:20:50.52Z`
+///
+/// The `T` may also be a lowercase `t` or a space, as allowed by the RFC.
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::ascii::datetime::DateTime;
+/// pub fn rfc3339(input: &mut &str) -> ModalResult<DateTime>
+/// # {
+/// #     winnow::ascii::datetime::rfc3339.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::datetime::{rfc3339, Date, DateTime, Time};
+///
+/// fn parser(input: &mut &str) -> ModalResult<DateTime> {
+///     rfc3339.parse_next(input)
+/// }
+///
+/// assert_eq!(
+///     parser.parse_peek("1996-12-19T16:39:57-08:00"),
+///     Ok(("", DateTime {
+///         date: Date { year: 1996, month: 12, day: 19 },
+///         time: Time { hour: 16, minute: 39, second: 57, nanosecond: 0 },
+///         offset: -8 * 60,
+///     }))
+/// );
+/// assert_eq!(
+///     parser.parse_peek("1990-12-31 23:59:60.5Z;"),
+///     Ok((";", DateTime {
+///         date: Date { year: 1990, month: 12, day: 31 },
+///         time: Time { hour: 23, minute: 59, second: 60, nanosecond: 500_000_000 },
+///         offset: 0,
+///     }))
+/// );
+/// assert!(parser.parse_peek("2023-02-29T00:00:00Z").is_err());
+/// assert!(parser.parse_peek("2023-01-01T00:00:00").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::Partial;
+/// # use winnow::ascii::datetime::rfc3339;
+/// assert_eq!(
+///     rfc3339::<_, ErrMode<ContextError>>.parse_peek(Partial::new("1985-04-12T23:20:50")),
+///     Err(ErrMode::Incomplete(Needed::Unknown))
+/// );
+/// ```
+pub fn rfc3339<Input, Error>(input: &mut Input) -> Result<DateTime, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<char>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("rfc3339", |input: &mut Input| {
+        let date = rfc3339_date(input)?;
+        one_of(['T', 't', ' '])
+            .context(StrContext::Expected(StrContextValue::CharLiteral('T')))
+            .parse_next(input)?;
+        let time = rfc3339_time(input)?;
+        let offset = rfc3339_offset(input)?;
+        Ok(DateTime { date, time, offset })
+    })
+    .parse_next(input)
+}
+
+/// Recognizes an [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339#section-5.6) `full-date`,
+/// like `1985-04-12`
+///
+/// The day is checked against the month, including leap years.
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::ascii::datetime::Date;
+/// pub fn rfc3339_date(input: &mut &str) -> ModalResult<Date>
+/// # {
+/// #     winnow::ascii::datetime::rfc3339_date.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::datetime::{rfc3339_date, Date};
+///
+/// fn parser(input: &mut &str) -> ModalResult<Date> {
+///     rfc3339_date.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("2024-02-29"), Ok(("", Date { year: 2024, month: 2, day: 29 })));
+/// assert!(parser.parse_peek("2024-13-01").is_err());
+/// assert!(parser.parse_peek("2024-2-1").is_err());
+/// ```
+pub fn rfc3339_date<Input, Error>(input: &mut Input) -> Result<Date, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<char>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("rfc3339_date", |input: &mut Input| {
+        let year = digits(4)
+            .context(StrContext::Label("year"))
+            .parse_next(input)?;
+        '-'.parse_next(input)?;
+        let month = digits(2)
+            .verify(|month: &u32| (1..=12).contains(month))
+            .context(StrContext::Label("month"))
+            .parse_next(input)?;
+        '-'.parse_next(input)?;
+        let day = digits(2)
+            .verify(|day: &u32| (1..=days_in_month(year, month)).contains(day))
+            .context(StrContext::Label("day"))
+            .parse_next(input)?;
+        Ok(Date {
+            year: year as u16,
+            month: month as u8,
+            day: day as u8,
+        })
+    })
+    .parse_next(input)
+}
+
+/// Recognizes an [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339#section-5.6) `partial-time`,
+/// like `23:20:50.52`
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::ascii::datetime::Time;
+/// pub fn rfc3339_time(input: &mut &str) -> ModalResult<Time>
+/// # {
+/// #     winnow::ascii::datetime::rfc3339_time.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::datetime::{rfc3339_time, Time};
+///
+/// fn parser(input: &mut &str) -> ModalResult<Time> {
+///     rfc3339_time.parse_next(input)
+/// }
+///
+/// assert_eq!(
+///     parser.parse_peek("08:49:37.0123456789"),
+///     Ok(("", Time { hour: 8, minute: 49, second: 37, nanosecond: 12_345_678 }))
+/// );
+/// assert!(parser.parse_peek("24:00:00").is_err());
+/// assert!(parser.parse_peek("08:49").is_err());
+/// ```
+pub fn rfc3339_time<Input, Error>(input: &mut Input) -> Result<Time, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<char>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("rfc3339_time", |input: &mut Input| {
+        let mut time = hms(input)?;
+        if let Some(nanosecond) = opt(preceded('.', nanoseconds)).parse_next(input)? {
+            time.nanosecond = nanosecond;
+        }
+        Ok(time)
+    })
+    .parse_next(input)
+}
+
+/// Recognizes an [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339#section-5.6) `time-offset`,
+/// like `Z` or `-08:00`, returning minutes east of UTC
+///
+/// `-00:00`, for an unknown local offset, is reported like `Z`.
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn rfc3339_offset(input: &mut &str) -> ModalResult<i16>
+/// # {
+/// #     winnow::ascii::datetime::rfc3339_offset.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::datetime::rfc3339_offset;
+///
+/// fn parser(input: &mut &str) -> ModalResult<i16> {
+///     rfc3339_offset.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("z"), Ok(("", 0)));
+/// assert_eq!(parser.parse_peek("+05:30"), Ok(("", 330)));
+/// assert!(parser.parse_peek("+0530").is_err());
+/// ```
+pub fn rfc3339_offset<Input, Error>(input: &mut Input) -> Result<i16, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<char>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("rfc3339_offset", |input: &mut Input| {
+        let sign = one_of(['Z', 'z', '+', '-'])
+            .context(StrContext::Label("offset"))
+            .context(StrContext::Expected(StrContextValue::CharLiteral('Z')))
+            .context(StrContext::Expected(StrContextValue::CharLiteral('+')))
+            .context(StrContext::Expected(StrContextValue::CharLiteral('-')))
+            .parse_next(input)?
+            .as_char();
+        if matches!(sign, 'Z' | 'z') {
+            return Ok(0);
+        }
+        let hours = digits(2)
+            .verify(|hour: &u32| *hour < 24)
+            .context(StrContext::Label("offset hour"))
+            .parse_next(input)?;
+        ':'.parse_next(input)?;
+        let minutes = digits(2)
+            .verify(|minute: &u32| *minute < 60)
+            .context(StrContext::Label("offset minute"))
+            .parse_next(input)?;
+        let offset = (hours * 60 + minutes) as i16;
+        Ok(if sign == '-' { -offset } else { offset })
+    })
+    .parse_next(input)
+}
+
+/// Recognizes an [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601#Durations) duration, like
+/// `P1Y2M10DT2H30M` or `P3W`
+///
+/// Components must be in order (`Y`, `M`, `W`, `D`, then after `T`: `H`, `M`, `S`) with at least
+/// one present. Only seconds may have a fraction, after a `.` or `,`.
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::ascii::datetime::Duration;
+/// pub fn iso8601_duration(input: &mut &str) -> ModalResult<Duration>
+/// # {
+/// #     winnow::ascii::datetime::iso8601_duration.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::datetime::{iso8601_duration, Duration};
+///
+/// fn parser(input: &mut &str) -> ModalResult<Duration> {
+///     iso8601_duration.parse_next(input)
+/// }
+///
+/// assert_eq!(
+///     parser.parse_peek("P1Y2M10DT2H30M"),
+///     Ok(("", Duration { years: 1, months: 2, days: 10, hours: 2, minutes: 30, ..Default::default() }))
+/// );
+/// assert_eq!(
+///     parser.parse_peek("PT0,5S"),
+///     Ok(("", Duration { nanoseconds: 500_000_000, ..Default::default() }))
+/// );
+/// assert!(parser.parse_peek("P").is_err());
+/// assert!(parser.parse_peek("P1DT").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::Partial;
+/// # use winnow::ascii::datetime::{iso8601_duration, Duration};
+/// assert_eq!(
+///     iso8601_duration::<_, ErrMode<ContextError>>.parse_peek(Partial::new("P3W ")),
+///     Ok((Partial::new(" "), Duration { weeks: 3, ..Default::default() }))
+/// );
+/// assert_eq!(
+///     iso8601_duration::<_, ErrMode<ContextError>>.parse_peek(Partial::new("P3W")),
+///     Err(ErrMode::Incomplete(Needed::new(1)))
+/// );
+/// ```
+pub fn iso8601_duration<Input, Error>(input: &mut Input) -> Result<Duration, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<char>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("iso8601_duration", |input: &mut Input| {
+        'P'.context(StrContext::Expected(StrContextValue::CharLiteral('P')))
+            .parse_next(input)?;
+        let years = component('Y', "years").parse_next(input)?;
+        let months = component('M', "months").parse_next(input)?;
+        let weeks = component('W', "weeks").parse_next(input)?;
+        let days = component('D', "days").parse_next(input)?;
+        let mut duration = Duration {
+            years: years.unwrap_or(0),
+            months: months.unwrap_or(0),
+            weeks: weeks.unwrap_or(0),
+            days: days.unwrap_or(0),
+            ..Default::default()
+        };
+        let mut found = years.or(months).or(weeks).or(days).is_some();
+
+        if opt('T').parse_next(input)?.is_some() {
+            let hours = component('H', "hours").parse_next(input)?;
+            let minutes = component('M', "minutes").parse_next(input)?;
+            let seconds = opt(terminated(
+                (dec_uint, opt(preceded(one_of(['.', ',']), nanoseconds))),
+                'S',
+            ))
+            .context(StrContext::Label("seconds"))
+            .parse_next(input)?;
+            duration.hours = hours.unwrap_or(0);
+            duration.minutes = minutes.unwrap_or(0);
+            if let Some((seconds, nanoseconds)) = seconds {
+                duration.seconds = seconds;
+                duration.nanoseconds = nanoseconds.unwrap_or(0);
+            }
+            if hours.or(minutes).is_none() && seconds.is_none() {
+                return fail
+                    .context(StrContext::Expected(StrContextValue::Description(
+                        "time component",
+                    )))
+                    .parse_next(input);
+            }
+            found = true;
+        }
+
+        if !found {
+            return fail
+                .context(StrContext::Expected(StrContextValue::Description(
+                    "duration component",
+                )))
+                .parse_next(input);
+        }
+        Ok(duration)
+    })
+    .parse_next(input)
+}
+
+/// Recognizes an [IMF-fixdate](https://www.rfc-editor.org/rfc/rfc9110#section-5.6.7), the
+/// preferred HTTP date format, like `Sun, 06 Nov 1994 08:49:37 GMT`
+///
+/// Names are case-sensitive. The weekday is reported as written, without checking it against the
+/// date.
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::ascii::datetime::HttpDate;
+/// pub fn http_date(input: &mut &str) -> ModalResult<HttpDate>
+/// # {
+/// #     winnow::ascii::datetime::http_date.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::datetime::{http_date, Date, HttpDate, Time, Weekday};
+///
+/// fn parser(input: &mut &str) -> ModalResult<HttpDate> {
+///     http_date.parse_next(input)
+/// }
+///
+/// assert_eq!(
+///     parser.parse_peek("Sun, 06 Nov 1994 08:49:37 GMT"),
+///     Ok(("", HttpDate {
+///         weekday: Weekday::Sunday,
+///         date: Date { year: 1994, month: 11, day: 6 },
+///         time: Time { hour: 8, minute: 49, second: 37, nanosecond: 0 },
+///     }))
+/// );
+/// assert!(parser.parse_peek("Sunday, 06-Nov-94 08:49:37 GMT").is_err());
+/// assert!(parser.parse_peek("Sun, 31 Nov 1994 08:49:37 GMT").is_err());
+/// ```
+pub fn http_date<Input, Error>(input: &mut Input) -> Result<HttpDate, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<char> + Compare<&'static str>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("http_date", |input: &mut Input| {
+        let weekday = alt((
+            "Mon".value(Weekday::Monday),
+            "Tue".value(Weekday::Tuesday),
+            "Wed".value(Weekday::Wednesday),
+            "Thu".value(Weekday::Thursday),
+            "Fri".value(Weekday::Friday),
+            "Sat".value(Weekday::Saturday),
+            "Sun".value(Weekday::Sunday),
+        ))
+        .context(StrContext::Label("weekday"))
+        .parse_next(input)?;
+        ", ".parse_next(input)?;
+        let day_start = input.checkpoint();
+        let day = digits(2)
+            .verify(|day: &u32| (1..=31).contains(day))
+            .context(StrContext::Label("day"))
+            .parse_next(input)?;
+        ' '.parse_next(input)?;
+        let month = alt((
+            "Jan".value(1),
+            "Feb".value(2),
+            "Mar".value(3),
+            "Apr".value(4),
+            "May".value(5),
+            "Jun".value(6),
+            "Jul".value(7),
+            "Aug".value(8),
+            "Sep".value(9),
+            "Oct".value(10),
+            "Nov".value(11),
+            "Dec".value(12),
+        ))
+        .context(StrContext::Label("month"))
+        .parse_next(input)?;
+        ' '.parse_next(input)?;
+        let year = digits(4)
+            .context(StrContext::Label("year"))
+            .parse_next(input)?;
+        if days_in_month(year, month) < day {
+            input.reset(&day_start);
+            return fail.context(StrContext::Label("day")).parse_next(input);
+        }
+        ' '.parse_next(input)?;
+        let time = hms(input)?;
+        " GMT".parse_next(input)?;
+        Ok(HttpDate {
+            weekday,
+            date: Date {
+                year: year as u16,
+                month: month as u8,
+                day: day as u8,
+            },
+            time,
+        })
+    })
+    .parse_next(input)
+}
+
+/// `hh:mm:ss`
+fn hms<Input, Error>(input: &mut Input) -> Result<Time, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<char>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    let hour = digits(2)
+        .verify(|hour: &u32| *hour < 24)
+        .context(StrContext::Label("hour"))
+        .parse_next(input)?;
+    ':'.parse_next(input)?;
+    let minute = digits(2)
+        .verify(|minute: &u32| *minute < 60)
+        .context(StrContext::Label("minute"))
+        .parse_next(input)?;
+    ':'.parse_next(input)?;
+    let second = digits(2)
+        .verify(|second: &u32| *second <= 60)
+        .context(StrContext::Label("second"))
+        .parse_next(input)?;
+    Ok(Time {
+        hour: hour as u8,
+        minute: minute as u8,
+        second: second as u8,
+        nanosecond: 0,
+    })
+}
+
+/// Exactly `width` decimal digits
+fn digits<Input, Error>(width: usize) -> impl Parser<Input, u32, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    take_while(width, AsChar::is_dec_digit).map(|digits: <Input as Stream>::Slice| {
+        digits
+            .as_bstr()
+            .iter()
+            .fold(0, |value, digit| value * 10 + u32::from(digit - b'0'))
+    })
+}
+
+/// A fraction of a second, as nanoseconds
+fn nanoseconds<Input, Error>(input: &mut Input) -> Result<u32, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    take_while(1.., AsChar::is_dec_digit)
+        .map(|digits: <Input as Stream>::Slice| {
+            let digits = digits.as_bstr();
+            (0..9).fold(0, |value, i| {
+                value * 10
+                    + digits
+                        .get(i)
+                        .map(|digit| u32::from(digit - b'0'))
+                        .unwrap_or(0)
+            })
+        })
+        .context(StrContext::Label("fraction"))
+        .parse_next(input)
+}
+
+/// An optional `<number><designator>` of a duration
+fn component<Input, Error>(
+    designator: char,
+    label: &'static str,
+) -> impl Parser<Input, Option<u32>, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<char>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    opt(terminated(dec_uint, designator)).context(StrContext::Label(label))
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
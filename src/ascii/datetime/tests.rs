@@ -0,0 +1,299 @@
+use super::*;
+use crate::error::ErrMode;
+use crate::error::InputError;
+use crate::prelude::*;
+use crate::Partial;
+
+const DATE: Date = Date {
+    year: 1985,
+    month: 4,
+    day: 12,
+};
+
+#[test]
+fn rfc3339_complete() {
+    fn parser<'i>(input: &mut &'i str) -> ModalResult<DateTime, InputError<&'i str>> {
+        rfc3339.parse_next(input)
+    }
+
+    assert_eq!(
+        parser.parse_peek("1985-04-12T23:20:50.52Z"),
+        Ok((
+            "",
+            DateTime {
+                date: DATE,
+                time: Time {
+                    hour: 23,
+                    minute: 20,
+                    second: 50,
+                    nanosecond: 520_000_000
+                },
+                offset: 0
+            }
+        ))
+    );
+    assert_eq!(
+        parser.parse_peek("1937-01-01t12:00:27.87+00:20 rest"),
+        Ok((
+            " rest",
+            DateTime {
+                date: Date {
+                    year: 1937,
+                    month: 1,
+                    day: 1
+                },
+                time: Time {
+                    hour: 12,
+                    minute: 0,
+                    second: 27,
+                    nanosecond: 870_000_000
+                },
+                offset: 20
+            }
+        ))
+    );
+    for input in [
+        "",
+        "1985-04-12",
+        "1985-04-12T",
+        "1985-04-12T23:20:50",
+        "1985-04-12T23:20:50.Z",
+        "1985-04-12T23:20:50+01",
+        "1985-04-12T23:20:50+24:00",
+        "1985-04-12X23:20:50Z",
+        "1985-00-12T23:20:50Z",
+        "1985-04-31T23:20:50Z",
+        "1985-04-12T23:60:50Z",
+        "1985-04-12T23:20:61Z",
+        "85-04-12T23:20:50Z",
+    ] {
+        assert!(parser.parse_peek(input).is_err(), "{input:?}");
+    }
+}
+
+#[test]
+fn rfc3339_bytes() {
+    fn parser<'i>(input: &mut &'i [u8]) -> ModalResult<DateTime, InputError<&'i [u8]>> {
+        rfc3339.parse_next(input)
+    }
+
+    assert_eq!(
+        parser.parse_peek(b"1985-04-12 23:20:50.000000000123-00:30"),
+        Ok((
+            &b""[..],
+            DateTime {
+                date: DATE,
+                time: Time {
+                    hour: 23,
+                    minute: 20,
+                    second: 50,
+                    nanosecond: 0
+                },
+                offset: -30
+            }
+        ))
+    );
+}
+
+#[test]
+fn leap_years() {
+    fn parser<'i>(input: &mut &'i str) -> ModalResult<Date, InputError<&'i str>> {
+        rfc3339_date.parse_next(input)
+    }
+
+    assert!(parser.parse_peek("2000-02-29").is_ok());
+    assert!(parser.parse_peek("2024-02-29").is_ok());
+    assert!(parser.parse_peek("1900-02-29").is_err());
+    assert!(parser.parse_peek("2023-02-29").is_err());
+}
+
+#[test]
+fn rfc3339_partial() {
+    fn parser<'i>(
+        input: &mut Partial<&'i str>,
+    ) -> ModalResult<DateTime, InputError<Partial<&'i str>>> {
+        rfc3339.parse_next(input)
+    }
+
+    for input in [
+        "",
+        "1985",
+        "1985-04-1",
+        "1985-04-12",
+        "1985-04-12T23:20",
+        "1985-04-12T23:20:50",
+        "1985-04-12T23:20:50.52",
+        "1985-04-12T23:20:50+01:",
+    ] {
+        assert!(
+            matches!(
+                parser.parse_peek(Partial::new(input)),
+                Err(ErrMode::Incomplete(_))
+            ),
+            "{input:?}"
+        );
+    }
+    assert_eq!(
+        parser.parse_peek(Partial::new("1985-04-12T23:20:50+01:00")),
+        Ok((
+            Partial::new(""),
+            DateTime {
+                date: DATE,
+                time: Time {
+                    hour: 23,
+                    minute: 20,
+                    second: 50,
+                    nanosecond: 0
+                },
+                offset: 60
+            }
+        ))
+    );
+}
+
+#[test]
+fn iso8601_duration_complete() {
+    fn parser<'i>(input: &mut &'i str) -> ModalResult<Duration, InputError<&'i str>> {
+        iso8601_duration.parse_next(input)
+    }
+
+    assert_eq!(
+        parser.parse_peek("P1Y2M3W4DT5H6M7.25S"),
+        Ok((
+            "",
+            Duration {
+                years: 1,
+                months: 2,
+                weeks: 3,
+                days: 4,
+                hours: 5,
+                minutes: 6,
+                seconds: 7,
+                nanoseconds: 250_000_000
+            }
+        ))
+    );
+    assert_eq!(
+        parser.parse_peek("PT36H"),
+        Ok((
+            "",
+            Duration {
+                hours: 36,
+                ..Default::default()
+            }
+        ))
+    );
+    assert_eq!(parser.parse_peek("P0D"), Ok(("", Duration::default())));
+    assert_eq!(
+        parser.parse_peek("P1M2Y"),
+        Ok((
+            "2Y",
+            Duration {
+                months: 1,
+                ..Default::default()
+            }
+        ))
+    );
+    for input in ["", "P", "PT", "P1", "P1DT", "P1.5D", "PT1.S", "1D"] {
+        assert!(parser.parse_peek(input).is_err(), "{input:?}");
+    }
+}
+
+#[test]
+fn iso8601_duration_partial() {
+    fn parser<'i>(
+        input: &mut Partial<&'i str>,
+    ) -> ModalResult<Duration, InputError<Partial<&'i str>>> {
+        iso8601_duration.parse_next(input)
+    }
+
+    for input in ["", "P", "P1", "P1D", "P1DT", "PT1", "PT1.5"] {
+        assert!(
+            matches!(
+                parser.parse_peek(Partial::new(input)),
+                Err(ErrMode::Incomplete(_))
+            ),
+            "{input:?}"
+        );
+    }
+    assert_eq!(
+        parser.parse_peek(Partial::new("PT1S;")),
+        Ok((
+            Partial::new(";"),
+            Duration {
+                seconds: 1,
+                ..Default::default()
+            }
+        ))
+    );
+}
+
+#[test]
+fn http_date_complete() {
+    fn parser<'i>(input: &mut &'i str) -> ModalResult<HttpDate, InputError<&'i str>> {
+        http_date.parse_next(input)
+    }
+
+    assert_eq!(
+        parser.parse_peek("Fri, 29 Feb 2008 00:00:00 GMT\r\n"),
+        Ok((
+            "\r\n",
+            HttpDate {
+                weekday: Weekday::Friday,
+                date: Date {
+                    year: 2008,
+                    month: 2,
+                    day: 29
+                },
+                time: Time {
+                    hour: 0,
+                    minute: 0,
+                    second: 0,
+                    nanosecond: 0
+                }
+            }
+        ))
+    );
+    for input in [
+        "Fri, 29 Feb 2007 00:00:00 GMT",
+        "fri, 29 Feb 2008 00:00:00 GMT",
+        "Fri, 29 feb 2008 00:00:00 GMT",
+        "Fri, 9 Feb 2008 00:00:00 GMT",
+        "Fri, 29 Feb 2008 00:00:00 UTC",
+        "Fri Feb 29 00:00:00 2008",
+    ] {
+        assert!(parser.parse_peek(input).is_err(), "{input:?}");
+    }
+}
+
+#[test]
+fn http_date_partial() {
+    fn parser<'i>(
+        input: &mut Partial<&'i str>,
+    ) -> ModalResult<HttpDate, InputError<Partial<&'i str>>> {
+        http_date.parse_next(input)
+    }
+
+    for input in ["Fri, 29 Feb 2008", "Fri, 29 Feb 2008 00:00:00 GM"] {
+        assert!(
+            matches!(
+                parser.parse_peek(Partial::new(input)),
+                Err(ErrMode::Incomplete(_))
+            ),
+            "{input:?}"
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn context_labels() {
+    use crate::error::ContextError;
+    use crate::lib::std::string::ToString;
+
+    let err = rfc3339::<_, ContextError>
+        .parse("1985-04-31T23:20:50Z")
+        .unwrap_err();
+    assert_eq!(err.offset(), 8);
+    assert_eq!(err.inner().to_string(), "invalid day");
+}
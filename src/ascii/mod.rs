@@ -2,10 +2,20 @@
 //!
 //! Functions recognizing specific characters
 
+pub mod datetime;
+
+#[cfg(feature = "alloc")]
+mod string;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "alloc")]
+use crate::lib::std::borrow::{Cow, ToOwned};
 use crate::lib::std::ops::{Add, Shl};
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
 
 use crate::combinator::alt;
 use crate::combinator::dispatch;
@@ -14,10 +24,11 @@ use crate::combinator::fail;
 use crate::combinator::opt;
 use crate::combinator::peek;
 use crate::combinator::trace;
+use crate::error::FromExternalError;
 use crate::error::Needed;
 use crate::error::ParserError;
 use crate::stream::FindSlice;
-use crate::stream::{AsBStr, AsChar, ParseSlice, Stream, StreamIsPartial};
+use crate::stream::{AsBStr, AsChar, ContainsToken, ParseSlice, Stream, StreamIsPartial};
 use crate::stream::{Compare, CompareResult};
 use crate::token::any;
 use crate::token::one_of;
@@ -26,6 +37,13 @@ use crate::token::take_while;
 use crate::Parser;
 use crate::Result;
 
+#[cfg(feature = "alloc")]
+pub use string::{
+    c_string, json_string, rust_byte_string, rust_raw_byte_string, rust_raw_string, rust_string,
+    toml_basic_string, toml_literal_string, toml_multiline_basic_string,
+    toml_multiline_literal_string,
+};
+
 /// Mark a value as case-insensitive for ASCII characters
 ///
 /// # Example
@@ -1168,6 +1186,42 @@ impl Uint for usize {
     }
 }
 
+impl Uint for NonZeroU8 {
+    fn try_from_dec_uint(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
+impl Uint for NonZeroU16 {
+    fn try_from_dec_uint(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
+impl Uint for NonZeroU32 {
+    fn try_from_dec_uint(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
+impl Uint for NonZeroU64 {
+    fn try_from_dec_uint(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
+impl Uint for NonZeroU128 {
+    fn try_from_dec_uint(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
+impl Uint for NonZeroUsize {
+    fn try_from_dec_uint(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
 /// Decode a decimal signed integer (e.g. [`i32`])
 ///
 /// *Complete version*: can parse until the end of input.
@@ -1258,8 +1312,46 @@ impl Int for isize {
     }
 }
 
+impl Int for NonZeroI8 {
+    fn try_from_dec_int(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
+impl Int for NonZeroI16 {
+    fn try_from_dec_int(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
+impl Int for NonZeroI32 {
+    fn try_from_dec_int(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
+impl Int for NonZeroI64 {
+    fn try_from_dec_int(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
+impl Int for NonZeroI128 {
+    fn try_from_dec_int(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
+impl Int for NonZeroIsize {
+    fn try_from_dec_int(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
 /// Decode a variable-width hexadecimal integer (e.g. [`u32`])
 ///
+/// For `NonZero` integers, use [`radix_uint`] with a radix of `16`.
+///
 /// *Complete version*: Will parse until the end of input if it has fewer characters than the type
 /// supports.
 ///
@@ -1407,6 +1499,609 @@ impl HexUint for u128 {
     }
 }
 
+/// Decode an unsigned integer in any radix from 2 to 36 (e.g. [`u32`])
+///
+/// Arguments:
+/// - `radix`: the [`Radix`] of the digits, either fixed or detected from a `0x`, `0o` or `0b`
+///   prefix
+/// - `separators`: [set of tokens][crate::stream::ContainsToken] allowed between digits to
+///   group them, like `_` in `1_000_000`, or `()` for none
+///
+/// Digits are case-insensitive.
+/// A separator is only consumed when followed by a digit, so a trailing separator is left in the
+/// input.
+///
+/// *Complete version*: can parse until the end of input.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// If the integer doesn't fit in `Output`, the error is built from [`OutOfRange`] with
+/// [`FromExternalError`].
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream] into a `u32`:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::ascii::Radix;
+/// # use winnow::error::ContextError;
+/// # use winnow::stream::ContainsToken;
+/// pub fn radix_uint<'i>(radix: impl Into<Radix>, separators: impl ContainsToken<char>) -> impl Parser<&'i str, u32, ContextError>
+/// # {
+/// #     winnow::ascii::radix_uint(radix, separators)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{radix_uint, Radix};
+///
+/// fn binary(s: &mut &str) -> ModalResult<u8> {
+///   radix_uint(2, ()).parse_next(s)
+/// }
+///
+/// assert_eq!(binary.parse_peek("1010;"), Ok((";", 0b1010)));
+/// assert!(binary.parse_peek("2").is_err());
+/// assert!(binary.parse_peek("100000000").is_err());
+///
+/// fn literal(s: &mut &str) -> ModalResult<u64> {
+///   radix_uint(Radix::Prefixed(10), '_').parse_next(s)
+/// }
+///
+/// assert_eq!(literal.parse_peek("1_000_000"), Ok(("", 1_000_000)));
+/// assert_eq!(literal.parse_peek("0xDEAD_beef"), Ok(("", 0xDEAD_BEEF)));
+/// assert_eq!(literal.parse_peek("0o17"), Ok(("", 0o17)));
+/// assert_eq!(literal.parse_peek("0b1_1_"), Ok(("_", 0b11)));
+/// assert!(literal.parse_peek("0x").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::Partial;
+/// use winnow::ascii::{radix_uint, Radix};
+///
+/// fn parser<'s>(s: &mut Partial<&'s str>) -> ModalResult<u32> {
+///   radix_uint(Radix::Prefixed(10), '\'').parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new("1'000;")), Ok((Partial::new(";"), 1000)));
+/// assert_eq!(parser.parse_peek(Partial::new("1'000")), Err(ErrMode::Incomplete(Needed::new(1))));
+/// assert_eq!(parser.parse_peek(Partial::new("0")), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[doc(alias = "bin")]
+#[doc(alias = "oct")]
+#[doc(alias = "from_str_radix")]
+pub fn radix_uint<Input, Output, Separators, Error>(
+    radix: impl Into<Radix>,
+    separators: Separators,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Output: RadixUint,
+    Separators: ContainsToken<<Input as Stream>::Token>,
+    Error: ParserError<Input> + FromExternalError<Input, OutOfRange>,
+{
+    let radix = radix.into();
+    trace("radix_uint", move |input: &mut Input| {
+        let start = input.checkpoint();
+        let radix = radix_prefix(input, radix)?;
+        let digits = radix_digits(input, radix, &separators)?;
+        Output::try_from_radix_digits(digits.as_bstr(), radix, sealed::SealedMarker).ok_or_else(
+            || {
+                input.reset(&start);
+                FromExternalError::from_external_error(input, OutOfRange)
+            },
+        )
+    })
+}
+
+/// Decode a signed integer in any radix from 2 to 36 (e.g. [`i32`])
+///
+/// Like [`radix_uint`], except for an optional `+` or `-` sign, which comes before any prefix.
+///
+/// *Complete version*: can parse until the end of input.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// If the integer doesn't fit in `Output`, the error is built from [`OutOfRange`] with
+/// [`FromExternalError`].
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream] into an `i32`:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::ascii::Radix;
+/// # use winnow::error::ContextError;
+/// # use winnow::stream::ContainsToken;
+/// pub fn radix_int<'i>(radix: impl Into<Radix>, separators: impl ContainsToken<char>) -> impl Parser<&'i str, i32, ContextError>
+/// # {
+/// #     winnow::ascii::radix_int(radix, separators)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{radix_int, Radix};
+///
+/// fn parser(s: &mut &str) -> ModalResult<i8> {
+///   radix_int(Radix::Prefixed(10), '_').parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek("-0x80"), Ok(("", -128)));
+/// assert_eq!(parser.parse_peek("+1_2"), Ok(("", 12)));
+/// assert!(parser.parse_peek("0x80").is_err());
+/// assert!(parser.parse_peek("-").is_err());
+/// ```
+#[doc(alias = "from_str_radix")]
+pub fn radix_int<Input, Output, Separators, Error>(
+    radix: impl Into<Radix>,
+    separators: Separators,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Output: RadixInt,
+    Separators: ContainsToken<<Input as Stream>::Token>,
+    Error: ParserError<Input> + FromExternalError<Input, OutOfRange>,
+{
+    let radix = radix.into();
+    trace("radix_int", move |input: &mut Input| {
+        let start = input.checkpoint();
+        let sign = input.iter_offsets().next().map(|(_, c)| c.as_char());
+        if matches!(sign, Some('+' | '-')) {
+            let _ = input.next_token();
+        }
+        let negative = sign == Some('-');
+        let radix = radix_prefix(input, radix)?;
+        let digits = radix_digits(input, radix, &separators)?;
+        Output::try_from_radix_digits(digits.as_bstr(), radix, negative, sealed::SealedMarker)
+            .ok_or_else(|| {
+                input.reset(&start);
+                FromExternalError::from_external_error(input, OutOfRange)
+            })
+    })
+}
+
+/// Metadata for parsing unsigned integers, see [`radix_uint`]
+pub trait RadixUint: Sized {
+    #[doc(hidden)]
+    fn try_from_radix_digits(digits: &[u8], radix: u32, _: sealed::SealedMarker) -> Option<Self>;
+}
+
+impl RadixUint for u8 {
+    fn try_from_radix_digits(digits: &[u8], radix: u32, _: sealed::SealedMarker) -> Option<Self> {
+        fold_digits(digits, radix, 0, |acc: Self, digit| {
+            acc.checked_mul(radix as Self)?.checked_add(digit as Self)
+        })
+    }
+}
+
+impl RadixUint for u16 {
+    fn try_from_radix_digits(digits: &[u8], radix: u32, _: sealed::SealedMarker) -> Option<Self> {
+        fold_digits(digits, radix, 0, |acc: Self, digit| {
+            acc.checked_mul(radix as Self)?.checked_add(digit as Self)
+        })
+    }
+}
+
+impl RadixUint for u32 {
+    fn try_from_radix_digits(digits: &[u8], radix: u32, _: sealed::SealedMarker) -> Option<Self> {
+        fold_digits(digits, radix, 0, |acc: Self, digit| {
+            acc.checked_mul(radix as Self)?.checked_add(digit as Self)
+        })
+    }
+}
+
+impl RadixUint for u64 {
+    fn try_from_radix_digits(digits: &[u8], radix: u32, _: sealed::SealedMarker) -> Option<Self> {
+        fold_digits(digits, radix, 0, |acc: Self, digit| {
+            acc.checked_mul(radix as Self)?.checked_add(digit as Self)
+        })
+    }
+}
+
+impl RadixUint for u128 {
+    fn try_from_radix_digits(digits: &[u8], radix: u32, _: sealed::SealedMarker) -> Option<Self> {
+        fold_digits(digits, radix, 0, |acc: Self, digit| {
+            acc.checked_mul(radix as Self)?.checked_add(digit as Self)
+        })
+    }
+}
+
+impl RadixUint for usize {
+    fn try_from_radix_digits(digits: &[u8], radix: u32, _: sealed::SealedMarker) -> Option<Self> {
+        fold_digits(digits, radix, 0, |acc: Self, digit| {
+            acc.checked_mul(radix as Self)?.checked_add(digit as Self)
+        })
+    }
+}
+
+impl RadixUint for NonZeroU8 {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        marker: sealed::SealedMarker,
+    ) -> Option<Self> {
+        u8::try_from_radix_digits(digits, radix, marker).and_then(Self::new)
+    }
+}
+
+impl RadixUint for NonZeroU16 {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        marker: sealed::SealedMarker,
+    ) -> Option<Self> {
+        u16::try_from_radix_digits(digits, radix, marker).and_then(Self::new)
+    }
+}
+
+impl RadixUint for NonZeroU32 {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        marker: sealed::SealedMarker,
+    ) -> Option<Self> {
+        u32::try_from_radix_digits(digits, radix, marker).and_then(Self::new)
+    }
+}
+
+impl RadixUint for NonZeroU64 {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        marker: sealed::SealedMarker,
+    ) -> Option<Self> {
+        u64::try_from_radix_digits(digits, radix, marker).and_then(Self::new)
+    }
+}
+
+impl RadixUint for NonZeroU128 {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        marker: sealed::SealedMarker,
+    ) -> Option<Self> {
+        u128::try_from_radix_digits(digits, radix, marker).and_then(Self::new)
+    }
+}
+
+impl RadixUint for NonZeroUsize {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        marker: sealed::SealedMarker,
+    ) -> Option<Self> {
+        usize::try_from_radix_digits(digits, radix, marker).and_then(Self::new)
+    }
+}
+
+/// Metadata for parsing signed integers, see [`radix_int`]
+pub trait RadixInt: Sized {
+    #[doc(hidden)]
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        negative: bool,
+        _: sealed::SealedMarker,
+    ) -> Option<Self>;
+}
+
+impl RadixInt for i8 {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        negative: bool,
+        _: sealed::SealedMarker,
+    ) -> Option<Self> {
+        fold_digits(digits, radix, 0, |acc: Self, digit| {
+            let acc = acc.checked_mul(radix as Self)?;
+            if negative {
+                acc.checked_sub(digit as Self)
+            } else {
+                acc.checked_add(digit as Self)
+            }
+        })
+    }
+}
+
+impl RadixInt for i16 {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        negative: bool,
+        _: sealed::SealedMarker,
+    ) -> Option<Self> {
+        fold_digits(digits, radix, 0, |acc: Self, digit| {
+            let acc = acc.checked_mul(radix as Self)?;
+            if negative {
+                acc.checked_sub(digit as Self)
+            } else {
+                acc.checked_add(digit as Self)
+            }
+        })
+    }
+}
+
+impl RadixInt for i32 {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        negative: bool,
+        _: sealed::SealedMarker,
+    ) -> Option<Self> {
+        fold_digits(digits, radix, 0, |acc: Self, digit| {
+            let acc = acc.checked_mul(radix as Self)?;
+            if negative {
+                acc.checked_sub(digit as Self)
+            } else {
+                acc.checked_add(digit as Self)
+            }
+        })
+    }
+}
+
+impl RadixInt for i64 {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        negative: bool,
+        _: sealed::SealedMarker,
+    ) -> Option<Self> {
+        fold_digits(digits, radix, 0, |acc: Self, digit| {
+            let acc = acc.checked_mul(radix as Self)?;
+            if negative {
+                acc.checked_sub(digit as Self)
+            } else {
+                acc.checked_add(digit as Self)
+            }
+        })
+    }
+}
+
+impl RadixInt for i128 {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        negative: bool,
+        _: sealed::SealedMarker,
+    ) -> Option<Self> {
+        fold_digits(digits, radix, 0, |acc: Self, digit| {
+            let acc = acc.checked_mul(radix as Self)?;
+            if negative {
+                acc.checked_sub(digit as Self)
+            } else {
+                acc.checked_add(digit as Self)
+            }
+        })
+    }
+}
+
+impl RadixInt for isize {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        negative: bool,
+        _: sealed::SealedMarker,
+    ) -> Option<Self> {
+        fold_digits(digits, radix, 0, |acc: Self, digit| {
+            let acc = acc.checked_mul(radix as Self)?;
+            if negative {
+                acc.checked_sub(digit as Self)
+            } else {
+                acc.checked_add(digit as Self)
+            }
+        })
+    }
+}
+
+impl RadixInt for NonZeroI8 {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        negative: bool,
+        marker: sealed::SealedMarker,
+    ) -> Option<Self> {
+        i8::try_from_radix_digits(digits, radix, negative, marker).and_then(Self::new)
+    }
+}
+
+impl RadixInt for NonZeroI16 {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        negative: bool,
+        marker: sealed::SealedMarker,
+    ) -> Option<Self> {
+        i16::try_from_radix_digits(digits, radix, negative, marker).and_then(Self::new)
+    }
+}
+
+impl RadixInt for NonZeroI32 {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        negative: bool,
+        marker: sealed::SealedMarker,
+    ) -> Option<Self> {
+        i32::try_from_radix_digits(digits, radix, negative, marker).and_then(Self::new)
+    }
+}
+
+impl RadixInt for NonZeroI64 {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        negative: bool,
+        marker: sealed::SealedMarker,
+    ) -> Option<Self> {
+        i64::try_from_radix_digits(digits, radix, negative, marker).and_then(Self::new)
+    }
+}
+
+impl RadixInt for NonZeroI128 {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        negative: bool,
+        marker: sealed::SealedMarker,
+    ) -> Option<Self> {
+        i128::try_from_radix_digits(digits, radix, negative, marker).and_then(Self::new)
+    }
+}
+
+impl RadixInt for NonZeroIsize {
+    fn try_from_radix_digits(
+        digits: &[u8],
+        radix: u32,
+        negative: bool,
+        marker: sealed::SealedMarker,
+    ) -> Option<Self> {
+        isize::try_from_radix_digits(digits, radix, negative, marker).and_then(Self::new)
+    }
+}
+
+/// The radix of the digits of an integer, see [`radix_uint`] and [`radix_int`]
+///
+/// Radixes must be from 2 to 36.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Radix {
+    /// All digits are in this radix
+    Fixed(u32),
+    /// Digits after a `0x`, `0o` or `0b` prefix, of either case, are hexadecimal, octal or
+    /// binary, otherwise they are in this radix
+    ///
+    /// A prefix takes precedence over digits, e.g. `0b1` is binary even with a radix of 16.
+    Prefixed(u32),
+}
+
+impl From<u32> for Radix {
+    #[inline(always)]
+    fn from(radix: u32) -> Self {
+        Self::Fixed(radix)
+    }
+}
+
+/// The integer doesn't fit in the output type, see [`radix_uint`] and [`radix_int`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OutOfRange;
+
+impl crate::lib::std::fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        "integer out of range for the output type".fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfRange {}
+
+/// Consume any prefix for `radix`, returning the radix of the digits
+fn radix_prefix<I, Error>(input: &mut I, radix: Radix) -> Result<u32, Error>
+where
+    I: StreamIsPartial + Stream,
+    <I as Stream>::Token: AsChar,
+    Error: ParserError<I>,
+{
+    let radix = match radix {
+        Radix::Fixed(radix) => radix,
+        Radix::Prefixed(default) => {
+            let mut tokens = input.iter_offsets().map(|(o, c)| (o, c.as_char()));
+            let prefixed = match (tokens.next(), tokens.next()) {
+                (Some((_, '0')), Some((_, 'x' | 'X'))) => Some(16),
+                (Some((_, '0')), Some((_, 'o' | 'O'))) => Some(8),
+                (Some((_, '0')), Some((_, 'b' | 'B'))) => Some(2),
+                (Some((_, '0')), None)
+                    if <I as StreamIsPartial>::is_partial_supported() && input.is_partial() =>
+                {
+                    // Could be the start of a prefix
+                    return Err(ParserError::incomplete(input, Needed::new(1)));
+                }
+                _ => None,
+            };
+            let prefix_len = tokens
+                .next()
+                .map(|(o, _)| o)
+                .unwrap_or_else(|| input.eof_offset());
+            match prefixed {
+                Some(radix) => {
+                    let _ = input.next_slice(prefix_len);
+                    radix
+                }
+                None => default,
+            }
+        }
+    };
+    if !(2..=36).contains(&radix) {
+        return Err(ParserError::assert(input, "radix must be from 2 to 36"));
+    }
+    Ok(radix)
+}
+
+/// Take at least one digit, with separators between them
+fn radix_digits<I, S, Error>(
+    input: &mut I,
+    radix: u32,
+    separators: &S,
+) -> Result<<I as Stream>::Slice, Error>
+where
+    I: StreamIsPartial + Stream,
+    <I as Stream>::Token: AsChar + Clone,
+    S: ContainsToken<<I as Stream>::Token>,
+    Error: ParserError<I>,
+{
+    // End of the last digit
+    let mut end = 0;
+    let mut after_digit = false;
+    let mut after_separator = false;
+    for (offset, token) in input.iter_offsets() {
+        if after_digit {
+            end = offset;
+        }
+        after_digit = token.clone().as_char().is_digit(radix);
+        if after_digit {
+            after_separator = false;
+        } else if end != 0 && !after_separator && separators.contains_token(token) {
+            after_separator = true;
+        } else if end == 0 {
+            return Err(ParserError::from_input(input));
+        } else {
+            return Ok(input.next_slice(end));
+        }
+    }
+    if after_digit {
+        end = input.eof_offset();
+    }
+    if <I as StreamIsPartial>::is_partial_supported() && input.is_partial() {
+        Err(ParserError::incomplete(input, Needed::new(1)))
+    } else if end == 0 {
+        Err(ParserError::from_input(input))
+    } else {
+        Ok(input.next_slice(end))
+    }
+}
+
+/// Fold the digits in `digits`, skipping separators
+fn fold_digits<T>(
+    digits: &[u8],
+    radix: u32,
+    init: T,
+    f: impl FnMut(T, u32) -> Option<T>,
+) -> Option<T> {
+    digits
+        .iter()
+        .filter_map(|b| char::from(*b).to_digit(radix))
+        .try_fold(init, f)
+}
+
 /// Recognizes floating point number in text format and returns a [`f32`] or [`f64`].
 ///
 /// *Complete version*: Can parse until the end of input.
@@ -1534,6 +2229,323 @@ where
     .parse_next(input)
 }
 
+/// Recognizes a decimal literal, returning its parts rather than converting it
+///
+/// Unlike [`float`], the digits are kept exact, for arbitrary-precision or decimal types.
+///
+/// The grammar is `[+-]? digits ('.' digits?)? ([eE] [+-]? digits)?` by default, with
+/// [`DecimalOptions`] to allow special values, a leading `.`, hexadecimal digits and digit
+/// separators.
+///
+/// *Complete version*: Can parse until the end of input.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// If the exponent doesn't fit in an [`i64`], the error is built from [`OutOfRange`] with
+/// [`FromExternalError`].
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::ascii::{DecimalLiteral, DecimalOptions};
+/// # use winnow::error::ContextError;
+/// pub fn decimal<'i>(options: DecimalOptions) -> impl Parser<&'i str, DecimalLiteral<&'i str>, ContextError>
+/// # {
+/// #     winnow::ascii::decimal(options)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{decimal, DecimalLiteral, DecimalOptions, DecimalValue};
+///
+/// fn parser<'s>(s: &mut &'s str) -> ModalResult<DecimalLiteral<&'s str>> {
+///   decimal(DecimalOptions::new()).parse_next(s)
+/// }
+///
+/// assert_eq!(
+///     parser.parse_peek("-12.050e-3;"),
+///     Ok((";", DecimalLiteral {
+///         negative: true,
+///         value: DecimalValue::Finite { hex: false, integer: "12", fraction: Some("050"), exponent: -3 },
+///     }))
+/// );
+/// assert_eq!(
+///     parser.parse_peek("7"),
+///     Ok(("", DecimalLiteral {
+///         negative: false,
+///         value: DecimalValue::Finite { hex: false, integer: "7", fraction: None, exponent: 0 },
+///     }))
+/// );
+/// assert!(parser.parse_peek(".5").is_err());
+/// assert!(parser.parse_peek("inf").is_err());
+///
+/// // An exact mantissa and scale, e.g. for `rust_decimal::Decimal::from_i128_with_scale`
+/// let (_, literal) = parser.parse_peek("-12.050").unwrap();
+/// let DecimalValue::Finite { integer, fraction, exponent, .. } = literal.value else { unreachable!() };
+/// let fraction = fraction.unwrap_or("");
+/// let mantissa: i128 = format!("{integer}{fraction}").parse().unwrap();
+/// let scale = fraction.len() as i64 - exponent;
+/// assert_eq!((-mantissa, scale), (-12050, 3));
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{decimal, DecimalLiteral, DecimalOptions, DecimalValue};
+///
+/// fn parser<'s>(s: &mut &'s str) -> ModalResult<DecimalLiteral<&'s str>> {
+///   let options = DecimalOptions::new()
+///     .special_values(true)
+///     .leading_dot(true)
+///     .hex(true)
+///     .separators(true);
+///   decimal(options).parse_next(s)
+/// }
+///
+/// assert_eq!(
+///     parser.parse_peek("0x1_F.8p-1"),
+///     Ok(("", DecimalLiteral {
+///         negative: false,
+///         value: DecimalValue::Finite { hex: true, integer: "1_F", fraction: Some("8"), exponent: -1 },
+///     }))
+/// );
+/// assert_eq!(
+///     parser.parse_peek(".5"),
+///     Ok(("", DecimalLiteral {
+///         negative: false,
+///         value: DecimalValue::Finite { hex: false, integer: "", fraction: Some("5"), exponent: 0 },
+///     }))
+/// );
+/// assert_eq!(
+///     parser.parse_peek("-Infinity"),
+///     Ok(("", DecimalLiteral { negative: true, value: DecimalValue::Infinity }))
+/// );
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::Partial;
+/// use winnow::ascii::{decimal, DecimalLiteral, DecimalOptions, DecimalValue};
+///
+/// fn parser<'s>(s: &mut Partial<&'s str>) -> ModalResult<DecimalLiteral<&'s str>> {
+///   decimal(DecimalOptions::new()).parse_next(s)
+/// }
+///
+/// assert_eq!(
+///     parser.parse_peek(Partial::new("1.5 ")),
+///     Ok((Partial::new(" "), DecimalLiteral {
+///         negative: false,
+///         value: DecimalValue::Finite { hex: false, integer: "1", fraction: Some("5"), exponent: 0 },
+///     }))
+/// );
+/// assert_eq!(parser.parse_peek(Partial::new("1.5")), Err(ErrMode::Incomplete(Needed::new(1))));
+/// assert_eq!(parser.parse_peek(Partial::new("1.5e")), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[doc(alias = "bigdecimal")]
+pub fn decimal<Input, Error>(
+    options: DecimalOptions,
+) -> impl Parser<Input, DecimalLiteral<<Input as Stream>::Slice>, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<Caseless<&'static str>>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + FromExternalError<Input, OutOfRange>,
+{
+    trace("decimal", move |input: &mut Input| {
+        let sign = peek_char(input)?;
+        if matches!(sign, Some('+' | '-')) {
+            let _ = input.next_token();
+        }
+        let value = match peek_char(input)? {
+            Some('i' | 'I') if options.special_values => {
+                (Caseless("inf"), opt(Caseless("inity"))).parse_next(input)?;
+                DecimalValue::Infinity
+            }
+            Some('n' | 'N') if options.special_values => {
+                Caseless("nan").parse_next(input)?;
+                DecimalValue::NaN
+            }
+            _ => decimal_finite(input, options)?,
+        };
+        Ok(DecimalLiteral {
+            negative: sign == Some('-'),
+            value,
+        })
+    })
+}
+
+fn decimal_finite<I, Error>(
+    input: &mut I,
+    options: DecimalOptions,
+) -> Result<DecimalValue<<I as Stream>::Slice>, Error>
+where
+    I: StreamIsPartial + Stream,
+    <I as Stream>::Slice: AsBStr,
+    <I as Stream>::Token: AsChar + Clone,
+    Error: ParserError<I> + FromExternalError<I, OutOfRange>,
+{
+    let hex = options.hex && {
+        let mut tokens = input.iter_offsets().map(|(_, c)| c.as_char());
+        match (tokens.next(), tokens.next()) {
+            (Some('0'), Some('x' | 'X')) => true,
+            (Some('0') | None, None)
+                if <I as StreamIsPartial>::is_partial_supported() && input.is_partial() =>
+            {
+                return Err(ParserError::incomplete(input, Needed::new(1)));
+            }
+            _ => false,
+        }
+    };
+    let radix = if hex {
+        let _ = input.next_token();
+        let _ = input.next_token();
+        16
+    } else {
+        10
+    };
+    let separators = |c: <I as Stream>::Token| options.separators && c.as_char() == '_';
+    let digits = |input: &mut I| -> Result<_, Error> {
+        match peek_char(input)? {
+            Some(c) if c.is_digit(radix) => radix_digits(input, radix, &separators),
+            _ => Ok(input.next_slice(0)),
+        }
+    };
+
+    let integer = digits(input)?;
+    let fraction = if peek_char(input)? == Some('.') {
+        let _ = input.next_token();
+        Some(digits(input)?)
+    } else {
+        None
+    };
+    let has_integer = !integer.as_bstr().is_empty();
+    let has_fraction = fraction
+        .as_ref()
+        .map(|f| !f.as_bstr().is_empty())
+        .unwrap_or(false);
+    if !(has_integer || options.leading_dot && has_fraction) {
+        return Err(ParserError::from_input(input));
+    }
+
+    let exponent_marker = if hex { ['p', 'P'] } else { ['e', 'E'] };
+    let exponent = match peek_char(input)? {
+        Some(c) if exponent_marker.contains(&c) => {
+            let _ = input.next_token();
+            radix_int(10, separators).parse_next(input)?
+        }
+        _ => 0,
+    };
+
+    Ok(DecimalValue::Finite {
+        hex,
+        integer,
+        fraction,
+        exponent,
+    })
+}
+
+/// The next character, if any, or `Incomplete` at the end of partial input
+fn peek_char<I, Error>(input: &mut I) -> Result<Option<char>, Error>
+where
+    I: StreamIsPartial + Stream,
+    <I as Stream>::Token: AsChar,
+    Error: ParserError<I>,
+{
+    match input.peek_token() {
+        Some(c) => Ok(Some(c.as_char())),
+        None if <I as StreamIsPartial>::is_partial_supported() && input.is_partial() => {
+            Err(ParserError::incomplete(input, Needed::new(1)))
+        }
+        None => Ok(None),
+    }
+}
+
+/// A decimal literal recognized by [`decimal`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DecimalLiteral<S> {
+    /// Whether the literal starts with `-`
+    pub negative: bool,
+    /// The unsigned value
+    pub value: DecimalValue<S>,
+}
+
+/// The unsigned value of a [`DecimalLiteral`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DecimalValue<S> {
+    /// A finite number, `integer.fraction * base^exponent`
+    ///
+    /// Digits may contain `_` separators when [`DecimalOptions::separators`] is set.
+    Finite {
+        /// Whether the digits are hexadecimal and the exponent is a power of 2, rather than
+        /// decimal digits with a power of 10
+        hex: bool,
+        /// Digits before the `.`, only empty with [`DecimalOptions::leading_dot`]
+        integer: S,
+        /// Digits after the `.`, if there is one, which may be empty like in `1.`
+        fraction: Option<S>,
+        /// The exponent, or `0` without one
+        exponent: i64,
+    },
+    /// `inf` or `infinity`, in any case
+    Infinity,
+    /// `nan`, in any case
+    NaN,
+}
+
+/// The grammar accepted by [`decimal`]
+///
+/// [`DecimalOptions::new`] only accepts decimal digits, with an optional fraction and exponent,
+/// like `-12.5e3`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DecimalOptions {
+    special_values: bool,
+    leading_dot: bool,
+    hex: bool,
+    separators: bool,
+}
+
+impl DecimalOptions {
+    /// Only decimal digits, with an optional fraction and exponent
+    pub const fn new() -> Self {
+        Self {
+            special_values: false,
+            leading_dot: false,
+            hex: false,
+            separators: false,
+        }
+    }
+
+    /// Allow `inf`, `infinity` and `nan`, in any case
+    pub const fn special_values(mut self, yes: bool) -> Self {
+        self.special_values = yes;
+        self
+    }
+
+    /// Allow a missing integer part, like `.5`
+    pub const fn leading_dot(mut self, yes: bool) -> Self {
+        self.leading_dot = yes;
+        self
+    }
+
+    /// Allow hexadecimal digits after a `0x` prefix, with a `p` before a binary exponent, like
+    /// `0x1.8p3`
+    pub const fn hex(mut self, yes: bool) -> Self {
+        self.hex = yes;
+        self
+    }
+
+    /// Allow `_` between digits, like `1_000.000_1e1_0`
+    pub const fn separators(mut self, yes: bool) -> Self {
+        self.separators = yes;
+        self
+    }
+}
+
 /// Recognize the input slice with escaped characters.
 ///
 /// Arguments:
@@ -1858,6 +2870,203 @@ where
     }
 }
 
+/// Parse escaped characters, unescaping them while borrowing from the input when possible
+///
+/// Like [`escaped`], except that the output is borrowed from the input until the first escape,
+/// so only strings with escapes allocate.
+///
+/// Arguments:
+/// - `normal`: unescapeable characters
+///   - Must not include `control`
+/// - `control_char`: e.g. `\` for strings in most languages
+/// - `escape`: parse and transform the escaped character
+///
+/// Parsing ends when:
+/// - `alt(normal, control._char)` [`Backtrack`s][crate::error::ErrMode::Backtrack]
+/// - `normal` doesn't advance the input stream
+/// - *(complete)* input stream is exhausted
+///
+/// <div class="warning">
+///
+/// **Warning:** If the `normal` parser passed to `escaped_cow` accepts empty inputs
+/// (like `alpha0` or `digit0`), `escaped_cow` will return an error,
+/// to prevent going into an infinite loop.
+///
+/// </div>
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use std::borrow::Cow;
+/// use winnow::ascii::escaped_cow;
+/// use winnow::combinator::alt;
+/// use winnow::token::take_till;
+///
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<Cow<'s, str>> {
+///   escaped_cow(
+///     take_till(1.., ['"', '\\']),
+///     '\\',
+///     alt((
+///       "\\".value("\\"),
+///       "\"".value("\""),
+///       "n".value("\n"),
+///     ))
+///   ).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("abcd\""), Ok(("\"", Cow::Borrowed("abcd"))));
+/// assert_eq!(parser.parse_peek("ab\\\"cd\""), Ok(("\"", Cow::Owned(String::from("ab\"cd")))));
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use std::borrow::Cow;
+/// # use winnow::Partial;
+/// use winnow::ascii::escaped_cow;
+/// use winnow::combinator::alt;
+/// use winnow::token::take_till;
+///
+/// fn parser<'s>(input: &mut Partial<&'s [u8]>) -> ModalResult<Cow<'s, [u8]>> {
+///   escaped_cow(
+///     take_till(1.., [b'"', b'\\']),
+///     '\\',
+///     alt((
+///       "\\".value(b'\\'),
+///       "\"".value(b'"'),
+///       "n".value(b'\n'),
+///     ))
+///   ).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new(&b"abcd\""[..])), Ok((Partial::new(&b"\""[..]), Cow::Borrowed(&b"abcd"[..]))));
+/// assert_eq!(parser.parse_peek(Partial::new(&b"ab\\ncd\""[..])), Ok((Partial::new(&b"\""[..]), Cow::Owned(b"ab\ncd".to_vec()))));
+/// assert_eq!(parser.parse_peek(Partial::new(&b"abcd"[..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn escaped_cow<'i, Input, Error, Normal, NormalOutput, Escape, EscapeOutput, Output>(
+    mut normal: Normal,
+    control_char: char,
+    mut escape: Escape,
+) -> impl Parser<Input, Cow<'i, Output>, Error>
+where
+    Input: StreamIsPartial + Stream<Slice = &'i Output> + Compare<char>,
+    Output: ?Sized + ToOwned + 'i,
+    Normal: Parser<Input, NormalOutput, Error>,
+    Escape: Parser<Input, EscapeOutput, Error>,
+    Cow<'i, Output>: crate::stream::Accumulate<NormalOutput>,
+    Cow<'i, Output>: crate::stream::Accumulate<EscapeOutput>,
+    Error: ParserError<Input>,
+{
+    trace("escaped_cow", move |input: &mut Input| {
+        if <Input as StreamIsPartial>::is_partial_supported() && input.is_partial() {
+            escaped_cow_internal::<_, _, _, _, _, _, _, true>(
+                input,
+                &mut normal,
+                control_char,
+                &mut escape,
+            )
+        } else {
+            escaped_cow_internal::<_, _, _, _, _, _, _, false>(
+                input,
+                &mut normal,
+                control_char,
+                &mut escape,
+            )
+        }
+    })
+}
+
+#[cfg(feature = "alloc")]
+fn escaped_cow_internal<
+    'i,
+    I,
+    Error,
+    F,
+    NormalOutput,
+    G,
+    EscapeOutput,
+    Output,
+    const PARTIAL: bool,
+>(
+    input: &mut I,
+    normal: &mut F,
+    control_char: char,
+    transform: &mut G,
+) -> Result<Cow<'i, Output>, Error>
+where
+    I: StreamIsPartial,
+    I: Stream<Slice = &'i Output>,
+    I: Compare<char>,
+    Output: ?Sized + ToOwned + 'i,
+    Cow<'i, Output>: crate::stream::Accumulate<NormalOutput>,
+    Cow<'i, Output>: crate::stream::Accumulate<EscapeOutput>,
+    F: Parser<I, NormalOutput, Error>,
+    G: Parser<I, EscapeOutput, Error>,
+    Error: ParserError<I>,
+{
+    use crate::stream::Accumulate;
+
+    let start = input.checkpoint();
+    // Only set once there is an escape, until then the output is borrowed from the input
+    let mut res: Option<Cow<'i, Output>> = None;
+
+    while input.eof_offset() > 0 {
+        let current_len = input.eof_offset();
+
+        match opt(normal.by_ref()).parse_next(input)? {
+            Some(o) => {
+                if let Some(res) = res.as_mut() {
+                    res.accumulate(o);
+                }
+                // infinite loop check: the parser must always consume
+                if input.eof_offset() == current_len {
+                    return Err(ParserError::assert(
+                        input,
+                        "`escaped_cow` parsers must always consume",
+                    ));
+                }
+            }
+            None => {
+                let offset = input.offset_from(&start);
+                if opt(control_char).parse_next(input)?.is_some() {
+                    let o = transform.parse_next(input)?;
+                    res.get_or_insert_with(|| {
+                        let current = input.checkpoint();
+                        input.reset(&start);
+                        let borrowed = input.next_slice(offset);
+                        input.reset(&current);
+                        Cow::Borrowed(borrowed)
+                    })
+                    .accumulate(o);
+                } else {
+                    return Ok(match res {
+                        Some(res) => res,
+                        None => {
+                            input.reset(&start);
+                            Cow::Borrowed(input.next_slice(offset))
+                        }
+                    });
+                }
+            }
+        }
+    }
+
+    if PARTIAL && input.is_partial() {
+        Err(ParserError::incomplete(input, Needed::Unknown))
+    } else {
+        Ok(match res {
+            Some(res) => res,
+            None => {
+                input.reset(&start);
+                Cow::Borrowed(input.finish())
+            }
+        })
+    }
+}
+
 mod sealed {
     pub struct SealedMarker;
 }
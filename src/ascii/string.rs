@@ -0,0 +1,1094 @@
+use crate::combinator::trace;
+use crate::error::Needed;
+use crate::error::ParserError;
+use crate::lib::std::borrow::{Cow, ToOwned};
+use crate::lib::std::ops::Range;
+use crate::lib::std::string::String;
+use crate::lib::std::vec::Vec;
+use crate::stream::{Stream, StreamIsPartial};
+use crate::Parser;
+use crate::Result;
+
+/// Recognizes a [JSON](https://www.rfc-editor.org/rfc/rfc8259#section-7) string, unescaping it
+///
+/// Supports the `\"`, `\\`, `\/`, `\b`, `\f`, `\n`, `\r`, `\t` and `\uXXXX` escapes, with
+/// characters outside of the Basic Multilingual Plane written as a UTF-16 surrogate pair.
+/// Unpaired surrogates and unescaped control characters are errors.
+///
+/// The content is borrowed from the input when there are no escapes.
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use std::borrow::Cow;
+/// pub fn json_string<'i>(input: &mut &'i str) -> ModalResult<Cow<'i, str>>
+/// # {
+/// #     winnow::ascii::json_string.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use std::borrow::Cow;
+/// # use winnow::ascii::json_string;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<Cow<'s, str>> {
+///     json_string.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek(r#""plain", 1"#), Ok((", 1", Cow::Borrowed("plain"))));
+/// assert_eq!(parser.parse_peek(r#""tab\t\u00e9\ud83d\ude00""#), Ok(("", Cow::Owned("tab\té😀".to_owned()))));
+/// assert!(parser.parse_peek(r#""\ud83d""#).is_err());
+/// assert!(parser.parse_peek("\"new\nline\"").is_err());
+/// assert!(parser.parse_peek(r#""unterminated"#).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::Partial;
+/// # use std::borrow::Cow;
+/// # use winnow::ascii::json_string;
+/// assert_eq!(json_string::<_, ErrMode<ContextError>>.parse_peek(Partial::new(r#""abc" "#)), Ok((Partial::new(" "), Cow::Borrowed("abc"))));
+/// assert_eq!(json_string::<_, ErrMode<ContextError>>.parse_peek(Partial::new(r#""abc"#)), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// assert_eq!(json_string::<_, ErrMode<ContextError>>.parse_peek(Partial::new(r#""\ud83d"#)), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// ```
+#[inline(always)]
+pub fn json_string<'i, Input, Error>(input: &mut Input) -> Result<Cow<'i, str>, Error>
+where
+    Input: StreamIsPartial + Stream<Slice = &'i str>,
+    Error: ParserError<Input>,
+{
+    trace("json_string", |input: &mut Input| scan(input, json)).parse_next(input)
+}
+
+/// Recognizes a [Rust string literal](https://doc.rust-lang.org/reference/tokens.html#string-literals), unescaping it
+///
+/// Supports the `\n`, `\r`, `\t`, `\\`, `\0`, `\'`, `\"`, `\x7F` and `\u{7FFF}` escapes and
+/// line continuations, where a `\` before a newline skips the newline and any whitespace after
+/// it.
+/// A bare carriage return is an error.
+///
+/// The content is borrowed from the input when there are no escapes.
+///
+/// See also [`rust_raw_string`], [`rust_byte_string`] and [`rust_raw_byte_string`].
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use std::borrow::Cow;
+/// pub fn rust_string<'i>(input: &mut &'i str) -> ModalResult<Cow<'i, str>>
+/// # {
+/// #     winnow::ascii::rust_string.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use std::borrow::Cow;
+/// # use winnow::ascii::rust_string;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<Cow<'s, str>> {
+///     rust_string.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek(r#""plain";"#), Ok((";", Cow::Borrowed("plain"))));
+/// assert_eq!(parser.parse_peek(r#""\x41\u{1F_600}\"""#), Ok(("", Cow::Owned("A😀\"".to_owned()))));
+/// assert_eq!(parser.parse_peek("\"one \\\n    two\""), Ok(("", Cow::Owned("one two".to_owned()))));
+/// assert!(parser.parse_peek(r#""\xFF""#).is_err());
+/// assert!(parser.parse_peek(r#""\u{D800}""#).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::Partial;
+/// # use std::borrow::Cow;
+/// # use winnow::ascii::rust_string;
+/// assert_eq!(rust_string::<_, ErrMode<ContextError>>.parse_peek(Partial::new(r#""abc";"#)), Ok((Partial::new(";"), Cow::Borrowed("abc"))));
+/// assert_eq!(rust_string::<_, ErrMode<ContextError>>.parse_peek(Partial::new(r#""\u{1F"#)), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// ```
+#[inline(always)]
+pub fn rust_string<'i, Input, Error>(input: &mut Input) -> Result<Cow<'i, str>, Error>
+where
+    Input: StreamIsPartial + Stream<Slice = &'i str>,
+    Error: ParserError<Input>,
+{
+    trace("rust_string", |input: &mut Input| scan(input, rust)).parse_next(input)
+}
+
+/// Recognizes a [Rust raw string literal](https://doc.rust-lang.org/reference/tokens.html#raw-string-literals), like `r#"..."#`
+///
+/// The literal is closed by a `"` followed by as many `#` as it was opened with, up to 255.
+/// A bare carriage return is an error.
+///
+/// The content is always borrowed from the input.
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use std::borrow::Cow;
+/// pub fn rust_raw_string<'i>(input: &mut &'i str) -> ModalResult<Cow<'i, str>>
+/// # {
+/// #     winnow::ascii::rust_raw_string.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use std::borrow::Cow;
+/// # use winnow::ascii::rust_raw_string;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<Cow<'s, str>> {
+///     rust_raw_string.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek(r#"r"C:\path";"#), Ok((";", Cow::Borrowed(r"C:\path"))));
+/// assert_eq!(parser.parse_peek(r###"r##"a "# b"##"###), Ok(("", Cow::Borrowed(r##"a "# b"##))));
+/// assert!(parser.parse_peek(r#""plain""#).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::Partial;
+/// # use std::borrow::Cow;
+/// # use winnow::ascii::rust_raw_string;
+/// assert_eq!(rust_raw_string::<_, ErrMode<ContextError>>.parse_peek(Partial::new(r##"r#"abc"#;"##)), Ok((Partial::new(";"), Cow::Borrowed("abc"))));
+/// assert_eq!(rust_raw_string::<_, ErrMode<ContextError>>.parse_peek(Partial::new(r##"r##"abc"#"##)), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// ```
+#[inline(always)]
+pub fn rust_raw_string<'i, Input, Error>(input: &mut Input) -> Result<Cow<'i, str>, Error>
+where
+    Input: StreamIsPartial + Stream<Slice = &'i str>,
+    Error: ParserError<Input>,
+{
+    trace("rust_raw_string", |input: &mut Input| {
+        scan(input, |source: &'i str, _| {
+            let (len, content) = rust_raw(source.as_bytes(), b"r", false)?;
+            Ok((len, Cow::Borrowed(&source[content])))
+        })
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a [Rust byte string literal](https://doc.rust-lang.org/reference/tokens.html#byte-string-literals), like `b"..."`, unescaping it
+///
+/// Supports the same escapes as [`rust_string`], except that `\x` escapes go up to `\xFF` and
+/// there are no `\u{...}` escapes.
+/// Non-ASCII characters are an error.
+///
+/// The content is borrowed from the input when there are no escapes.
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use std::borrow::Cow;
+/// pub fn rust_byte_string<'i>(input: &mut &'i str) -> ModalResult<Cow<'i, [u8]>>
+/// # {
+/// #     winnow::ascii::rust_byte_string.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use std::borrow::Cow;
+/// # use winnow::ascii::rust_byte_string;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<Cow<'s, [u8]>> {
+///     rust_byte_string.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek(r#"b"abc";"#), Ok((";", Cow::Borrowed(&b"abc"[..]))));
+/// assert_eq!(parser.parse_peek(r#"b"\xFF\n""#), Ok(("", Cow::Owned(b"\xFF\n".to_vec()))));
+/// assert!(parser.parse_peek(r#"b"é""#).is_err());
+/// assert!(parser.parse_peek(r#"b"\u{41}""#).is_err());
+/// ```
+#[inline(always)]
+pub fn rust_byte_string<'i, Input, Error>(input: &mut Input) -> Result<Cow<'i, [u8]>, Error>
+where
+    Input: StreamIsPartial + Stream<Slice = &'i str>,
+    Error: ParserError<Input>,
+{
+    trace("rust_byte_string", |input: &mut Input| {
+        scan(input, rust_bytes)
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a [Rust raw byte string literal](https://doc.rust-lang.org/reference/tokens.html#raw-byte-string-literals), like `br#"..."#`
+///
+/// Like [`rust_raw_string`], except that non-ASCII characters are an error.
+///
+/// The content is always borrowed from the input.
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use std::borrow::Cow;
+/// pub fn rust_raw_byte_string<'i>(input: &mut &'i str) -> ModalResult<Cow<'i, [u8]>>
+/// # {
+/// #     winnow::ascii::rust_raw_byte_string.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use std::borrow::Cow;
+/// # use winnow::ascii::rust_raw_byte_string;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<Cow<'s, [u8]>> {
+///     rust_raw_byte_string.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek(r##"br#"\x"#;"##), Ok((";", Cow::Borrowed(&br"\x"[..]))));
+/// assert!(parser.parse_peek(r#"br"é""#).is_err());
+/// ```
+#[inline(always)]
+pub fn rust_raw_byte_string<'i, Input, Error>(input: &mut Input) -> Result<Cow<'i, [u8]>, Error>
+where
+    Input: StreamIsPartial + Stream<Slice = &'i str>,
+    Error: ParserError<Input>,
+{
+    trace("rust_raw_byte_string", |input: &mut Input| {
+        scan(input, |source: &'i str, _| {
+            let (len, content) = rust_raw(source.as_bytes(), b"br", true)?;
+            Ok((len, Cow::Borrowed(&source.as_bytes()[content])))
+        })
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a C string literal, unescaping it
+///
+/// Supports the simple escapes (`\'`, `\"`, `\?`, `\\`, `\a`, `\b`, `\f`, `\n`, `\r`, `\t`,
+/// `\v`), octal escapes of one to three digits, hex escapes of any number of digits, universal
+/// character names (`\uXXXX` and `\UXXXXXXXX`, encoded as UTF-8) and line splices.
+/// Octal and hex escapes are bytes, so they must be at most `0xFF`.
+/// Unescaped newlines are an error.
+///
+/// The content is borrowed from the input when there are no escapes.
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use std::borrow::Cow;
+/// pub fn c_string<'i>(input: &mut &'i str) -> ModalResult<Cow<'i, [u8]>>
+/// # {
+/// #     winnow::ascii::c_string.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use std::borrow::Cow;
+/// # use winnow::ascii::c_string;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<Cow<'s, [u8]>> {
+///     c_string.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek(r#""plain";"#), Ok((";", Cow::Borrowed(&b"plain"[..]))));
+/// assert_eq!(parser.parse_peek(r#""\101\x42\0\u00e9""#), Ok(("", Cow::Owned(b"AB\0\xC3\xA9".to_vec()))));
+/// assert!(parser.parse_peek(r#""\x100""#).is_err());
+/// assert!(parser.parse_peek("\"new\nline\"").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::Partial;
+/// # use std::borrow::Cow;
+/// # use winnow::ascii::c_string;
+/// assert_eq!(c_string::<_, ErrMode<ContextError>>.parse_peek(Partial::new(r#""abc";"#)), Ok((Partial::new(";"), Cow::Borrowed(&b"abc"[..]))));
+/// assert_eq!(c_string::<_, ErrMode<ContextError>>.parse_peek(Partial::new(r#""\12"#)), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// ```
+#[inline(always)]
+pub fn c_string<'i, Input, Error>(input: &mut Input) -> Result<Cow<'i, [u8]>, Error>
+where
+    Input: StreamIsPartial + Stream<Slice = &'i str>,
+    Error: ParserError<Input>,
+{
+    trace("c_string", |input: &mut Input| scan(input, c)).parse_next(input)
+}
+
+/// Recognizes a [TOML basic string](https://toml.io/en/v1.0.0#string), unescaping it
+///
+/// Supports the `\b`, `\t`, `\n`, `\f`, `\r`, `\"`, `\\`, `\uXXXX` and `\UXXXXXXXX` escapes.
+/// Control characters other than tab are an error.
+///
+/// The content is borrowed from the input when there are no escapes.
+///
+/// <div class="warning">
+///
+/// **Warning:** `""` is an empty basic string, so try [`toml_multiline_basic_string`] first when
+/// both are allowed.
+///
+/// </div>
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use std::borrow::Cow;
+/// pub fn toml_basic_string<'i>(input: &mut &'i str) -> ModalResult<Cow<'i, str>>
+/// # {
+/// #     winnow::ascii::toml_basic_string.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use std::borrow::Cow;
+/// # use winnow::ascii::toml_basic_string;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<Cow<'s, str>> {
+///     toml_basic_string.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek(r#""plain" # comment"#), Ok((" # comment", Cow::Borrowed("plain"))));
+/// assert_eq!(parser.parse_peek(r#""\u00e9\U0001F600""#), Ok(("", Cow::Owned("é😀".to_owned()))));
+/// assert!(parser.parse_peek(r#""\x41""#).is_err());
+/// ```
+#[inline(always)]
+pub fn toml_basic_string<'i, Input, Error>(input: &mut Input) -> Result<Cow<'i, str>, Error>
+where
+    Input: StreamIsPartial + Stream<Slice = &'i str>,
+    Error: ParserError<Input>,
+{
+    trace("toml_basic_string", |input: &mut Input| {
+        scan(input, toml_basic)
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a [TOML literal string](https://toml.io/en/v1.0.0#string), like `'C:\path'`
+///
+/// Control characters other than tab are an error.
+///
+/// The content is always borrowed from the input.
+///
+/// <div class="warning">
+///
+/// **Warning:** `''` is an empty literal string, so try [`toml_multiline_literal_string`] first
+/// when both are allowed.
+///
+/// </div>
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use std::borrow::Cow;
+/// pub fn toml_literal_string<'i>(input: &mut &'i str) -> ModalResult<Cow<'i, str>>
+/// # {
+/// #     winnow::ascii::toml_literal_string.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use std::borrow::Cow;
+/// # use winnow::ascii::toml_literal_string;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<Cow<'s, str>> {
+///     toml_literal_string.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek(r"'C:\path' # comment"), Ok((" # comment", Cow::Borrowed(r"C:\path"))));
+/// assert!(parser.parse_peek("'new\nline'").is_err());
+/// ```
+#[inline(always)]
+pub fn toml_literal_string<'i, Input, Error>(input: &mut Input) -> Result<Cow<'i, str>, Error>
+where
+    Input: StreamIsPartial + Stream<Slice = &'i str>,
+    Error: ParserError<Input>,
+{
+    trace("toml_literal_string", |input: &mut Input| {
+        scan(input, toml_literal)
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a [TOML multi-line basic string](https://toml.io/en/v1.0.0#string), unescaping it
+///
+/// Supports the same escapes as [`toml_basic_string`], along with line ending backslashes,
+/// which trim all whitespace and newlines up to the next non-whitespace character.
+/// A newline right after the opening `"""` is trimmed, and up to two `"` may come right before
+/// the closing `"""`.
+/// Control characters other than tab and newlines are an error.
+///
+/// The content is borrowed from the input when there are no escapes.
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use std::borrow::Cow;
+/// pub fn toml_multiline_basic_string<'i>(input: &mut &'i str) -> ModalResult<Cow<'i, str>>
+/// # {
+/// #     winnow::ascii::toml_multiline_basic_string.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use std::borrow::Cow;
+/// # use winnow::ascii::toml_multiline_basic_string;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<Cow<'s, str>> {
+///     toml_multiline_basic_string.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("\"\"\"\nRoses\nViolets\"\"\"\n"), Ok(("\n", Cow::Borrowed("Roses\nViolets"))));
+/// assert_eq!(
+///     parser.parse_peek("\"\"\"The quick \\\n\n    brown fox\"\"\""),
+///     Ok(("", Cow::Owned("The quick brown fox".to_owned())))
+/// );
+/// assert_eq!(parser.parse_peek(r#""""say "hi"""""#), Ok(("", Cow::Borrowed(r#"say "hi""#))));
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::Partial;
+/// # use std::borrow::Cow;
+/// # use winnow::ascii::toml_multiline_basic_string;
+/// assert_eq!(toml_multiline_basic_string::<_, ErrMode<ContextError>>.parse_peek(Partial::new(r#""""abc"""
+/// "#)), Ok((Partial::new("\n"), Cow::Borrowed("abc"))));
+/// // More `"` could still be part of the content
+/// assert_eq!(toml_multiline_basic_string::<_, ErrMode<ContextError>>.parse_peek(Partial::new(r#""""abc""""#)), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// ```
+#[inline(always)]
+pub fn toml_multiline_basic_string<'i, Input, Error>(
+    input: &mut Input,
+) -> Result<Cow<'i, str>, Error>
+where
+    Input: StreamIsPartial + Stream<Slice = &'i str>,
+    Error: ParserError<Input>,
+{
+    trace("toml_multiline_basic_string", |input: &mut Input| {
+        scan(input, |source: &'i str, partial| {
+            toml_multiline(source, partial, b'"')
+        })
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a [TOML multi-line literal string](https://toml.io/en/v1.0.0#string), like `'''...'''`
+///
+/// A newline right after the opening `'''` is trimmed, and up to two `'` may come right before
+/// the closing `'''`.
+/// Control characters other than tab and newlines are an error.
+///
+/// The content is always borrowed from the input.
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use std::borrow::Cow;
+/// pub fn toml_multiline_literal_string<'i>(input: &mut &'i str) -> ModalResult<Cow<'i, str>>
+/// # {
+/// #     winnow::ascii::toml_multiline_literal_string.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use std::borrow::Cow;
+/// # use winnow::ascii::toml_multiline_literal_string;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<Cow<'s, str>> {
+///     toml_multiline_literal_string.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("'''\n\\d{2} apps\n'''"), Ok(("", Cow::Borrowed("\\d{2} apps\n"))));
+/// assert_eq!(parser.parse_peek("'''it's'''''"), Ok(("", Cow::Borrowed("it's''"))));
+/// ```
+#[inline(always)]
+pub fn toml_multiline_literal_string<'i, Input, Error>(
+    input: &mut Input,
+) -> Result<Cow<'i, str>, Error>
+where
+    Input: StreamIsPartial + Stream<Slice = &'i str>,
+    Error: ParserError<Input>,
+{
+    trace("toml_multiline_literal_string", |input: &mut Input| {
+        scan(input, |source: &'i str, partial| {
+            toml_multiline(source, partial, b'\'')
+        })
+    })
+    .parse_next(input)
+}
+
+/// Why a literal couldn't be scanned
+enum Stop {
+    /// The input ended before the literal did
+    Eof,
+    /// The literal is malformed at this offset
+    Invalid(usize),
+}
+
+type Scan<O> = crate::lib::std::result::Result<O, Stop>;
+
+/// Run `scanner` on the rest of the input, advancing past the literal it found
+fn scan<'i, Input, Output, Error>(
+    input: &mut Input,
+    scanner: impl FnOnce(&'i str, bool) -> Scan<(usize, Output)>,
+) -> Result<Output, Error>
+where
+    Input: StreamIsPartial + Stream<Slice = &'i str>,
+    Error: ParserError<Input>,
+{
+    let partial = <Input as StreamIsPartial>::is_partial_supported() && input.is_partial();
+    let source = input.peek_slice(input.eof_offset());
+    match scanner(source, partial) {
+        Ok((len, output)) => {
+            let _ = input.next_slice(len);
+            Ok(output)
+        }
+        Err(Stop::Eof) if partial => Err(ParserError::incomplete(input, Needed::Unknown)),
+        Err(Stop::Eof) => {
+            let _ = input.finish();
+            Err(ParserError::from_input(input))
+        }
+        Err(Stop::Invalid(offset)) => {
+            let _ = input.next_slice(offset);
+            Err(ParserError::from_input(input))
+        }
+    }
+}
+
+/// The content of a literal, borrowed from the source until the first escape
+struct Unescaped<'i, T: ?Sized + ToOwned> {
+    source: &'i T,
+    /// Start of the content not yet copied into `owned`
+    start: usize,
+    owned: Option<T::Owned>,
+}
+
+impl<'i, T: ?Sized + ToOwned> Unescaped<'i, T> {
+    fn new(source: &'i T, start: usize) -> Self {
+        Self {
+            source,
+            start,
+            owned: None,
+        }
+    }
+}
+
+impl<'i> Unescaped<'i, str> {
+    /// Replace `source[at..resume]` with `c`
+    fn replace(&mut self, at: usize, resume: usize, c: Option<char>) {
+        let owned = self.owned.get_or_insert_with(String::new);
+        owned.push_str(&self.source[self.start..at]);
+        owned.extend(c);
+        self.start = resume;
+    }
+
+    fn finish(self, end: usize) -> Cow<'i, str> {
+        let rest = &self.source[self.start..end];
+        match self.owned {
+            Some(mut owned) => {
+                owned.push_str(rest);
+                Cow::Owned(owned)
+            }
+            None => Cow::Borrowed(rest),
+        }
+    }
+}
+
+impl<'i> Unescaped<'i, [u8]> {
+    /// Replace `source[at..resume]` with `bytes`
+    fn replace(&mut self, at: usize, resume: usize, bytes: &[u8]) {
+        let owned = self.owned.get_or_insert_with(Vec::new);
+        owned.extend_from_slice(&self.source[self.start..at]);
+        owned.extend_from_slice(bytes);
+        self.start = resume;
+    }
+
+    fn finish(self, end: usize) -> Cow<'i, [u8]> {
+        let rest = &self.source[self.start..end];
+        match self.owned {
+            Some(mut owned) => {
+                owned.extend_from_slice(rest);
+                Cow::Owned(owned)
+            }
+            None => Cow::Borrowed(rest),
+        }
+    }
+}
+
+fn byte(source: &[u8], at: usize) -> Scan<u8> {
+    source.get(at).copied().ok_or(Stop::Eof)
+}
+
+/// Match `literal` at `at`, returning the offset after it
+fn expect(source: &[u8], at: usize, literal: &[u8]) -> Scan<usize> {
+    for (i, expected) in literal.iter().enumerate() {
+        if byte(source, at + i)? != *expected {
+            return Err(Stop::Invalid(at + i));
+        }
+    }
+    Ok(at + literal.len())
+}
+
+fn hex_digit(source: &[u8], at: usize) -> Scan<u32> {
+    char::from(byte(source, at)?)
+        .to_digit(16)
+        .ok_or(Stop::Invalid(at))
+}
+
+/// Parse exactly `count` hex digits at `at`
+fn hex_digits(source: &[u8], at: usize, count: usize) -> Scan<u32> {
+    (at..at + count).try_fold(0, |value, i| Ok(value << 4 | hex_digit(source, i)?))
+}
+
+/// The character for the escape at `at`
+fn scalar(value: u32, at: usize) -> Scan<char> {
+    char::from_u32(value).ok_or(Stop::Invalid(at))
+}
+
+fn json(source: &str, _partial: bool) -> Scan<(usize, Cow<'_, str>)> {
+    let bytes = source.as_bytes();
+    let mut i = expect(bytes, 0, b"\"")?;
+    let mut content = Unescaped::new(source, i);
+    loop {
+        match byte(bytes, i)? {
+            b'"' => return Ok((i + 1, content.finish(i))),
+            b'\\' => {
+                let (resume, c) = json_escape(bytes, i)?;
+                content.replace(i, resume, Some(c));
+                i = resume;
+            }
+            0x00..=0x1F => return Err(Stop::Invalid(i)),
+            _ => i += 1,
+        }
+    }
+}
+
+fn json_escape(bytes: &[u8], at: usize) -> Scan<(usize, char)> {
+    let c = match byte(bytes, at + 1)? {
+        b'"' => '"',
+        b'\\' => '\\',
+        b'/' => '/',
+        b'b' => '\u{8}',
+        b'f' => '\u{c}',
+        b'n' => '\n',
+        b'r' => '\r',
+        b't' => '\t',
+        b'u' => {
+            let high = hex_digits(bytes, at + 2, 4)?;
+            if !(0xD800..0xDC00).contains(&high) {
+                return Ok((at + 6, scalar(high, at)?));
+            }
+            let low_at = expect(bytes, at + 6, b"\\u")?;
+            let low = hex_digits(bytes, low_at, 4)?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(Stop::Invalid(at + 6));
+            }
+            let value = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+            return Ok((low_at + 4, scalar(value, at)?));
+        }
+        _ => return Err(Stop::Invalid(at)),
+    };
+    Ok((at + 2, c))
+}
+
+fn rust(source: &str, _partial: bool) -> Scan<(usize, Cow<'_, str>)> {
+    let bytes = source.as_bytes();
+    let mut i = expect(bytes, 0, b"\"")?;
+    let mut content = Unescaped::new(source, i);
+    loop {
+        match byte(bytes, i)? {
+            b'"' => return Ok((i + 1, content.finish(i))),
+            b'\\' => {
+                let (resume, c) = rust_escape(bytes, i, false)?;
+                content.replace(i, resume, c);
+                i = resume;
+            }
+            b'\r' => i = expect(bytes, i + 1, b"\n")?,
+            _ => i += 1,
+        }
+    }
+}
+
+fn rust_bytes(source: &str, _partial: bool) -> Scan<(usize, Cow<'_, [u8]>)> {
+    let bytes = source.as_bytes();
+    let mut i = expect(bytes, 0, b"b\"")?;
+    let mut content = Unescaped::new(bytes, i);
+    loop {
+        match byte(bytes, i)? {
+            b'"' => return Ok((i + 1, content.finish(i))),
+            b'\\' => {
+                let (resume, c) = rust_escape(bytes, i, true)?;
+                match c {
+                    Some(c) => content.replace(i, resume, &[c as u8]),
+                    None => content.replace(i, resume, &[]),
+                }
+                i = resume;
+            }
+            b'\r' => i = expect(bytes, i + 1, b"\n")?,
+            0x80..=0xFF => return Err(Stop::Invalid(i)),
+            _ => i += 1,
+        }
+    }
+}
+
+/// Returns `None` for line continuations
+///
+/// For byte strings, the character is at most `\u{FF}`.
+fn rust_escape(bytes: &[u8], at: usize, byte_string: bool) -> Scan<(usize, Option<char>)> {
+    let c = match byte(bytes, at + 1)? {
+        b'n' => '\n',
+        b'r' => '\r',
+        b't' => '\t',
+        b'\\' => '\\',
+        b'0' => '\0',
+        b'\'' => '\'',
+        b'"' => '"',
+        b'x' => {
+            let value = hex_digits(bytes, at + 2, 2)?;
+            if !byte_string && 0x7F < value {
+                return Err(Stop::Invalid(at));
+            }
+            return Ok((at + 4, Some(scalar(value, at)?)));
+        }
+        b'u' if !byte_string => {
+            let mut i = expect(bytes, at + 2, b"{")?;
+            let mut value = 0;
+            let mut digits = 0;
+            loop {
+                match byte(bytes, i)? {
+                    b'}' if 0 < digits => break,
+                    b'_' if 0 < digits => {}
+                    _ if digits == 6 => return Err(Stop::Invalid(i)),
+                    _ => {
+                        value = value << 4 | hex_digit(bytes, i)?;
+                        digits += 1;
+                    }
+                }
+                i += 1;
+            }
+            return Ok((i + 1, Some(scalar(value, at)?)));
+        }
+        b'\n' => return Ok((skip_rust_whitespace(bytes, at + 2)?, None)),
+        b'\r' => {
+            let i = expect(bytes, at + 2, b"\n")?;
+            return Ok((skip_rust_whitespace(bytes, i)?, None));
+        }
+        _ => return Err(Stop::Invalid(at)),
+    };
+    Ok((at + 2, Some(c)))
+}
+
+fn skip_rust_whitespace(bytes: &[u8], mut at: usize) -> Scan<usize> {
+    while matches!(byte(bytes, at)?, b' ' | b'\t' | b'\n' | b'\r') {
+        at += 1;
+    }
+    Ok(at)
+}
+
+/// Returns the length of the literal and the range of its content
+fn rust_raw(bytes: &[u8], prefix: &[u8], byte_string: bool) -> Scan<(usize, Range<usize>)> {
+    let mut i = expect(bytes, 0, prefix)?;
+    let hashes_start = i;
+    while byte(bytes, i)? == b'#' {
+        i += 1;
+    }
+    let hashes = i - hashes_start;
+    if 255 < hashes {
+        return Err(Stop::Invalid(hashes_start + 255));
+    }
+    i = expect(bytes, i, b"\"")?;
+    let start = i;
+    loop {
+        match byte(bytes, i)? {
+            b'"' => {
+                let end = i;
+                i += 1;
+                let mut closing = 0;
+                while closing < hashes && byte(bytes, i)? == b'#' {
+                    closing += 1;
+                    i += 1;
+                }
+                if closing == hashes {
+                    return Ok((i, start..end));
+                }
+            }
+            b'\r' => i = expect(bytes, i + 1, b"\n")?,
+            0x80..=0xFF if byte_string => return Err(Stop::Invalid(i)),
+            _ => i += 1,
+        }
+    }
+}
+
+fn c(source: &str, _partial: bool) -> Scan<(usize, Cow<'_, [u8]>)> {
+    let bytes = source.as_bytes();
+    let mut i = expect(bytes, 0, b"\"")?;
+    let mut content = Unescaped::new(bytes, i);
+    loop {
+        match byte(bytes, i)? {
+            b'"' => return Ok((i + 1, content.finish(i))),
+            b'\\' => i = c_escape(bytes, i, &mut content)?,
+            b'\n' | b'\r' => return Err(Stop::Invalid(i)),
+            _ => i += 1,
+        }
+    }
+}
+
+fn c_escape(bytes: &[u8], at: usize, content: &mut Unescaped<'_, [u8]>) -> Scan<usize> {
+    let simple = match byte(bytes, at + 1)? {
+        b'\'' => Some(b'\''),
+        b'"' => Some(b'"'),
+        b'?' => Some(b'?'),
+        b'\\' => Some(b'\\'),
+        b'a' => Some(0x07),
+        b'b' => Some(0x08),
+        b'f' => Some(0x0C),
+        b'n' => Some(b'\n'),
+        b'r' => Some(b'\r'),
+        b't' => Some(b'\t'),
+        b'v' => Some(0x0B),
+        _ => None,
+    };
+    if let Some(value) = simple {
+        content.replace(at, at + 2, &[value]);
+        return Ok(at + 2);
+    }
+
+    let (resume, value) = match byte(bytes, at + 1)? {
+        b'0'..=b'7' => {
+            let mut i = at + 1;
+            let mut value = 0;
+            while i < at + 4 {
+                match byte(bytes, i)? {
+                    digit @ b'0'..=b'7' => value = value << 3 | u32::from(digit - b'0'),
+                    _ => break,
+                }
+                i += 1;
+            }
+            (i, value)
+        }
+        b'x' => {
+            let mut i = at + 2;
+            let mut value = hex_digit(bytes, i)?;
+            i += 1;
+            while let Some(digit) = char::from(byte(bytes, i)?).to_digit(16) {
+                value = value << 4 | digit;
+                if 0xFF < value {
+                    return Err(Stop::Invalid(at));
+                }
+                i += 1;
+            }
+            (i, value)
+        }
+        b'u' => {
+            let c = scalar(hex_digits(bytes, at + 2, 4)?, at)?;
+            let mut utf8 = [0; 4];
+            content.replace(at, at + 6, c.encode_utf8(&mut utf8).as_bytes());
+            return Ok(at + 6);
+        }
+        b'U' => {
+            let c = scalar(hex_digits(bytes, at + 2, 8)?, at)?;
+            let mut utf8 = [0; 4];
+            content.replace(at, at + 10, c.encode_utf8(&mut utf8).as_bytes());
+            return Ok(at + 10);
+        }
+        b'\n' => {
+            content.replace(at, at + 2, &[]);
+            return Ok(at + 2);
+        }
+        b'\r' => {
+            let resume = expect(bytes, at + 2, b"\n")?;
+            content.replace(at, resume, &[]);
+            return Ok(resume);
+        }
+        _ => return Err(Stop::Invalid(at)),
+    };
+    if 0xFF < value {
+        return Err(Stop::Invalid(at));
+    }
+    content.replace(at, resume, &[value as u8]);
+    Ok(resume)
+}
+
+fn is_toml_control(b: u8) -> bool {
+    matches!(b, 0x00..=0x08 | 0x0A..=0x1F | 0x7F)
+}
+
+fn toml_basic(source: &str, _partial: bool) -> Scan<(usize, Cow<'_, str>)> {
+    let bytes = source.as_bytes();
+    let mut i = expect(bytes, 0, b"\"")?;
+    let mut content = Unescaped::new(source, i);
+    loop {
+        match byte(bytes, i)? {
+            b'"' => return Ok((i + 1, content.finish(i))),
+            b'\\' => {
+                let (resume, c) = toml_escape(bytes, i)?;
+                content.replace(i, resume, Some(c));
+                i = resume;
+            }
+            b if is_toml_control(b) => return Err(Stop::Invalid(i)),
+            _ => i += 1,
+        }
+    }
+}
+
+fn toml_literal(source: &str, _partial: bool) -> Scan<(usize, Cow<'_, str>)> {
+    let bytes = source.as_bytes();
+    let start = expect(bytes, 0, b"'")?;
+    let mut i = start;
+    loop {
+        match byte(bytes, i)? {
+            b'\'' => return Ok((i + 1, Cow::Borrowed(&source[start..i]))),
+            b if is_toml_control(b) => return Err(Stop::Invalid(i)),
+            _ => i += 1,
+        }
+    }
+}
+
+fn toml_escape(bytes: &[u8], at: usize) -> Scan<(usize, char)> {
+    let c = match byte(bytes, at + 1)? {
+        b'b' => '\u{8}',
+        b't' => '\t',
+        b'n' => '\n',
+        b'f' => '\u{c}',
+        b'r' => '\r',
+        b'"' => '"',
+        b'\\' => '\\',
+        b'u' => return Ok((at + 6, scalar(hex_digits(bytes, at + 2, 4)?, at)?)),
+        b'U' => return Ok((at + 10, scalar(hex_digits(bytes, at + 2, 8)?, at)?)),
+        _ => return Err(Stop::Invalid(at)),
+    };
+    Ok((at + 2, c))
+}
+
+/// `delim` is `"` for basic strings, which have escapes, and `'` for literal strings
+fn toml_multiline(source: &str, partial: bool, delim: u8) -> Scan<(usize, Cow<'_, str>)> {
+    let bytes = source.as_bytes();
+    let mut i = expect(bytes, 0, &[delim; 3])?;
+    match byte(bytes, i)? {
+        b'\n' => i += 1,
+        b'\r' => i = expect(bytes, i + 1, b"\n")?,
+        _ => {}
+    }
+    let mut content = Unescaped::new(source, i);
+    loop {
+        match byte(bytes, i)? {
+            b if b == delim => {
+                let run = bytes[i..].iter().take_while(|b| **b == delim).count();
+                if run < 3 {
+                    i += run;
+                    continue;
+                }
+                if partial && i + run == bytes.len() {
+                    return Err(Stop::Eof);
+                }
+                if 5 < run {
+                    return Err(Stop::Invalid(i + 5));
+                }
+                return Ok((i + run, content.finish(i + run - 3)));
+            }
+            b'\\' if delim == b'"' => i = toml_multiline_escape(bytes, i, &mut content)?,
+            b'\n' => i += 1,
+            b'\r' => i = expect(bytes, i + 1, b"\n")?,
+            b if is_toml_control(b) => return Err(Stop::Invalid(i)),
+            _ => i += 1,
+        }
+    }
+}
+
+fn toml_multiline_escape(bytes: &[u8], at: usize, content: &mut Unescaped<'_, str>) -> Scan<usize> {
+    let mut i = at + 1;
+    while matches!(byte(bytes, i)?, b' ' | b'\t') {
+        i += 1;
+    }
+    match byte(bytes, i)? {
+        b'\n' | b'\r' => {
+            // Line ending backslash
+            loop {
+                match byte(bytes, i)? {
+                    b' ' | b'\t' | b'\n' => i += 1,
+                    b'\r' => i = expect(bytes, i + 1, b"\n")?,
+                    _ => break,
+                }
+            }
+            content.replace(at, i, None);
+            Ok(i)
+        }
+        _ if i != at + 1 => Err(Stop::Invalid(at)),
+        _ => {
+            let (resume, c) = toml_escape(bytes, at)?;
+            content.replace(at, resume, Some(c));
+            Ok(resume)
+        }
+    }
+}
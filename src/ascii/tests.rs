@@ -2849,6 +2849,689 @@ Ok(
             .raw()
         );
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn complete_escaped_cow() {
+        use crate::lib::std::borrow::Cow;
+        use crate::token::take_till;
+
+        fn esc<'i>(i: &mut &'i str) -> TestResult<&'i str, Cow<'i, str>> {
+            escaped_cow(
+                take_till(1.., ['"', '\\']),
+                '\\',
+                alt(("\\".value("\\"), "\"".value("\""), "n".value("\n"))),
+            )
+            .parse_next(i)
+        }
+
+        assert_eq!(
+            esc.parse_peek("abcd\";"),
+            Ok(("\";", Cow::Borrowed("abcd")))
+        );
+        assert_eq!(esc.parse_peek("abcd"), Ok(("", Cow::Borrowed("abcd"))));
+        assert_eq!(esc.parse_peek(""), Ok(("", Cow::Borrowed(""))));
+        assert_eq!(
+            esc.parse_peek("ab\\\"cd\\ne\";"),
+            Ok(("\";", Cow::Owned(String::from("ab\"cd\ne"))))
+        );
+        assert_eq!(
+            esc.parse_peek("\\\\ab\""),
+            Ok(("\"", Cow::Owned(String::from("\\ab"))))
+        );
+        assert_eq!(
+            esc.parse_peek("ab\\n"),
+            Ok(("", Cow::Owned(String::from("ab\n"))))
+        );
+        assert!(esc.parse_peek("ab\\x\"").is_err());
+
+        fn esc_bytes<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], Cow<'i, [u8]>> {
+            escaped_cow(
+                take_till(1.., [b'"', b'\\']),
+                '\\',
+                alt((b'\\', b'"', "n".value(b'\n'))),
+            )
+            .parse_next(i)
+        }
+
+        assert_eq!(
+            esc_bytes.parse_peek(&b"abcd\""[..]),
+            Ok((&b"\""[..], Cow::Borrowed(&b"abcd"[..])))
+        );
+        assert_eq!(
+            esc_bytes.parse_peek(&b"a\\nb\\\\\""[..]),
+            Ok((&b"\""[..], Cow::Owned(b"a\nb\\".to_vec())))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn complete_escaped_cow_hang() {
+        use crate::lib::std::borrow::Cow;
+
+        fn esc<'i>(i: &mut &'i str) -> TestResult<&'i str, Cow<'i, str>> {
+            escaped_cow(alpha0, '\\', "n".value('\n')).parse_next(i)
+        }
+
+        assert!(esc.parse_peek("abcd;").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn accumulate_cow() {
+        use crate::combinator::repeat;
+        use crate::lib::std::borrow::Cow;
+        use crate::token::any;
+
+        fn esc<'i>(i: &mut &'i str) -> TestResult<&'i str, Cow<'i, str>> {
+            escaped(alpha1, '\\', "n".value("\n")).parse_next(i)
+        }
+
+        assert_eq!(esc.parse_peek("abcd;"), Ok((";", Cow::Borrowed("abcd"))));
+        assert_eq!(
+            esc.parse_peek("ab\\ncd;"),
+            Ok((";", Cow::Owned(String::from("ab\ncd"))))
+        );
+
+        fn chars<'i>(i: &mut &'i str) -> TestResult<&'i str, Cow<'i, str>> {
+            repeat(0.., one_of(['a', 'b'])).parse_next(i)
+        }
+
+        assert_eq!(
+            chars.parse_peek("abc"),
+            Ok(("c", Cow::Owned(String::from("ab"))))
+        );
+
+        fn bytes<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], Cow<'i, [u8]>> {
+            repeat(2, any).parse_next(i)
+        }
+
+        assert_eq!(
+            bytes.parse_peek(&b"abc"[..]),
+            Ok((&b"c"[..], Cow::Owned(b"ab".to_vec())))
+        );
+    }
+
+    #[test]
+    fn radix_uint_test() {
+        fn bin<'i>(i: &mut &'i str) -> TestResult<&'i str, u8> {
+            radix_uint(2, ()).parse_next(i)
+        }
+        assert_eq!(bin.parse_peek("0"), Ok(("", 0)));
+        assert_eq!(bin.parse_peek("11111111"), Ok(("", 255)));
+        assert_eq!(bin.parse_peek("0000000011111111"), Ok(("", 255)));
+        assert_eq!(bin.parse_peek("102"), Ok(("2", 2)));
+        assert_eq!(bin.parse_peek("1_0"), Ok(("_0", 1)));
+        assert!(bin.parse_peek("").is_err());
+        assert!(bin.parse_peek("2").is_err());
+        assert_parse!(
+            bin.parse_peek("100000000;"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "100000000;",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+
+        fn base36<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], u64> {
+            radix_uint(36, ()).parse_next(i)
+        }
+        assert_eq!(
+            base36.parse_peek(&b"zZ;"[..]),
+            Ok((&b";"[..], 35 * 36 + 35))
+        );
+
+        fn grouped<'i>(i: &mut &'i str) -> TestResult<&'i str, u32> {
+            radix_uint(10, ['_', '\'']).parse_next(i)
+        }
+        assert_eq!(grouped.parse_peek("1_000'000"), Ok(("", 1_000_000)));
+        assert_eq!(grouped.parse_peek("1__0"), Ok(("__0", 1)));
+        assert_eq!(grouped.parse_peek("1_"), Ok(("_", 1)));
+        assert!(grouped.parse_peek("_1").is_err());
+
+        fn unicode_grouped<'i>(i: &mut &'i str) -> TestResult<&'i str, u32> {
+            radix_uint(10, '\u{202F}').parse_next(i)
+        }
+        assert_eq!(unicode_grouped.parse_peek("1\u{202F}000"), Ok(("", 1000)));
+
+        fn prefixed<'i>(i: &mut &'i str) -> TestResult<&'i str, u128> {
+            radix_uint(Radix::Prefixed(10), '_').parse_next(i)
+        }
+        assert_eq!(prefixed.parse_peek("0"), Ok(("", 0)));
+        assert_eq!(prefixed.parse_peek("010"), Ok(("", 10)));
+        assert_eq!(prefixed.parse_peek("0;"), Ok((";", 0)));
+        assert_eq!(prefixed.parse_peek("0XfF"), Ok(("", 0xFF)));
+        assert_eq!(prefixed.parse_peek("0O78"), Ok(("8", 0o7)));
+        assert_eq!(prefixed.parse_peek("0B101"), Ok(("", 0b101)));
+        assert_eq!(
+            prefixed.parse_peek("0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff"),
+            Ok(("", u128::MAX))
+        );
+        assert!(prefixed
+            .parse_peek("0x1_0000_0000_0000_0000_0000_0000_0000_0000")
+            .is_err());
+        assert!(prefixed.parse_peek("0x_1").is_err());
+        assert!(prefixed.parse_peek("0b2").is_err());
+
+        fn hex_prefixed<'i>(i: &mut &'i str) -> TestResult<&'i str, u32> {
+            radix_uint(Radix::Prefixed(16), ()).parse_next(i)
+        }
+        assert_eq!(hex_prefixed.parse_peek("0b1"), Ok(("", 1)));
+        assert_eq!(hex_prefixed.parse_peek("b1"), Ok(("", 0xB1)));
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn radix_uint_invalid_radix() {
+        fn parser<'i>(i: &mut &'i str) -> TestResult<&'i str, u32> {
+            radix_uint(37, ()).parse_next(i)
+        }
+        assert!(parser.parse_peek("1").is_err());
+    }
+
+    #[test]
+    fn radix_int_test() {
+        fn int<'i>(i: &mut &'i str) -> TestResult<&'i str, i8> {
+            radix_int(Radix::Prefixed(10), '_').parse_next(i)
+        }
+        assert_eq!(int.parse_peek("127"), Ok(("", 127)));
+        assert_eq!(int.parse_peek("+127"), Ok(("", 127)));
+        assert_eq!(int.parse_peek("-128"), Ok(("", -128)));
+        assert_eq!(int.parse_peek("-0b1000_0000"), Ok(("", -128)));
+        assert_eq!(int.parse_peek("-0"), Ok(("", 0)));
+        assert!(int.parse_peek("128").is_err());
+        assert!(int.parse_peek("-129").is_err());
+        assert!(int.parse_peek("--1").is_err());
+        assert!(int.parse_peek("0x-1").is_err());
+        assert!(int.parse_peek("+").is_err());
+
+        fn wide<'i>(i: &mut &'i str) -> TestResult<&'i str, i128> {
+            radix_int(16, ()).parse_next(i)
+        }
+        assert_eq!(
+            wide.parse_peek("-80000000000000000000000000000000"),
+            Ok(("", i128::MIN))
+        );
+        assert_eq!(
+            wide.parse_peek("7fffffffffffffffffffffffffffffff"),
+            Ok(("", i128::MAX))
+        );
+        assert!(wide.parse_peek("80000000000000000000000000000000").is_err());
+    }
+
+    #[test]
+    fn non_zero_ints() {
+        use core::num::{NonZeroI32, NonZeroU32, NonZeroU8, NonZeroUsize};
+
+        fn dec<'i>(i: &mut &'i str) -> TestResult<&'i str, NonZeroU8> {
+            dec_uint.parse_next(i)
+        }
+        assert_eq!(dec.parse_peek("12"), Ok(("", NonZeroU8::new(12).unwrap())));
+        assert!(dec.parse_peek("0").is_err());
+        assert!(dec.parse_peek("256").is_err());
+
+        fn signed<'i>(i: &mut &'i str) -> TestResult<&'i str, NonZeroI32> {
+            dec_int.parse_next(i)
+        }
+        assert_eq!(
+            signed.parse_peek("-7"),
+            Ok(("", NonZeroI32::new(-7).unwrap()))
+        );
+        assert!(signed.parse_peek("-0").is_err());
+
+        fn radix<'i>(i: &mut &'i str) -> TestResult<&'i str, NonZeroUsize> {
+            radix_uint(Radix::Prefixed(10), ()).parse_next(i)
+        }
+        assert_eq!(
+            radix.parse_peek("0x10"),
+            Ok(("", NonZeroUsize::new(16).unwrap()))
+        );
+        assert!(radix.parse_peek("0x0").is_err());
+
+        fn radix_signed<'i>(i: &mut &'i str) -> TestResult<&'i str, NonZeroI32> {
+            radix_int(8, ()).parse_next(i)
+        }
+        assert_eq!(
+            radix_signed.parse_peek("-17"),
+            Ok(("", NonZeroI32::new(-0o17).unwrap()))
+        );
+        assert!(radix_signed.parse_peek("-0").is_err());
+
+        fn hex<'i>(i: &mut &'i str) -> TestResult<&'i str, NonZeroU32> {
+            radix_uint(16, ()).parse_next(i)
+        }
+        assert_eq!(
+            hex.parse_peek("1f;"),
+            Ok((";", NonZeroU32::new(0x1f).unwrap()))
+        );
+        assert_eq!(hex.parse_peek("000;"), Err(ErrMode::from_input(&"000;")));
+        assert!(hex.parse_peek("100000000").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn radix_out_of_range() {
+        use crate::error::ContextError;
+
+        let err = radix_uint::<_, u8, _, ContextError>(10, ())
+            .parse("256")
+            .unwrap_err();
+        assert_eq!(err.offset(), 0);
+        assert_eq!(
+            err.inner().cause().map(|e| e.to_string()),
+            Some(OutOfRange.to_string())
+        );
+    }
+
+    #[test]
+    fn decimal_test() {
+        fn finite<S>(integer: S, fraction: Option<S>, exponent: i64) -> DecimalValue<S> {
+            DecimalValue::Finite {
+                hex: false,
+                integer,
+                fraction,
+                exponent,
+            }
+        }
+
+        fn strict<'i>(i: &mut &'i str) -> TestResult<&'i str, DecimalLiteral<&'i str>> {
+            decimal(DecimalOptions::new()).parse_next(i)
+        }
+        assert_eq!(
+            strict.parse_peek("+1."),
+            Ok((
+                "",
+                DecimalLiteral {
+                    negative: false,
+                    value: finite("1", Some(""), 0)
+                }
+            ))
+        );
+        assert_eq!(
+            strict.parse_peek("0.25E+10x"),
+            Ok((
+                "x",
+                DecimalLiteral {
+                    negative: false,
+                    value: finite("0", Some("25"), 10)
+                }
+            ))
+        );
+        assert_eq!(
+            strict.parse_peek("0x10"),
+            Ok((
+                "x10",
+                DecimalLiteral {
+                    negative: false,
+                    value: finite("0", None, 0)
+                }
+            ))
+        );
+        assert_eq!(
+            strict.parse_peek("1_0"),
+            Ok((
+                "_0",
+                DecimalLiteral {
+                    negative: false,
+                    value: finite("1", None, 0)
+                }
+            ))
+        );
+        for input in ["", "-", ".", ".5", "nan", "-inf", "1e", "1e+"] {
+            assert!(strict.parse_peek(input).is_err(), "{input:?}");
+        }
+
+        fn relaxed<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], DecimalLiteral<&'i [u8]>> {
+            let options = DecimalOptions::new()
+                .special_values(true)
+                .leading_dot(true)
+                .hex(true)
+                .separators(true);
+            decimal(options).parse_next(i)
+        }
+        assert_eq!(
+            relaxed.parse_peek(b"-.5e-1_0"),
+            Ok((
+                &b""[..],
+                DecimalLiteral {
+                    negative: true,
+                    value: finite(&b""[..], Some(&b"5"[..]), -10)
+                }
+            ))
+        );
+        assert_eq!(
+            relaxed.parse_peek(b"1_000.000_1"),
+            Ok((
+                &b""[..],
+                DecimalLiteral {
+                    negative: false,
+                    value: finite(&b"1_000"[..], Some(&b"000_1"[..]), 0)
+                }
+            ))
+        );
+        assert_eq!(
+            relaxed.parse_peek(b"0XaB.cP+4e"),
+            Ok((
+                &b"e"[..],
+                DecimalLiteral {
+                    negative: false,
+                    value: DecimalValue::Finite {
+                        hex: true,
+                        integer: &b"aB"[..],
+                        fraction: Some(&b"c"[..]),
+                        exponent: 4
+                    }
+                }
+            ))
+        );
+        assert_eq!(
+            relaxed.parse_peek(b"0x.8"),
+            Ok((
+                &b""[..],
+                DecimalLiteral {
+                    negative: false,
+                    value: DecimalValue::Finite {
+                        hex: true,
+                        integer: &b""[..],
+                        fraction: Some(&b"8"[..]),
+                        exponent: 0
+                    }
+                }
+            ))
+        );
+        assert_eq!(
+            relaxed.parse_peek(b"INF"),
+            Ok((
+                &b""[..],
+                DecimalLiteral {
+                    negative: false,
+                    value: DecimalValue::Infinity
+                }
+            ))
+        );
+        assert_eq!(
+            relaxed.parse_peek(b"-infinity"),
+            Ok((
+                &b""[..],
+                DecimalLiteral {
+                    negative: true,
+                    value: DecimalValue::Infinity
+                }
+            ))
+        );
+        assert_eq!(
+            relaxed.parse_peek(b"+NaN"),
+            Ok((
+                &b""[..],
+                DecimalLiteral {
+                    negative: false,
+                    value: DecimalValue::NaN
+                }
+            ))
+        );
+        for input in [&b"."[..], b"0x", b"0x.", b"in", b"1e99999999999999999999"] {
+            assert!(relaxed.parse_peek(input).is_err(), "{input:?}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn json_string_test() {
+        use crate::lib::std::borrow::Cow;
+
+        fn json<'i>(i: &mut &'i str) -> TestResult<&'i str, Cow<'i, str>> {
+            json_string.parse_next(i)
+        }
+
+        assert_eq!(
+            json.parse_peek(r#""" rest"#),
+            Ok((" rest", Cow::Borrowed("")))
+        );
+        assert_eq!(
+            json.parse_peek(r#""caf\u00E9 \"x\"\/""#),
+            Ok(("", Cow::Owned("café \"x\"/".to_owned())))
+        );
+        assert_eq!(
+            json.parse_peek(r#""\uD834\uDD1E""#),
+            Ok(("", Cow::Owned("𝄞".to_owned())))
+        );
+        assert_eq!(json.parse_peek("\"é😀\""), Ok(("", Cow::Borrowed("é😀"))));
+        assert!(json.parse_peek(r#""\uDD1E""#).is_err());
+        assert!(json.parse_peek(r#""\uD834x""#).is_err());
+        assert!(json.parse_peek(r#""\uD834\u0041""#).is_err());
+        assert!(json.parse_peek(r#""\u12""#).is_err());
+        assert!(json.parse_peek(r#""\a""#).is_err());
+        assert!(json.parse_peek("\"\t\"").is_err());
+        assert!(json.parse_peek("'a'").is_err());
+        assert!(json.parse_peek("").is_err());
+        assert_parse!(
+            json.parse_peek(r#""ab\q""#),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "\\q\"",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn rust_string_test() {
+        use crate::lib::std::borrow::Cow;
+
+        fn rust<'i>(i: &mut &'i str) -> TestResult<&'i str, Cow<'i, str>> {
+            rust_string.parse_next(i)
+        }
+
+        assert_eq!(
+            rust.parse_peek("\"multi\nline\r\n\";"),
+            Ok((";", Cow::Borrowed("multi\nline\r\n")))
+        );
+        assert_eq!(
+            rust.parse_peek(r#""\n\r\t\\\0\'\"\x7F""#),
+            Ok(("", Cow::Owned("\n\r\t\\\0'\"\x7F".to_owned())))
+        );
+        assert_eq!(
+            rust.parse_peek(r#""\u{0}\u{10FFFF}\u{1_0}""#),
+            Ok(("", Cow::Owned("\0\u{10FFFF}\u{10}".to_owned())))
+        );
+        assert_eq!(
+            rust.parse_peek("\"a\\\r\n \t\n b\""),
+            Ok(("", Cow::Owned("ab".to_owned())))
+        );
+        assert!(rust.parse_peek(r#""\u{}""#).is_err());
+        assert!(rust.parse_peek(r#""\u{_1}""#).is_err());
+        assert!(rust.parse_peek(r#""\u{1000000}""#).is_err());
+        assert!(rust.parse_peek(r#""\u{110000}""#).is_err());
+        assert!(rust.parse_peek(r#""\x80""#).is_err());
+        assert!(rust.parse_peek(r#""\a""#).is_err());
+        assert!(rust.parse_peek("\"bare\rcr\"").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn rust_raw_string_test() {
+        use crate::lib::std::borrow::Cow;
+
+        fn raw<'i>(i: &mut &'i str) -> TestResult<&'i str, Cow<'i, str>> {
+            rust_raw_string.parse_next(i)
+        }
+        fn raw_bytes<'i>(i: &mut &'i str) -> TestResult<&'i str, Cow<'i, [u8]>> {
+            rust_raw_byte_string.parse_next(i)
+        }
+
+        assert_eq!(raw.parse_peek(r#"r"""#), Ok(("", Cow::Borrowed(""))));
+        assert_eq!(
+            raw.parse_peek(r##"r#"a"b"#"#"##),
+            Ok((r##""#"##, Cow::Borrowed(r#"a"b"#)))
+        );
+        assert_eq!(
+            raw.parse_peek(r###"r##"a"#b"##"###),
+            Ok(("", Cow::Borrowed(r##"a"#b"##)))
+        );
+        assert!(raw.parse_peek(r##"r#"a""##).is_err());
+        assert!(raw.parse_peek(r#"r#a"#).is_err());
+        assert!(raw.parse_peek("r\"bare\rcr\"").is_err());
+        let hashes = "#".repeat(256);
+        let too_many = format!("r{hashes}\"\"{hashes}");
+        assert!(raw.parse_peek(too_many.as_str()).is_err());
+        let most = format!("r{0}\"\"{0}", &hashes[1..]);
+        assert_eq!(raw.parse_peek(most.as_str()), Ok(("", Cow::Borrowed(""))));
+
+        assert_eq!(
+            raw_bytes.parse_peek(r#"br"\n""#),
+            Ok(("", Cow::Borrowed(&br"\n"[..])))
+        );
+        assert!(raw_bytes.parse_peek(r#"r"a""#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn rust_byte_string_test() {
+        use crate::lib::std::borrow::Cow;
+
+        fn bytes<'i>(i: &mut &'i str) -> TestResult<&'i str, Cow<'i, [u8]>> {
+            rust_byte_string.parse_next(i)
+        }
+
+        assert_eq!(
+            bytes.parse_peek(r#"b"a\x00\xff\n\\""#),
+            Ok(("", Cow::Owned(b"a\x00\xff\n\\".to_vec())))
+        );
+        assert_eq!(
+            bytes.parse_peek("b\"a\\\n  b\""),
+            Ok(("", Cow::Owned(b"ab".to_vec())))
+        );
+        assert!(bytes.parse_peek(r#""a""#).is_err());
+        assert!(bytes.parse_peek(r#"b"\xg0""#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn c_string_test() {
+        use crate::lib::std::borrow::Cow;
+
+        fn c<'i>(i: &mut &'i str) -> TestResult<&'i str, Cow<'i, [u8]>> {
+            c_string.parse_next(i)
+        }
+
+        assert_eq!(
+            c.parse_peek(r#""\a\b\f\n\r\t\v\'\"\?\\""#),
+            Ok(("", Cow::Owned(b"\x07\x08\x0c\n\r\t\x0b'\"?\\".to_vec())))
+        );
+        assert_eq!(
+            c.parse_peek(r#""\0\7\77\1011\377""#),
+            Ok(("", Cow::Owned(b"\0\x07?A1\xff".to_vec())))
+        );
+        assert_eq!(
+            c.parse_peek(r#""\x0\x00ff\xAg""#),
+            Ok(("", Cow::Owned(b"\0\xff\x0ag".to_vec())))
+        );
+        assert_eq!(
+            c.parse_peek(r#""\U0001F600""#),
+            Ok(("", Cow::Owned("😀".as_bytes().to_vec())))
+        );
+        assert_eq!(
+            c.parse_peek("\"a\\\r\nb\""),
+            Ok(("", Cow::Owned(b"ab".to_vec())))
+        );
+        assert!(c.parse_peek(r#""\400""#).is_err());
+        assert!(c.parse_peek(r#""\x""#).is_err());
+        assert!(c.parse_peek(r#""\uD800""#).is_err());
+        assert!(c.parse_peek(r#""\q""#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn toml_string_test() {
+        use crate::lib::std::borrow::Cow;
+
+        fn basic<'i>(i: &mut &'i str) -> TestResult<&'i str, Cow<'i, str>> {
+            toml_basic_string.parse_next(i)
+        }
+        fn literal<'i>(i: &mut &'i str) -> TestResult<&'i str, Cow<'i, str>> {
+            toml_literal_string.parse_next(i)
+        }
+
+        assert_eq!(
+            basic.parse_peek(r#""\b\t\n\f\r\"\\""#),
+            Ok(("", Cow::Owned("\u{8}\t\n\u{c}\r\"\\".to_owned())))
+        );
+        assert_eq!(
+            basic.parse_peek("\"a\tb\""),
+            Ok(("", Cow::Borrowed("a\tb")))
+        );
+        assert!(basic.parse_peek(r#""\e""#).is_err());
+        assert!(basic.parse_peek(r#""\/""#).is_err());
+        assert!(basic.parse_peek(r#""\uD800""#).is_err());
+        assert!(basic.parse_peek("\"a\x7f\"").is_err());
+        assert!(basic.parse_peek("\"a\nb\"").is_err());
+
+        assert_eq!(literal.parse_peek("''"), Ok(("", Cow::Borrowed(""))));
+        assert_eq!(
+            literal.parse_peek(r#"'<\i\c*\s*>'"#),
+            Ok(("", Cow::Borrowed(r#"<\i\c*\s*>"#)))
+        );
+        assert!(literal.parse_peek("'a\x00'").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn toml_multiline_string_test() {
+        use crate::lib::std::borrow::Cow;
+
+        fn basic<'i>(i: &mut &'i str) -> TestResult<&'i str, Cow<'i, str>> {
+            toml_multiline_basic_string.parse_next(i)
+        }
+        fn literal<'i>(i: &mut &'i str) -> TestResult<&'i str, Cow<'i, str>> {
+            toml_multiline_literal_string.parse_next(i)
+        }
+
+        assert_eq!(basic.parse_peek(r#""""""""#), Ok(("", Cow::Borrowed(""))));
+        assert_eq!(
+            basic.parse_peek("\"\"\"\r\na\r\nb\"\"\""),
+            Ok(("", Cow::Borrowed("a\r\nb")))
+        );
+        assert_eq!(
+            basic.parse_peek("\"\"\"\n\na\"\"\""),
+            Ok(("", Cow::Borrowed("\na")))
+        );
+        assert_eq!(
+            basic.parse_peek("\"\"\"a \\  \r\n \t\n  b\\tc\"\"\""),
+            Ok(("", Cow::Owned("a b\tc".to_owned())))
+        );
+        assert_eq!(
+            basic.parse_peek(r#"""""a""""""#),
+            Ok(("", Cow::Borrowed(r#""a"""#)))
+        );
+        assert!(basic.parse_peek(r#""""a""""""""#).is_err());
+        assert!(basic.parse_peek("\"\"\"a\\ b\"\"\"").is_err());
+        assert!(basic.parse_peek("\"\"\"a\rb\"\"\"").is_err());
+        assert!(basic.parse_peek(r#""a""#).is_err());
+
+        assert_eq!(
+            literal.parse_peek("'''\nfirst\n  \\n second'''"),
+            Ok(("", Cow::Borrowed("first\n  \\n second")))
+        );
+        assert_eq!(
+            literal.parse_peek("'''a''''"),
+            Ok(("", Cow::Borrowed("a'")))
+        );
+        assert!(literal.parse_peek("'''a\x01'''").is_err());
+    }
 }
 
 mod partial {
@@ -4653,4 +5336,201 @@ Err(
             .raw()
         );
     }
+
+    #[test]
+    fn radix_partial() {
+        use crate::error::ErrMode;
+
+        fn prefixed<'i>(i: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, u32> {
+            radix_uint(Radix::Prefixed(10), '_').parse_next(i)
+        }
+        for input in ["", "0", "0x", "0xf", "1", "1_", "1_0"] {
+            assert_eq!(
+                prefixed.parse_peek(Partial::new(input)),
+                Err(ErrMode::Incomplete(Needed::new(1))),
+                "{input:?}"
+            );
+        }
+        assert_eq!(
+            prefixed.parse_peek(Partial::new("0;")),
+            Ok((Partial::new(";"), 0))
+        );
+        assert_eq!(
+            prefixed.parse_peek(Partial::new("0x1_f;")),
+            Ok((Partial::new(";"), 0x1F))
+        );
+        assert_eq!(
+            prefixed.parse_peek(Partial::new("1__")),
+            Ok((Partial::new("__"), 1))
+        );
+
+        fn int<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, i64> {
+            radix_int(2, ()).parse_next(i)
+        }
+        for input in [&b""[..], b"-", b"-1"] {
+            assert_eq!(
+                int.parse_peek(Partial::new(input)),
+                Err(ErrMode::Incomplete(Needed::new(1))),
+                "{input:?}"
+            );
+        }
+        assert_eq!(
+            int.parse_peek(Partial::new(&b"-10;"[..])),
+            Ok((Partial::new(&b";"[..]), -2))
+        );
+    }
+
+    #[test]
+    fn decimal_partial() {
+        use crate::error::ErrMode;
+
+        fn relaxed<'i>(
+            i: &mut Partial<&'i str>,
+        ) -> TestResult<Partial<&'i str>, DecimalLiteral<&'i str>> {
+            let options = DecimalOptions::new()
+                .special_values(true)
+                .leading_dot(true)
+                .hex(true)
+                .separators(true);
+            decimal(options).parse_next(i)
+        }
+        for input in [
+            "", "-", "0", "0x", "0x1", "1", "1_", "1.", ".", ".5", "1e", "1e-", "1e1",
+        ] {
+            assert_eq!(
+                relaxed.parse_peek(Partial::new(input)),
+                Err(ErrMode::Incomplete(Needed::new(1))),
+                "{input:?}"
+            );
+        }
+        for input in ["in", "infin", "na"] {
+            assert!(
+                matches!(
+                    relaxed.parse_peek(Partial::new(input)),
+                    Err(ErrMode::Incomplete(_))
+                ),
+                "{input:?}"
+            );
+        }
+        assert_eq!(
+            relaxed.parse_peek(Partial::new("0x1p2;")),
+            Ok((
+                Partial::new(";"),
+                DecimalLiteral {
+                    negative: false,
+                    value: DecimalValue::Finite {
+                        hex: true,
+                        integer: "1",
+                        fraction: None,
+                        exponent: 2
+                    }
+                }
+            ))
+        );
+        assert_eq!(
+            relaxed.parse_peek(Partial::new("-inf;")),
+            Ok((
+                Partial::new(";"),
+                DecimalLiteral {
+                    negative: true,
+                    value: DecimalValue::Infinity
+                }
+            ))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn string_literals_partial() {
+        use crate::error::ErrMode;
+        use crate::lib::std::borrow::Cow;
+
+        type Error<'i> = ErrMode<InputError<Partial<&'i str>>>;
+
+        for input in [
+            "",
+            "\"",
+            "\"abc",
+            "\"a\\",
+            "\"\\u00",
+            "\"\\ud83d",
+            "\"\\ud83d\\u",
+        ] {
+            assert_eq!(
+                json_string::<_, Error<'_>>.parse_peek(Partial::new(input)),
+                Err(ErrMode::Incomplete(Needed::Unknown)),
+                "{input:?}"
+            );
+        }
+        assert_eq!(
+            json_string::<_, Error<'_>>.parse_peek(Partial::new("\"a\"")),
+            Ok((Partial::new(""), Cow::Borrowed("a")))
+        );
+        assert!(json_string::<_, Error<'_>>
+            .parse_peek(Partial::new("'"))
+            .is_err());
+
+        for input in ["\"a\\\n  ", "\"a\r", "\"\\u{10", "\"\\x4"] {
+            assert_eq!(
+                rust_string::<_, Error<'_>>.parse_peek(Partial::new(input)),
+                Err(ErrMode::Incomplete(Needed::Unknown)),
+                "{input:?}"
+            );
+        }
+        for input in ["r", "r##", "r#\"a\"", "r##\"a\"#"] {
+            assert_eq!(
+                rust_raw_string::<_, Error<'_>>.parse_peek(Partial::new(input)),
+                Err(ErrMode::Incomplete(Needed::Unknown)),
+                "{input:?}"
+            );
+        }
+        assert_eq!(
+            rust_byte_string::<_, Error<'_>>.parse_peek(Partial::new("b")),
+            Err(ErrMode::Incomplete(Needed::Unknown))
+        );
+
+        for input in ["\"\\1", "\"\\x1", "\"\\x1f", "\"\\U0001"] {
+            assert_eq!(
+                c_string::<_, Error<'_>>.parse_peek(Partial::new(input)),
+                Err(ErrMode::Incomplete(Needed::Unknown)),
+                "{input:?}"
+            );
+        }
+
+        assert_eq!(
+            toml_basic_string::<_, Error<'_>>.parse_peek(Partial::new("\"a\"")),
+            Ok((Partial::new(""), Cow::Borrowed("a")))
+        );
+        assert_eq!(
+            toml_literal_string::<_, Error<'_>>.parse_peek(Partial::new("'a")),
+            Err(ErrMode::Incomplete(Needed::Unknown))
+        );
+        for input in [
+            "\"\"",
+            "\"\"\"",
+            "\"\"\"\r",
+            "\"\"\"a\"\"",
+            "\"\"\"a\"\"\"\"",
+            "\"\"\"a\\  ",
+        ] {
+            assert_eq!(
+                toml_multiline_basic_string::<_, Error<'_>>.parse_peek(Partial::new(input)),
+                Err(ErrMode::Incomplete(Needed::Unknown)),
+                "{input:?}"
+            );
+        }
+        assert_eq!(
+            toml_multiline_basic_string::<_, Error<'_>>
+                .parse_peek(Partial::new("\"\"\"a\"\"\"\"\n")),
+            Ok((Partial::new("\n"), Cow::Borrowed("a\"")))
+        );
+        assert_eq!(
+            toml_multiline_literal_string::<_, Error<'_>>.parse_peek(Partial::new("'''a'''")),
+            Err(ErrMode::Incomplete(Needed::Unknown))
+        );
+        assert_eq!(
+            toml_multiline_literal_string::<_, Error<'_>>.parse_peek(Partial::new("'''a''' ")),
+            Ok((Partial::new(" "), Cow::Borrowed("a")))
+        );
+    }
 }
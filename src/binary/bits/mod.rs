@@ -1,9 +1,17 @@
 //! Bit level parsers
 //!
+//! The `usize` of a `(Input, usize)` bit stream counts the bits of the first byte already consumed.
+//! [`take`], [`pattern`] and [`bool`] read bits starting at the most significant bit of each byte
+//! while the parsers taking a [`BitOrder`] can read either end first, like [`uint`],
+//! [`uint_pattern`] and [`bit`] in place of those.
+//!
+//! The offset doesn't record which end of the byte its bits were consumed from, so don't mix
+//! [`BitOrder::Lsb0`] parsers with most significant bit first ones within a byte.
 
 #[cfg(test)]
 mod tests;
 
+use super::{sealed, VarInt, VarUint};
 use crate::combinator::trace;
 use crate::error::{ErrorConvert, Needed, ParserError};
 use crate::lib::std::ops::{AddAssign, Div, Shl, Shr};
@@ -396,3 +404,591 @@ where
     })
     .parse_next(input)
 }
+
+/// Order of the bits within each byte of a [`bits`] stream
+///
+/// Bits read first are the more significant in [`BitOrder::Msb0`] and the less significant in
+/// [`BitOrder::Lsb0`], so a field spanning bytes is big or little endian, respectively.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BitOrder {
+    /// Most significant bit first, like H.264 and most network protocols
+    Msb0,
+    /// Least significant bit first, like DEFLATE
+    Lsb0,
+}
+
+/// Parse `count` bits as an unsigned integer
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::binary::bits::BitOrder;
+/// # use winnow::error::ContextError;
+/// pub fn uint<'i>(order: BitOrder, count: usize) -> impl Parser<(&'i [u8], usize), u32, ContextError>
+/// # {
+/// #     winnow::binary::bits::uint(order, count)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::error::ContextError;
+/// use winnow::binary::bits::{uint, BitOrder};
+///
+/// fn msb0(input: &mut (&[u8], usize)) -> ModalResult<(u8, u16)> {
+///     (uint(BitOrder::Msb0, 3usize), uint(BitOrder::Msb0, 9usize)).parse_next(input)
+/// }
+///
+/// fn lsb0(input: &mut (&[u8], usize)) -> ModalResult<(u8, u16)> {
+///     (uint(BitOrder::Lsb0, 3usize), uint(BitOrder::Lsb0, 9usize)).parse_next(input)
+/// }
+///
+/// let input = &[0b1010_1100, 0b0101_0011][..];
+/// assert_eq!(msb0.parse_peek((input, 0)), Ok(((&input[1..], 4), (0b101, 0b0_1100_0101))));
+/// assert_eq!(lsb0.parse_peek((input, 0)), Ok(((&input[1..], 4), (0b100, 0b0_0111_0101))));
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::bits::{uint, BitOrder};
+///
+/// fn parser(input: &mut (Partial<&[u8]>, usize)) -> ModalResult<u16> {
+///     uint(BitOrder::Lsb0, 12usize).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek((Partial::new(&[0xff, 0x0f][..]), 0)), Ok(((Partial::new(&[0x0f][..]), 4), 0xfff)));
+/// assert_eq!(parser.parse_peek((Partial::new(&[0xff][..]), 0)), Err(ErrMode::Incomplete(Needed::new(4))));
+/// ```
+#[inline(always)]
+pub fn uint<Input, Output, Count, Error>(
+    order: BitOrder,
+    count: Count,
+) -> impl Parser<(Input, usize), Output, Error>
+where
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Output: VarUint,
+    Count: ToUsize,
+    Error: ParserError<(Input, usize)>,
+{
+    let count = count.to_usize();
+    trace("uint", move |input: &mut (Input, usize)| {
+        if count > Output::BITS as usize {
+            return Err(ParserError::assert(
+                input,
+                "bit count exceeds the output's size",
+            ));
+        }
+        let value = read(input, order, count)?;
+        Ok(Output::from_wide(value, sealed::SealedMarker))
+    })
+}
+
+/// Parse `count` bits as an unsigned integer, if it matches `pattern`
+///
+/// This is [`pattern`] in either [`BitOrder`].
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::binary::bits::BitOrder;
+/// # use winnow::error::ContextError;
+/// pub fn uint_pattern<'i>(order: BitOrder, pattern: u8, count: usize) -> impl Parser<(&'i [u8], usize), u8, ContextError>
+/// # {
+/// #     winnow::binary::bits::uint_pattern(order, pattern, count)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::bits::{uint_pattern, BitOrder};
+///
+/// // A DEFLATE block header for a final block with fixed Huffman codes
+/// fn fixed_final(input: &mut (&[u8], usize)) -> ModalResult<(u8, u8)> {
+///     (
+///         uint_pattern(BitOrder::Lsb0, 1, 1usize),
+///         uint_pattern(BitOrder::Lsb0, 0b01, 2usize),
+///     )
+///         .parse_next(input)
+/// }
+///
+/// let input = &[0b0000_0011][..];
+/// assert_eq!(fixed_final.parse_peek((input, 0)), Ok(((input, 3), (1, 0b01))));
+/// assert!(fixed_final.parse_peek((&[0b0000_0101][..], 0)).is_err());
+/// ```
+#[inline(always)]
+pub fn uint_pattern<Input, Output, Count, Error>(
+    order: BitOrder,
+    pattern: Output,
+    count: Count,
+) -> impl Parser<(Input, usize), Output, Error>
+where
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Output: VarUint + PartialEq,
+    Count: ToUsize,
+    Error: ParserError<(Input, usize)>,
+{
+    let count = count.to_usize();
+    let mut parser = uint(order, count);
+    trace("uint_pattern", move |input: &mut (Input, usize)| {
+        let start = input.checkpoint();
+        let value: Output = parser.parse_next(input)?;
+        if value == pattern {
+            Ok(value)
+        } else {
+            input.reset(&start);
+            Err(ParserError::from_input(input))
+        }
+    })
+}
+
+/// Parse one bit as a bool
+///
+/// This is [`bool`] in either [`BitOrder`].
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::binary::bits::BitOrder;
+/// # use winnow::error::ContextError;
+/// pub fn bit<'i>(order: BitOrder) -> impl Parser<(&'i [u8], usize), bool, ContextError>
+/// # {
+/// #     winnow::binary::bits::bit(order)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::bits::{bit, BitOrder};
+///
+/// fn parser(input: &mut (&[u8], usize)) -> ModalResult<(bool, bool)> {
+///     (bit(BitOrder::Lsb0), bit(BitOrder::Lsb0)).parse_next(input)
+/// }
+///
+/// let input = &[0b1000_0001][..];
+/// assert_eq!(parser.parse_peek((input, 0)), Ok(((input, 2), (true, false))));
+/// ```
+#[inline(always)]
+pub fn bit<Input, Error>(order: BitOrder) -> impl Parser<(Input, usize), bool, Error>
+where
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Error: ParserError<(Input, usize)>,
+{
+    trace("bit", move |input: &mut (Input, usize)| {
+        read(input, order, 1).map(|bit| bit == 1)
+    })
+}
+
+/// Parse `count` bits as a two's complement signed integer
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::binary::bits::BitOrder;
+/// # use winnow::error::ContextError;
+/// pub fn int<'i>(order: BitOrder, count: usize) -> impl Parser<(&'i [u8], usize), i32, ContextError>
+/// # {
+/// #     winnow::binary::bits::int(order, count)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::bits::{int, BitOrder};
+///
+/// fn parser(input: &mut (&[u8], usize)) -> ModalResult<(i8, i8)> {
+///     (int(BitOrder::Msb0, 4usize), int(BitOrder::Msb0, 4usize)).parse_next(input)
+/// }
+///
+/// let input = &[0b1110_0111][..];
+/// assert_eq!(parser.parse_peek((input, 0)), Ok(((&input[1..], 0), (-2, 7))));
+/// ```
+#[inline(always)]
+pub fn int<Input, Output, Count, Error>(
+    order: BitOrder,
+    count: Count,
+) -> impl Parser<(Input, usize), Output, Error>
+where
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Output: VarInt,
+    Count: ToUsize,
+    Error: ParserError<(Input, usize)>,
+{
+    let count = count.to_usize();
+    trace("int", move |input: &mut (Input, usize)| {
+        if count > Output::BITS as usize {
+            return Err(ParserError::assert(
+                input,
+                "bit count exceeds the output's size",
+            ));
+        }
+        let value = read(input, order, count)?;
+        let value = if count == 0 {
+            0
+        } else {
+            let unused = u128::BITS as usize - count;
+            ((value << unused) as i128) >> unused
+        };
+        Ok(Output::from_wide(value, sealed::SealedMarker))
+    })
+}
+
+/// Parse `count` bits as a signed integer whose most significant bit is the sign, rather than
+/// two's complement
+///
+/// Negative zero is parsed as zero.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::binary::bits::BitOrder;
+/// # use winnow::error::ContextError;
+/// pub fn sign_magnitude<'i>(order: BitOrder, count: usize) -> impl Parser<(&'i [u8], usize), i32, ContextError>
+/// # {
+/// #     winnow::binary::bits::sign_magnitude(order, count)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::bits::{sign_magnitude, BitOrder};
+///
+/// fn parser(input: &mut (&[u8], usize)) -> ModalResult<(i8, i8)> {
+///     (sign_magnitude(BitOrder::Msb0, 4usize), sign_magnitude(BitOrder::Msb0, 4usize)).parse_next(input)
+/// }
+///
+/// let input = &[0b1010_0010][..];
+/// assert_eq!(parser.parse_peek((input, 0)), Ok(((&input[1..], 0), (-2, 2))));
+/// ```
+#[inline(always)]
+pub fn sign_magnitude<Input, Output, Count, Error>(
+    order: BitOrder,
+    count: Count,
+) -> impl Parser<(Input, usize), Output, Error>
+where
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Output: VarInt,
+    Count: ToUsize,
+    Error: ParserError<(Input, usize)>,
+{
+    let count = count.to_usize();
+    trace("sign_magnitude", move |input: &mut (Input, usize)| {
+        if count > Output::BITS as usize {
+            return Err(ParserError::assert(
+                input,
+                "bit count exceeds the output's size",
+            ));
+        }
+        let value = read(input, order, count)?;
+        let value = if count == 0 {
+            0
+        } else {
+            let sign = 1 << (count - 1);
+            let magnitude = (value & (sign - 1)) as i128;
+            if value & sign == 0 {
+                magnitude
+            } else {
+                -magnitude
+            }
+        };
+        Ok(Output::from_wide(value, sealed::SealedMarker))
+    })
+}
+
+/// Parse a unary code, counting the bits before the first one equal to `stop`
+///
+/// The `stop` bit is consumed but not counted.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::binary::bits::BitOrder;
+/// # use winnow::error::ContextError;
+/// pub fn unary<'i>(order: BitOrder, stop: bool) -> impl Parser<(&'i [u8], usize), usize, ContextError>
+/// # {
+/// #     winnow::binary::bits::unary(order, stop)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::bits::{unary, BitOrder};
+///
+/// fn parser(input: &mut (&[u8], usize)) -> ModalResult<(usize, usize)> {
+///     (unary(BitOrder::Msb0, false), unary(BitOrder::Msb0, true)).parse_next(input)
+/// }
+///
+/// let input = &[0b1110_0001][..];
+/// assert_eq!(parser.parse_peek((input, 0)), Ok(((&input[1..], 0), (3, 3))));
+/// ```
+#[inline(always)]
+pub fn unary<Input, Error>(order: BitOrder, stop: bool) -> impl Parser<(Input, usize), usize, Error>
+where
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Error: ParserError<(Input, usize)>,
+{
+    trace("unary", move |input: &mut (Input, usize)| {
+        let start = input.checkpoint();
+        let mut count = 0;
+        loop {
+            match read(input, order, 1) {
+                Ok(bit) if (bit == 1) == stop => return Ok(count),
+                Ok(_) => count += 1,
+                Err(err) => {
+                    input.reset(&start);
+                    return Err(err);
+                }
+            }
+        }
+    })
+}
+
+/// Parse an unsigned Exp-Golomb code, `ue(v)` in H.264
+///
+/// *Complete version*: Returns an error if there is not enough input data or the value doesn't fit
+/// in `Output`.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::binary::bits::BitOrder;
+/// # use winnow::error::ContextError;
+/// pub fn exp_golomb<'i>(order: BitOrder) -> impl Parser<(&'i [u8], usize), u32, ContextError>
+/// # {
+/// #     winnow::binary::bits::exp_golomb(order)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::bits::{exp_golomb, BitOrder};
+///
+/// fn parser(input: &mut (&[u8], usize)) -> ModalResult<(u32, u32, u32)> {
+///     (
+///         exp_golomb(BitOrder::Msb0),
+///         exp_golomb(BitOrder::Msb0),
+///         exp_golomb(BitOrder::Msb0),
+///     )
+///         .parse_next(input)
+/// }
+///
+/// // `1`, `010` and `00111`
+/// let input = &[0b1010_0011, 0b1000_0000][..];
+/// assert_eq!(parser.parse_peek((input, 0)), Ok(((&input[1..], 1), (0, 1, 6))));
+/// ```
+#[inline(always)]
+pub fn exp_golomb<Input, Output, Error>(
+    order: BitOrder,
+) -> impl Parser<(Input, usize), Output, Error>
+where
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Output: VarUint,
+    Error: ParserError<(Input, usize)>,
+{
+    trace("exp_golomb", move |input: &mut (Input, usize)| {
+        let start = input.checkpoint();
+        let value = exp_golomb_(input, order)?;
+        if Output::BITS < u128::BITS && value >> Output::BITS != 0 {
+            input.reset(&start);
+            return Err(ParserError::from_input(input));
+        }
+        Ok(Output::from_wide(value, sealed::SealedMarker))
+    })
+}
+
+/// Parse a signed Exp-Golomb code, `se(v)` in H.264
+///
+/// Codes alternate between positive and negative values, with `ue(v)` `0, 1, 2, 3, 4` parsed as
+/// `0, 1, -1, 2, -2`.
+///
+/// *Complete version*: Returns an error if there is not enough input data or the value doesn't fit
+/// in `Output`.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::binary::bits::BitOrder;
+/// # use winnow::error::ContextError;
+/// pub fn signed_exp_golomb<'i>(order: BitOrder) -> impl Parser<(&'i [u8], usize), i32, ContextError>
+/// # {
+/// #     winnow::binary::bits::signed_exp_golomb(order)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::bits::{signed_exp_golomb, BitOrder};
+///
+/// fn parser(input: &mut (&[u8], usize)) -> ModalResult<(i32, i32, i32)> {
+///     (
+///         signed_exp_golomb(BitOrder::Msb0),
+///         signed_exp_golomb(BitOrder::Msb0),
+///         signed_exp_golomb(BitOrder::Msb0),
+///     )
+///         .parse_next(input)
+/// }
+///
+/// // `1`, `010` and `011`
+/// let input = &[0b1010_0110][..];
+/// assert_eq!(parser.parse_peek((input, 0)), Ok(((&input[..], 7), (0, 1, -1))));
+/// ```
+#[inline(always)]
+pub fn signed_exp_golomb<Input, Output, Error>(
+    order: BitOrder,
+) -> impl Parser<(Input, usize), Output, Error>
+where
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Output: VarInt,
+    Error: ParserError<(Input, usize)>,
+{
+    trace("signed_exp_golomb", move |input: &mut (Input, usize)| {
+        let start = input.checkpoint();
+        let code = exp_golomb_(input, order)?;
+        let magnitude = (code / 2) as i128;
+        let value = if code % 2 == 1 {
+            magnitude + 1
+        } else {
+            -magnitude
+        };
+        let unused = i128::BITS - Output::BITS;
+        if (value << unused) >> unused != value {
+            input.reset(&start);
+            return Err(ParserError::from_input(input));
+        }
+        Ok(Output::from_wide(value, sealed::SealedMarker))
+    })
+}
+
+/// Parse an Exp-Golomb code that fits in a `u128`, resetting to the start on error
+fn exp_golomb_<I, E>(input: &mut (I, usize), order: BitOrder) -> Result<u128, E>
+where
+    I: Stream<Token = u8> + StreamIsPartial + Clone,
+    E: ParserError<(I, usize)>,
+{
+    let start = input.checkpoint();
+    let zeros = unary(order, true).parse_next(input)?;
+    // The value is `2^zeros - 1 + suffix`
+    if zeros >= u128::BITS as usize {
+        input.reset(&start);
+        return Err(ParserError::from_input(input));
+    }
+    match read(input, order, zeros) {
+        Ok(suffix) => Ok(((1u128 << zeros) - 1) + suffix),
+        Err(err) => {
+            input.reset(&start);
+            Err(err)
+        }
+    }
+}
+
+/// Read `count` bits, at most 128, in `order`
+fn read<I, E>(bit_input: &mut (I, usize), order: BitOrder, count: usize) -> Result<u128, E>
+where
+    I: Stream<Token = u8> + StreamIsPartial + Clone,
+    E: ParserError<(I, usize)>,
+{
+    debug_assert!(count <= u128::BITS as usize);
+    if count == 0 {
+        return Ok(0);
+    }
+    let (mut input, mut offset) = bit_input.clone();
+    let available = input.eof_offset() * BYTE - offset;
+    if available < count {
+        return if <I as StreamIsPartial>::is_partial_supported() && input.is_partial() {
+            Err(ParserError::incomplete(
+                bit_input,
+                Needed::new(count - available),
+            ))
+        } else {
+            Err(ParserError::from_input(bit_input))
+        };
+    }
+
+    let mut value = 0u128;
+    let mut read = 0;
+    while read < count {
+        let byte = input.peek_token().expect("enough bits were checked");
+        let remaining = BYTE - offset;
+        let width = remaining.min(count - read);
+        let mask = ((1u16 << width) - 1) as u8;
+        match order {
+            BitOrder::Msb0 => {
+                let chunk = (byte >> (remaining - width)) & mask;
+                value = (value << width) | u128::from(chunk);
+            }
+            BitOrder::Lsb0 => {
+                let chunk = (byte >> offset) & mask;
+                value |= u128::from(chunk) << read;
+            }
+        }
+        read += width;
+        offset += width;
+        if offset == BYTE {
+            let _ = input.next_token();
+            offset = 0;
+        }
+    }
+    *bit_input = (input, offset);
+    Ok(value)
+}
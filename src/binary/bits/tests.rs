@@ -202,3 +202,281 @@ fn test_bool_eof_partial() {
         Err(crate::error::ErrMode::Incomplete(Needed::new(1)))
     );
 }
+
+#[test]
+fn test_uint_bit_order() {
+    let input = &[0b1010_1100, 0b0101_0011][..];
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), (u8, u16)), InputError<_>> =
+        (uint(BitOrder::Msb0, 3usize), uint(BitOrder::Msb0, 9usize)).parse_peek((input, 0));
+    assert_eq!(result, Ok(((&input[1..], 4), (0b101, 0b0_1100_0101))));
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), (u8, u16)), InputError<_>> =
+        (uint(BitOrder::Lsb0, 3usize), uint(BitOrder::Lsb0, 9usize)).parse_peek((input, 0));
+    assert_eq!(result, Ok(((&input[1..], 4), (0b100, 0b0_0111_0101))));
+}
+
+#[test]
+fn test_uint_wide() {
+    let input = &[0xff; 17][..];
+
+    let result: ModalResult<((&[u8], usize), u128), InputError<_>> =
+        uint(BitOrder::Lsb0, 128usize).parse_peek((input, 4));
+    assert_eq!(result, Ok(((&input[16..], 4), u128::MAX)));
+
+    let result: ModalResult<((&[u8], usize), u8), InputError<_>> =
+        uint(BitOrder::Msb0, 0usize).parse_peek((input, 4));
+    assert_eq!(result, Ok(((input, 4), 0)));
+}
+
+#[test]
+fn test_uint_eof_complete() {
+    let input = &[0b1010_1100][..];
+
+    let result: ModalResult<((&[u8], usize), u16), InputError<_>> =
+        uint(BitOrder::Lsb0, 9usize).parse_peek((input, 0));
+    assert_eq!(result, Err(ErrMode::Backtrack(InputError::at((input, 0)))));
+}
+
+#[test]
+fn test_uint_eof_partial() {
+    let input = Partial::new(&[0b1010_1100][..]);
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((Partial<&[u8]>, usize), u16), InputError<_>> =
+        uint(BitOrder::Lsb0, 9usize).parse_peek((input, 2));
+    assert_eq!(result, Err(ErrMode::Incomplete(Needed::new(3))));
+}
+
+#[test]
+fn test_int() {
+    let input = &[0b1110_0111, 0b1000_0000][..];
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), (i8, i8)), InputError<_>> =
+        (int(BitOrder::Msb0, 4usize), int(BitOrder::Msb0, 4usize)).parse_peek((input, 0));
+    assert_eq!(result, Ok(((&input[1..], 0), (-2, 7))));
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), (i8, i8)), InputError<_>> =
+        (int(BitOrder::Lsb0, 4usize), int(BitOrder::Lsb0, 4usize)).parse_peek((input, 0));
+    assert_eq!(result, Ok(((&input[1..], 0), (7, -2))));
+
+    let result: ModalResult<((&[u8], usize), i16), InputError<_>> =
+        int(BitOrder::Msb0, 9usize).parse_peek((input, 0));
+    assert_eq!(result, Ok(((&input[1..], 1), -49)));
+
+    let result: ModalResult<((&[u8], usize), i128), InputError<_>> =
+        int(BitOrder::Lsb0, 128usize).parse_peek((&[0xff; 16][..], 0));
+    assert_eq!(result, Ok(((&[][..], 0), -1)));
+}
+
+#[test]
+fn test_sign_magnitude() {
+    let input = &[0b1010_0010, 0b1000_0000][..];
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), (i8, i8)), InputError<_>> = (
+        sign_magnitude(BitOrder::Msb0, 4usize),
+        sign_magnitude(BitOrder::Msb0, 4usize),
+    )
+        .parse_peek((input, 0));
+    assert_eq!(result, Ok(((&input[1..], 0), (-2, 2))));
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), (i8, i8)), InputError<_>> = (
+        sign_magnitude(BitOrder::Lsb0, 4usize),
+        sign_magnitude(BitOrder::Lsb0, 4usize),
+    )
+        .parse_peek((input, 0));
+    assert_eq!(result, Ok(((&input[1..], 0), (2, -2))));
+
+    // Negative zero
+    let result: ModalResult<((&[u8], usize), i8), InputError<_>> =
+        sign_magnitude(BitOrder::Msb0, 1usize).parse_peek((&input[1..], 0));
+    assert_eq!(result, Ok(((&input[1..], 1), 0)));
+}
+
+#[test]
+fn test_unary() {
+    let input = &[0b1110_0001, 0b0000_0000][..];
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), (usize, usize)), InputError<_>> =
+        (unary(BitOrder::Msb0, false), unary(BitOrder::Msb0, true)).parse_peek((input, 0));
+    assert_eq!(result, Ok(((&input[1..], 0), (3, 3))));
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), (usize, usize)), InputError<_>> =
+        (unary(BitOrder::Lsb0, false), unary(BitOrder::Lsb0, true)).parse_peek((input, 0));
+    assert_eq!(result, Ok(((input, 6), (1, 3))));
+
+    let result: ModalResult<((&[u8], usize), usize), InputError<_>> =
+        unary(BitOrder::Lsb0, true).parse_peek((&input[1..], 0));
+    assert_eq!(
+        result,
+        Err(ErrMode::Backtrack(InputError::at((&input[2..], 0))))
+    );
+}
+
+#[test]
+fn test_unary_partial() {
+    let input = Partial::new(&[0b0000_0000][..]);
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((Partial<&[u8]>, usize), usize), InputError<_>> =
+        unary(BitOrder::Msb0, true).parse_peek((input, 0));
+    assert_eq!(result, Err(ErrMode::Incomplete(Needed::new(1))));
+}
+
+#[test]
+fn test_exp_golomb() {
+    fn ue<'i>(input: &mut (&'i [u8], usize)) -> ModalResult<u32, InputError<(&'i [u8], usize)>> {
+        exp_golomb(BitOrder::Msb0).parse_next(input)
+    }
+
+    // ue(v) codes for 0 through 8
+    let input = &[
+        0b1010_0110,
+        0b0100_0010,
+        0b1001_1000,
+        0b1110_0010,
+        0b0000_0100,
+        0b1000_0000,
+    ][..];
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<
+        (
+            (&[u8], usize),
+            (u32, u32, u32, u32, u32, u32, u32, u32, u32),
+        ),
+        InputError<_>,
+    > = (ue, ue, ue, ue, ue, ue, ue, ue, ue).parse_peek((input, 0));
+    assert_eq!(result, Ok(((&input[5..], 1), (0, 1, 2, 3, 4, 5, 6, 7, 8))));
+
+    // Two leading zeros and a suffix of 2, each read from the least significant bit
+    let input = &[0b0001_0100][..];
+
+    let result: ModalResult<((&[u8], usize), u32), InputError<_>> =
+        exp_golomb(BitOrder::Lsb0).parse_peek((input, 0));
+    assert_eq!(result, Ok(((input, 5), 5)));
+}
+
+#[test]
+fn test_exp_golomb_overflow() {
+    // 8 leading zeros: 255 fits in a `u8`, anything larger doesn't
+    let input = &[0b0000_0000, 0b1000_0000, 0b0000_0000][..];
+
+    let result: ModalResult<((&[u8], usize), u8), InputError<_>> =
+        exp_golomb(BitOrder::Msb0).parse_peek((input, 0));
+    assert_eq!(result, Ok(((&input[2..], 1), 255)));
+
+    let input = &[0b0000_0000, 0b1000_0000, 0b1000_0000][..];
+
+    let result: ModalResult<((&[u8], usize), u8), InputError<_>> =
+        exp_golomb(BitOrder::Msb0).parse_peek((input, 0));
+    assert_eq!(result, Err(ErrMode::Backtrack(InputError::at((input, 0)))));
+
+    let result: ModalResult<((&[u8], usize), u16), InputError<_>> =
+        exp_golomb(BitOrder::Msb0).parse_peek((input, 0));
+    assert_eq!(result, Ok(((&input[2..], 1), 256)));
+}
+
+#[test]
+fn test_exp_golomb_partial() {
+    let input = Partial::new(&[0b0000_0100][..]);
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((Partial<&[u8]>, usize), u32), InputError<_>> =
+        exp_golomb(BitOrder::Msb0).parse_peek((input, 0));
+    assert_eq!(result, Err(ErrMode::Incomplete(Needed::new(3))));
+}
+
+#[test]
+fn test_signed_exp_golomb() {
+    fn se<'i>(input: &mut (&'i [u8], usize)) -> ModalResult<i8, InputError<(&'i [u8], usize)>> {
+        signed_exp_golomb(BitOrder::Msb0).parse_next(input)
+    }
+
+    // se(v) codes for 0, 1, -1, 2, -2
+    let input = &[0b1010_0110, 0b0100_0010, 0b1000_0000][..];
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), (i8, i8, i8, i8, i8)), InputError<_>> =
+        (se, se, se, se, se).parse_peek((input, 0));
+    assert_eq!(result, Ok(((&input[2..], 1), (0, 1, -1, 2, -2))));
+
+    // -128 is `ue(v)` 256 and 128 is `ue(v)` 255
+    let input = &[0b0000_0000, 0b1000_0000, 0b1000_0000][..];
+
+    let result: ModalResult<((&[u8], usize), i8), InputError<_>> =
+        signed_exp_golomb(BitOrder::Msb0).parse_peek((input, 0));
+    assert_eq!(result, Ok(((&input[2..], 1), -128)));
+
+    let input = &[0b0000_0000, 0b1000_0000, 0b0000_0000][..];
+
+    let result: ModalResult<((&[u8], usize), i8), InputError<_>> =
+        signed_exp_golomb(BitOrder::Msb0).parse_peek((input, 0));
+    assert_eq!(result, Err(ErrMode::Backtrack(InputError::at((input, 0)))));
+}
+
+#[test]
+fn test_bit_order_in_bits() {
+    let input = &[0b1000_0011, 0b0000_0001, 0xff][..];
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<(&[u8], (u8, u16, bool)), InputError<_>> =
+        bits::<_, _, ErrMode<InputError<(&[u8], usize)>>, _, _>((
+            uint(BitOrder::Lsb0, 2usize),
+            uint(BitOrder::Lsb0, 13usize),
+            bit(BitOrder::Lsb0),
+        ))
+        .parse_peek(input);
+    assert_eq!(result, Ok((&input[2..], (0b11, 0b0_0000_0110_0000, false))));
+}
+
+#[test]
+fn test_bit() {
+    let input = &[0b1000_0001][..];
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), (bool, bool)), InputError<_>> =
+        (bit(BitOrder::Lsb0), bit(BitOrder::Lsb0)).parse_peek((input, 0));
+    assert_eq!(result, Ok(((input, 2), (true, false))));
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), (bool, bool)), InputError<_>> =
+        (bit(BitOrder::Msb0), bit(BitOrder::Msb0)).parse_peek((input, 0));
+    assert_eq!(result, Ok(((input, 2), (true, false))));
+
+    let result: ModalResult<((&[u8], usize), bool), InputError<_>> =
+        bit(BitOrder::Lsb0).parse_peek((input, 8));
+    assert_eq!(result, Err(ErrMode::Backtrack(InputError::at((input, 8)))));
+}
+
+#[test]
+fn test_uint_pattern() {
+    let input = &[0b0000_0011][..];
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), (u8, u8)), InputError<_>> = (
+        uint_pattern(BitOrder::Lsb0, 1, 1usize),
+        uint_pattern(BitOrder::Lsb0, 0b01, 2usize),
+    )
+        .parse_peek((input, 0));
+    assert_eq!(result, Ok(((input, 3), (1, 0b01))));
+
+    let result: ModalResult<((&[u8], usize), u8), InputError<_>> =
+        uint_pattern(BitOrder::Msb0, 0b01, 2usize).parse_peek((input, 0));
+    assert_eq!(result, Err(ErrMode::Backtrack(InputError::at((input, 0)))));
+
+    let input = Partial::new(&[0b0000_0011][..]);
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((Partial<&[u8]>, usize), u16), InputError<_>> =
+        uint_pattern(BitOrder::Lsb0, 3, 9usize).parse_peek((input, 0));
+    assert_eq!(result, Err(ErrMode::Incomplete(Needed::new(1))));
+}
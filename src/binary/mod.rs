@@ -3,6 +3,7 @@
 #![allow(clippy::match_same_arms)]
 
 pub mod bits;
+pub mod protobuf;
 
 #[cfg(test)]
 mod tests;
@@ -2378,6 +2379,327 @@ where
     }(input)
 }
 
+/// Recognizes an unsigned [LEB128](https://en.wikipedia.org/wiki/LEB128) integer, as used by
+/// protobuf, WebAssembly and DWARF.
+///
+/// Padding with `0x80` bytes is allowed, up to the length needed for the full `Output`, e.g. 5
+/// bytes for a [`u32`].
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// Values that don't fit in `Output` are errors, as are encodings longer than needed for `Output`.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::binary::uleb128;
+///
+/// fn parser(s: &mut &[u8]) -> ModalResult<u32> {
+///     uleb128.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&b"\xe5\x8e\x26abc"[..]), Ok((&b"abc"[..], 624485)));
+/// assert_eq!(parser.parse_peek(&b"\x80\x80\x00"[..]), Ok((&b""[..], 0)));
+/// assert!(parser.parse_peek(&b"\xff\xff\xff\xff\x1f"[..]).is_err());
+/// assert!(parser.parse_peek(&b"\x80\x80\x80\x80\x80\x00"[..]).is_err());
+/// assert!(parser.parse_peek(&b"\x80"[..]).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::uleb128;
+///
+/// fn parser(s: &mut Partial<&[u8]>) -> ModalResult<u64> {
+///     uleb128.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new(&b"\x96\x01abcd"[..])), Ok((Partial::new(&b"abcd"[..]), 150)));
+/// assert_eq!(parser.parse_peek(Partial::new(&b"\x96"[..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[doc(alias = "varint")]
+#[inline(always)]
+pub fn uleb128<Input, Output, Error>(input: &mut Input) -> Result<Output, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Output: VarUint,
+    Error: ParserError<Input>,
+{
+    trace("uleb128", move |input: &mut Input| {
+        leb128(input, Output::BITS, false)
+            .map(|value| Output::from_wide(value, sealed::SealedMarker))
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a signed [LEB128](https://en.wikipedia.org/wiki/LEB128) integer, as used by
+/// WebAssembly and DWARF.
+///
+/// Padding with `0x80` or `0xff` bytes is allowed, up to the length needed for the full `Output`,
+/// e.g. 5 bytes for an [`i32`].
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// Values that don't fit in `Output` are errors, as are encodings longer than needed for `Output`.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::binary::sleb128;
+///
+/// fn parser(s: &mut &[u8]) -> ModalResult<i32> {
+///     sleb128.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&b"\xc0\xbb\x78abc"[..]), Ok((&b"abc"[..], -123456)));
+/// assert_eq!(parser.parse_peek(&b"\x3f"[..]), Ok((&b""[..], 63)));
+/// assert_eq!(parser.parse_peek(&b"\x40"[..]), Ok((&b""[..], -64)));
+/// assert!(parser.parse_peek(&b"\x80\x80\x80\x80\x08"[..]).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::sleb128;
+///
+/// fn parser(s: &mut Partial<&[u8]>) -> ModalResult<i64> {
+///     sleb128.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new(&b"\x7fabcd"[..])), Ok((Partial::new(&b"abcd"[..]), -1)));
+/// assert_eq!(parser.parse_peek(Partial::new(&b"\xff"[..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[inline(always)]
+pub fn sleb128<Input, Output, Error>(input: &mut Input) -> Result<Output, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Output: VarInt,
+    Error: ParserError<Input>,
+{
+    trace("sleb128", move |input: &mut Input| {
+        leb128(input, Output::BITS, true)
+            .map(|value| Output::from_wide(value as i128, sealed::SealedMarker))
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a [zigzag](https://protobuf.dev/programming-guides/encoding/#signed-ints) encoded
+/// [LEB128](https://en.wikipedia.org/wiki/LEB128) integer, as used by protobuf's `sint32` and
+/// `sint64`.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// Like [`uleb128`], values that don't fit in `Output` are errors, as are encodings longer than
+/// needed for `Output`.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::binary::zigzag;
+///
+/// fn parser(s: &mut &[u8]) -> ModalResult<i32> {
+///     zigzag.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&b"\x03abc"[..]), Ok((&b"abc"[..], -2)));
+/// assert_eq!(parser.parse_peek(&b"\x04"[..]), Ok((&b""[..], 2)));
+/// assert_eq!(parser.parse_peek(&b"\xff\xff\xff\xff\x0f"[..]), Ok((&b""[..], i32::MIN)));
+/// assert!(parser.parse_peek(&b"\xff\xff\xff\xff\x1f"[..]).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::zigzag;
+///
+/// fn parser(s: &mut Partial<&[u8]>) -> ModalResult<i64> {
+///     zigzag.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new(&b"\x01abcd"[..])), Ok((Partial::new(&b"abcd"[..]), -1)));
+/// assert_eq!(parser.parse_peek(Partial::new(&b"\x81"[..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[inline(always)]
+pub fn zigzag<Input, Output, Error>(input: &mut Input) -> Result<Output, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Output: VarInt,
+    Error: ParserError<Input>,
+{
+    trace("zigzag", move |input: &mut Input| {
+        let value = leb128(input, Output::BITS, false)?;
+        let value = (value >> 1) as i128 ^ -((value & 1) as i128);
+        Ok(Output::from_wide(value, sealed::SealedMarker))
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a [QUIC](https://www.rfc-editor.org/rfc/rfc9000#section-16) variable-length
+/// integer, as used by QUIC and HTTP/3.
+///
+/// The 2 most significant bits of the first byte give the length, 1, 2, 4 or 8 bytes, leaving 62
+/// bits for the value.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::binary::quic_varint;
+///
+/// fn parser(s: &mut &[u8]) -> ModalResult<u64> {
+///     quic_varint.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&b"\x25abc"[..]), Ok((&b"abc"[..], 37)));
+/// assert_eq!(parser.parse_peek(&b"\x7b\xbd"[..]), Ok((&b""[..], 15293)));
+/// assert_eq!(parser.parse_peek(&b"\x9d\x7f\x3e\x7d"[..]), Ok((&b""[..], 494878333)));
+/// assert!(parser.parse_peek(&b"\x9d\x7f"[..]).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::quic_varint;
+///
+/// fn parser(s: &mut Partial<&[u8]>) -> ModalResult<u64> {
+///     quic_varint.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new(&b"\x40\x25abcd"[..])), Ok((Partial::new(&b"abcd"[..]), 37)));
+/// assert_eq!(parser.parse_peek(Partial::new(&b""[..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// assert_eq!(parser.parse_peek(Partial::new(&b"\xc2\x19"[..])), Err(ErrMode::Incomplete(Needed::new(6))));
+/// ```
+#[inline(always)]
+pub fn quic_varint<Input, Error>(input: &mut Input) -> Result<u64, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    trace("quic_varint", move |input: &mut Input| {
+        let len = match input.peek_token() {
+            Some(first) => 1 << (first >> 6),
+            None if <Input as StreamIsPartial>::is_partial_supported() && input.is_partial() => {
+                return Err(ParserError::incomplete(input, Needed::new(1)));
+            }
+            None => return Err(ParserError::from_input(input)),
+        };
+        match input.offset_at(len) {
+            Ok(offset) => {
+                let value = input
+                    .iter_offsets()
+                    .take(offset)
+                    .fold(0, |value, (i, byte)| {
+                        let byte = if i == 0 { byte & 0x3f } else { byte };
+                        (value << 8) | u64::from(byte)
+                    });
+                input.next_slice(offset);
+                Ok(value)
+            }
+            Err(e) if <Input as StreamIsPartial>::is_partial_supported() && input.is_partial() => {
+                Err(ParserError::incomplete(input, e))
+            }
+            Err(_needed) => Err(ParserError::from_input(input)),
+        }
+    })
+    .parse_next(input)
+}
+
+/// Decodes up to `bits` bits of LEB128, returning them sign-extended when `signed`
+fn leb128<Input, Error>(input: &mut Input, bits: u32, signed: bool) -> Result<u128, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    let max_len = ((bits + 6) / 7) as usize;
+    let mut value = 0u128;
+    for (offset, byte) in input.iter_offsets().take(max_len) {
+        let payload = u128::from(byte & 0x7f);
+        let shift = 7 * offset as u32;
+        let width = bits - shift;
+        if width < 7 {
+            // The bits that don't fit must be zero, or copies of the sign bit
+            let excess_shift = if signed { width - 1 } else { width };
+            let excess = payload >> excess_shift;
+            if excess != 0 && !(signed && excess == 0x7f >> excess_shift) {
+                return Err(ParserError::from_input(input));
+            }
+        }
+        value |= payload << shift;
+        if byte & 0x80 == 0 {
+            let end = shift + 7;
+            if signed && end < u128::BITS && payload & 0x40 != 0 {
+                value |= u128::MAX << end;
+            }
+            input.next_slice(offset + 1);
+            return Ok(value);
+        }
+    }
+
+    if input.eof_offset() < max_len
+        && <Input as StreamIsPartial>::is_partial_supported()
+        && input.is_partial()
+    {
+        Err(ParserError::incomplete(input, Needed::new(1)))
+    } else {
+        Err(ParserError::from_input(input))
+    }
+}
+
+/// Metadata for parsing unsigned variable-length integers, see [`uleb128`] and [`bits::uint`]
+pub trait VarUint: Sized {
+    #[doc(hidden)]
+    const BITS: u32;
+    #[doc(hidden)]
+    fn from_wide(value: u128, _: sealed::SealedMarker) -> Self;
+}
+
+/// Metadata for parsing signed variable-length integers, see [`sleb128`], [`zigzag`] and
+/// [`bits::int`]
+pub trait VarInt: Sized {
+    #[doc(hidden)]
+    const BITS: u32;
+    #[doc(hidden)]
+    fn from_wide(value: i128, _: sealed::SealedMarker) -> Self;
+}
+
+macro_rules! impl_var_int {
+    ($trait:ident, $from:ty, $($int:ty),+) => {
+        $(
+            impl $trait for $int {
+                const BITS: u32 = <$int>::BITS;
+
+                fn from_wide(value: $from, _: sealed::SealedMarker) -> Self {
+                    value as Self
+                }
+            }
+        )+
+    };
+}
+
+impl_var_int!(VarUint, u128, u8, u16, u32, u64, u128, usize);
+impl_var_int!(VarInt, i128, i8, i16, i32, i64, i128, isize);
+
 /// Get a length-prefixed slice ([TLV](https://en.wikipedia.org/wiki/Type-length-value))
 ///
 /// To apply a parser to the returned slice, see [`length_and_then`].
@@ -2531,3 +2853,7 @@ where
         repeat(n, parser.by_ref()).parse_next(i)
     })
 }
+
+mod sealed {
+    pub struct SealedMarker;
+}
@@ -0,0 +1,378 @@
+//! [Protocol Buffers](https://protobuf.dev/programming-guides/encoding/) wire format parsers
+//!
+//! A message is a sequence of fields, each a [`key`] followed by a value in the key's
+//! [`WireType`]. Values are read with the other [`binary`][crate::binary] parsers:
+//!
+//! | Protobuf type | Parser |
+//! |---------------|--------|
+//! | `int32`, `int64`, `uint32`, `uint64`, `bool`, `enum` | [`uleb128`][crate::binary::uleb128] |
+//! | `sint32`, `sint64` | [`zigzag`][crate::binary::zigzag] |
+//! | `fixed32`, `sfixed32`, `float` | [`le_u32`][crate::binary::le_u32], [`le_i32`][crate::binary::le_i32], [`le_f32`][crate::binary::le_f32] |
+//! | `fixed64`, `sfixed64`, `double` | [`le_u64`][crate::binary::le_u64], [`le_i64`][crate::binary::le_i64], [`le_f64`][crate::binary::le_f64] |
+//! | `string`, `bytes` | [`length_delimited`] |
+//! | embedded messages | [`length_and_then`][crate::binary::length_and_then] |
+//! | packed repeated fields | [`packed`] |
+//! | unknown fields | [`raw_value`] |
+//!
+//! Negative `int32` and `int64` values are encoded as 10 byte varints, so read them as a [`u64`]
+//! and cast.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use winnow::prelude::*;
+//! use winnow::binary::{length_and_then, uleb128};
+//! use winnow::binary::protobuf::{key, length_delimited, packed, raw_value, FieldKey, WireType};
+//! use winnow::combinator::{dispatch, repeat};
+//!
+//! // message Person {
+//! //   string name = 1;
+//! //   uint64 id = 2;
+//! //   repeated uint32 scores = 3;
+//! // }
+//! #[derive(Debug, Default, PartialEq)]
+//! struct Person<'i> {
+//!     name: &'i [u8],
+//!     id: u64,
+//!     scores: Vec<u32>,
+//! }
+//!
+//! enum Field<'i> {
+//!     Name(&'i [u8]),
+//!     Id(u64),
+//!     Scores(Vec<u32>),
+//!     Unknown,
+//! }
+//!
+//! fn field<'i>(input: &mut &'i [u8]) -> ModalResult<Field<'i>> {
+//!     dispatch! {key;
+//!         FieldKey { number: 1, wire_type: WireType::Len } => length_delimited.map(Field::Name),
+//!         FieldKey { number: 2, wire_type: WireType::Varint } => uleb128.map(Field::Id),
+//!         FieldKey { number: 3, wire_type: WireType::Len } => packed(uleb128::<_, u32, _>).map(Field::Scores),
+//!         FieldKey { number: 3, wire_type: WireType::Varint } => uleb128.map(|s| Field::Scores(vec![s])),
+//!         key => raw_value(key).map(|_| Field::Unknown),
+//!     }
+//!     .parse_next(input)
+//! }
+//!
+//! fn person<'i>(input: &mut &'i [u8]) -> ModalResult<Person<'i>> {
+//!     repeat(0.., field)
+//!         .fold(Person::default, |mut person, field| {
+//!             match field {
+//!                 Field::Name(name) => person.name = name,
+//!                 Field::Id(id) => person.id = id,
+//!                 Field::Scores(scores) => person.scores.extend(scores),
+//!                 Field::Unknown => {}
+//!             }
+//!             person
+//!         })
+//!         .parse_next(input)
+//! }
+//!
+//! // Messages in a stream are usually prefixed with their length
+//! fn delimited_person<'i>(input: &mut &'i [u8]) -> ModalResult<Person<'i>> {
+//!     length_and_then(uleb128::<_, u32, _>, person).parse_next(input)
+//! }
+//!
+//! let bytes = b"\x13\x0a\x03Ann\x10\x96\x01\x1a\x02\x05\x07\x25\xff\xff\xff\xff\x18\x09";
+//! assert_eq!(
+//!     delimited_person.parse_peek(&bytes[..]),
+//!     Ok((&b""[..], Person { name: b"Ann", id: 150, scores: vec![5, 7, 9] }))
+//! );
+//! # }
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use crate::binary::{length_and_then, length_take, uleb128};
+use crate::combinator::eof;
+use crate::combinator::repeat;
+use crate::combinator::terminated;
+use crate::combinator::trace;
+use crate::error::ParserError;
+use crate::stream::{Accumulate, Stream, StreamIsPartial, UpdateSlice};
+use crate::token::take;
+use crate::Parser;
+use crate::Result;
+
+/// How deeply [`WireType::SGroup`]s may nest before [`raw_value`] gives up
+const MAX_GROUP_DEPTH: usize = 100;
+
+/// The encoding of a field's value
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WireType {
+    /// A [`uleb128`] or [`zigzag`][crate::binary::zigzag] integer
+    Varint,
+    /// 8 little endian bytes
+    I64,
+    /// A [`length_delimited`] value: strings, bytes, embedded messages and packed repeated fields
+    Len,
+    /// The start of a group, which is deprecated
+    SGroup,
+    /// The end of a group, which is deprecated
+    EGroup,
+    /// 4 little endian bytes
+    I32,
+}
+
+/// The key at the start of each field, see [`key`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FieldKey {
+    /// The field number from the message definition, `1..=536_870_911`
+    pub number: u32,
+    /// The encoding of the value that follows
+    pub wire_type: WireType,
+}
+
+/// Recognizes a field's key, a [`uleb128`] combining the field number and [`WireType`]
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// Field number `0` and unassigned wire types are errors.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::binary::protobuf::{key, FieldKey, WireType};
+///
+/// fn parser(s: &mut &[u8]) -> ModalResult<FieldKey> {
+///     key.parse_next(s)
+/// }
+///
+/// assert_eq!(
+///     parser.parse_peek(&b"\x08\x96\x01"[..]),
+///     Ok((&b"\x96\x01"[..], FieldKey { number: 1, wire_type: WireType::Varint }))
+/// );
+/// assert_eq!(
+///     parser.parse_peek(&b"\xa2\x06"[..]),
+///     Ok((&b""[..], FieldKey { number: 100, wire_type: WireType::Len }))
+/// );
+/// assert!(parser.parse_peek(&b"\x02"[..]).is_err());
+/// assert!(parser.parse_peek(&b"\x0e"[..]).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::protobuf::{key, FieldKey};
+///
+/// fn parser(s: &mut Partial<&[u8]>) -> ModalResult<FieldKey> {
+///     key.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new(&b""[..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// assert_eq!(parser.parse_peek(Partial::new(&b"\xa2"[..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[inline(always)]
+pub fn key<Input, Error>(input: &mut Input) -> Result<FieldKey, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    trace("key", move |input: &mut Input| {
+        let start = input.checkpoint();
+        let key = uleb128::<_, u32, _>(input)?;
+        let wire_type = match key & 0x7 {
+            0 => WireType::Varint,
+            1 => WireType::I64,
+            2 => WireType::Len,
+            3 => WireType::SGroup,
+            4 => WireType::EGroup,
+            5 => WireType::I32,
+            _ => {
+                input.reset(&start);
+                return Err(ParserError::from_input(input));
+            }
+        };
+        let number = key >> 3;
+        if number == 0 {
+            input.reset(&start);
+            return Err(ParserError::from_input(input));
+        }
+        Ok(FieldKey { number, wire_type })
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a [`WireType::Len`] value, returning its content
+///
+/// This is the value of `string` and `bytes` fields. To parse the content, like for embedded
+/// messages, see [`length_and_then`].
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::binary::protobuf::length_delimited;
+///
+/// fn parser<'i>(s: &mut &'i [u8]) -> ModalResult<&'i [u8]> {
+///     length_delimited.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&b"\x07testing\x10"[..]), Ok((&b"\x10"[..], &b"testing"[..])));
+/// assert!(parser.parse_peek(&b"\x07test"[..]).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::protobuf::length_delimited;
+///
+/// fn parser<'i>(s: &mut Partial<&'i [u8]>) -> ModalResult<&'i [u8]> {
+///     length_delimited.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new(&b"\x07test"[..])), Err(ErrMode::Incomplete(Needed::new(3))));
+/// ```
+#[inline(always)]
+pub fn length_delimited<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    trace("length_delimited", length_take(uleb128::<_, u32, _>)).parse_next(input)
+}
+
+/// Recognizes a packed repeated field, a [`WireType::Len`] value holding back-to-back values
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// The values must fill the content exactly.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::binary::{le_u32, uleb128};
+/// use winnow::binary::protobuf::packed;
+///
+/// fn varints(s: &mut &[u8]) -> ModalResult<Vec<u64>> {
+///     packed(uleb128::<_, u64, _>).parse_next(s)
+/// }
+///
+/// assert_eq!(varints.parse_peek(&b"\x06\x03\x8e\x02\x9e\xa7\x05"[..]), Ok((&b""[..], vec![3, 270, 86942])));
+/// assert_eq!(varints.parse_peek(&b"\x00"[..]), Ok((&b""[..], vec![])));
+/// assert!(varints.parse_peek(&b"\x02\x03\x8e"[..]).is_err());
+///
+/// fn fixed(s: &mut &[u8]) -> ModalResult<Vec<u32>> {
+///     packed(le_u32).parse_next(s)
+/// }
+///
+/// assert_eq!(fixed.parse_peek(&b"\x04\x01\x00\x00\x00"[..]), Ok((&b""[..], vec![1])));
+/// assert!(fixed.parse_peek(&b"\x05\x01\x00\x00\x00\x00"[..]).is_err());
+/// # }
+/// ```
+pub fn packed<Input, Output, Accumulator, Error, ParseNext>(
+    mut parser: ParseNext,
+) -> impl Parser<Input, Accumulator, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8> + UpdateSlice + Clone,
+    Accumulator: Accumulate<Output>,
+    ParseNext: Parser<Input, Output, Error>,
+    Error: ParserError<Input>,
+{
+    trace("packed", move |input: &mut Input| {
+        length_and_then(
+            uleb128::<_, u32, _>,
+            terminated(repeat(0.., parser.by_ref()), eof),
+        )
+        .parse_next(input)
+    })
+}
+
+/// Recognizes the value of a field with the given key, returning its encoded bytes
+///
+/// Use this to skip unknown fields, or to keep them for writing back out.
+///
+/// For a [`WireType::SGroup`], the value is the nested fields up to and including the matching
+/// [`WireType::EGroup`] key, with groups nesting at most 100 deep. A [`WireType::EGroup`] key
+/// on its own is an error.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::binary::protobuf::{key, raw_value};
+///
+/// fn field<'i>(s: &mut &'i [u8]) -> ModalResult<&'i [u8]> {
+///     key.flat_map(raw_value).parse_next(s)
+/// }
+///
+/// assert_eq!(field.parse_peek(&b"\x08\x96\x01\x10"[..]), Ok((&b"\x10"[..], &b"\x96\x01"[..])));
+/// assert_eq!(field.parse_peek(&b"\x0d\x01\x02\x03\x04"[..]), Ok((&b""[..], &b"\x01\x02\x03\x04"[..])));
+/// assert_eq!(field.parse_peek(&b"\x12\x01a"[..]), Ok((&b""[..], &b"\x01a"[..])));
+/// assert_eq!(field.parse_peek(&b"\x0b\x10\x01\x0c"[..]), Ok((&b""[..], &b"\x10\x01\x0c"[..])));
+/// assert!(field.parse_peek(&b"\x0b\x10\x01\x14"[..]).is_err());
+/// assert!(field.parse_peek(&b"\x0c"[..]).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::protobuf::{key, raw_value};
+///
+/// fn field<'i>(s: &mut Partial<&'i [u8]>) -> ModalResult<&'i [u8]> {
+///     key.flat_map(raw_value).parse_next(s)
+/// }
+///
+/// assert_eq!(field.parse_peek(Partial::new(&b"\x09\x01\x02"[..])), Err(ErrMode::Incomplete(Needed::new(6))));
+/// assert_eq!(field.parse_peek(Partial::new(&b"\x0b\x10\x01"[..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+pub fn raw_value<Input, Error>(key: FieldKey) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    trace("raw_value", move |input: &mut Input| {
+        let start = input.checkpoint();
+        skip_value(input, key, 0)?;
+        let offset = input.offset_from(&start);
+        input.reset(&start);
+        Ok(input.next_slice(offset))
+    })
+}
+
+fn skip_value<Input, Error>(input: &mut Input, key: FieldKey, depth: usize) -> Result<(), Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    match key.wire_type {
+        WireType::Varint => uleb128::<_, u64, _>.void().parse_next(input),
+        WireType::I64 => take(8usize).void().parse_next(input),
+        WireType::Len => length_delimited.void().parse_next(input),
+        WireType::I32 => take(4usize).void().parse_next(input),
+        WireType::SGroup if depth < MAX_GROUP_DEPTH => loop {
+            let nested = self::key(input)?;
+            match nested.wire_type {
+                WireType::EGroup if nested.number == key.number => return Ok(()),
+                WireType::EGroup => return Err(ParserError::from_input(input)),
+                _ => skip_value(input, nested, depth + 1)?,
+            }
+        },
+        WireType::SGroup | WireType::EGroup => Err(ParserError::from_input(input)),
+    }
+}
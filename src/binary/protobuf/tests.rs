@@ -0,0 +1,162 @@
+use super::*;
+use crate::error::ErrMode;
+use crate::error::InputError;
+use crate::error::Needed;
+use crate::error::TestResult;
+use crate::Partial;
+
+#[cfg(feature = "alloc")]
+use crate::lib::std::vec::Vec;
+
+fn field<'i>(input: &mut &'i [u8]) -> TestResult<&'i [u8], (FieldKey, &'i [u8])> {
+    key.flat_map(|key| raw_value(key).map(move |value| (key, value)))
+        .parse_next(input)
+}
+
+#[test]
+fn key_limits() {
+    assert_eq!(
+        key::<_, InputError<_>>.parse_peek(&[0xf8, 0xff, 0xff, 0xff, 0x0f][..]),
+        Ok((
+            &[][..],
+            FieldKey {
+                number: 536_870_911,
+                wire_type: WireType::Varint
+            }
+        ))
+    );
+    assert!(key::<_, InputError<_>>
+        .parse_peek(&[0xf8, 0xff, 0xff, 0xff, 0x1f][..])
+        .is_err());
+    assert!(key::<_, InputError<_>>.parse_peek(&[0x07][..]).is_err());
+}
+
+#[test]
+fn skip_every_wire_type() {
+    let message = [
+        0x08, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01, // 1: -1 as int64
+        0x11, 1, 2, 3, 4, 5, 6, 7, 8, // 2: fixed64
+        0x1a, 0x02, b'h', b'i', // 3: bytes
+        0x23, 0x08, 0x01, 0x2b, 0x2c, 0x24, // 4: group holding 1: 1 and an empty group 5
+        0x35, 1, 2, 3, 4, // 6: fixed32
+    ];
+    let mut input = &message[..];
+    let mut numbers = [0; 5];
+    for number in &mut numbers {
+        let (key, _) = field.parse_next(&mut input).unwrap();
+        *number = key.number;
+    }
+    assert_eq!(numbers, [1, 2, 3, 4, 6]);
+    assert!(input.is_empty());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn group_depth_limit() {
+    fn nested(depth: usize) -> Vec<u8> {
+        let mut message = Vec::new();
+        message.resize(depth, 0x0b);
+        message.resize(2 * depth, 0x0c);
+        message
+    }
+
+    let message = nested(MAX_GROUP_DEPTH);
+    assert_eq!(
+        field
+            .parse_peek(&message[..])
+            .map(|(rest, (_, value))| (rest, value.len())),
+        Ok((&[][..], message.len() - 1))
+    );
+    let message = nested(MAX_GROUP_DEPTH + 1);
+    assert!(field.parse_peek(&message[..]).is_err());
+}
+
+#[test]
+fn unmatched_groups() {
+    // End without start
+    assert!(field.parse_peek(&[0x0c][..]).is_err());
+    // Start without end
+    assert!(field.parse_peek(&[0x0b, 0x08, 0x01][..]).is_err());
+    // Crossed ends
+    assert!(field.parse_peek(&[0x0b, 0x13, 0x0c, 0x14][..]).is_err());
+}
+
+#[test]
+fn length_beyond_input() {
+    assert!(field
+        .parse_peek(&[0x0a, 0xff, 0xff, 0xff, 0xff, 0x0f][..])
+        .is_err());
+    assert!(field
+        .parse_peek(&[0x0a, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01][..])
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn packed_values() {
+    fn sints<'i>(input: &mut &'i [u8]) -> TestResult<&'i [u8], Vec<i32>> {
+        packed(crate::binary::zigzag::<_, i32, _>).parse_next(input)
+    }
+    assert_eq!(
+        sints.parse_peek(&[0x03, 0x01, 0x02, 0x03, 0x00][..]),
+        Ok((&[0x00][..], vec![-1, 1, -2]))
+    );
+    // Truncated value inside the length
+    assert!(sints.parse_peek(&[0x02, 0x01, 0x80, 0x00][..]).is_err());
+
+    fn count<'i>(input: &mut &'i [u8]) -> TestResult<&'i [u8], usize> {
+        packed(crate::binary::le_u64).parse_next(input)
+    }
+    assert_eq!(count.parse_peek(&[0x10; 17][..]), Ok((&[][..], 2)));
+}
+
+#[test]
+fn partial() {
+    fn field<'i>(
+        input: &mut Partial<&'i [u8]>,
+    ) -> TestResult<Partial<&'i [u8]>, (FieldKey, &'i [u8])> {
+        key.flat_map(|key| raw_value(key).map(move |value| (key, value)))
+            .parse_next(input)
+    }
+
+    assert_eq!(
+        field.parse_peek(Partial::new(&[0x0a, 0x03, b'a'][..])),
+        Err(ErrMode::Incomplete(Needed::new(2)))
+    );
+    assert_eq!(
+        field.parse_peek(Partial::new(&[0x0a, 0x83][..])),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+    assert_eq!(
+        field.parse_peek(Partial::new(&[0x0b, 0x08, 0x01][..])),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+    assert_eq!(
+        field.parse_peek(Partial::new(&[0x0b, 0x08, 0x01, 0x0c, 0x08][..])),
+        Ok((
+            Partial::new(&[0x08][..]),
+            (
+                FieldKey {
+                    number: 1,
+                    wire_type: WireType::SGroup
+                },
+                &[0x08, 0x01, 0x0c][..]
+            )
+        ))
+    );
+
+    #[cfg(feature = "alloc")]
+    {
+        fn fixed<'i>(input: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, Vec<u32>> {
+            packed(crate::binary::le_u32).parse_next(input)
+        }
+        assert_eq!(
+            fixed.parse_peek(Partial::new(&[0x08, 0x01, 0x00, 0x00, 0x00][..])),
+            Err(ErrMode::Incomplete(Needed::new(4)))
+        );
+        assert_eq!(
+            fixed.parse_peek(Partial::new(&[0x04, 0x01, 0x00, 0x00, 0x00][..])),
+            Ok((Partial::new(&[][..]), vec![1]))
+        );
+    }
+}
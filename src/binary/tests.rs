@@ -8,6 +8,11 @@ use crate::prelude::*;
 mod complete {
     use super::*;
 
+    use proptest::prelude::*;
+
+    #[cfg(feature = "alloc")]
+    use crate::error::InputError;
+
     #[test]
     fn i8_tests() {
         assert_parse!(
@@ -1158,6 +1163,120 @@ Ok(
             .raw()
         );
     }
+    #[test]
+    fn uleb128_tests() {
+        fn u8_<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], u8> {
+            uleb128.parse_next(i)
+        }
+        assert_eq!(u8_.parse_peek(&[0x7f][..]), Ok((&[][..], 0x7f)));
+        assert_eq!(u8_.parse_peek(&[0xff, 0x01][..]), Ok((&[][..], 0xff)));
+        assert_eq!(
+            u8_.parse_peek(&[0x80, 0x00, 0x00][..]),
+            Ok((&[0x00][..], 0))
+        );
+        assert!(u8_.parse_peek(&[0x80, 0x02][..]).is_err());
+        assert!(u8_.parse_peek(&[0x80, 0x80, 0x00][..]).is_err());
+
+        fn u128_<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], u128> {
+            uleb128.parse_next(i)
+        }
+        let mut max = [0xff; 19];
+        max[18] = 0x03;
+        assert_eq!(u128_.parse_peek(&max[..]), Ok((&[][..], u128::MAX)));
+        max[18] = 0x07;
+        assert!(u128_.parse_peek(&max[..]).is_err());
+
+        assert!(u128_.parse_peek(&[][..]).is_err());
+        assert!(u128_.parse_peek(&[0x80][..]).is_err());
+    }
+
+    #[test]
+    fn sleb128_tests() {
+        fn i8_<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], i8> {
+            sleb128.parse_next(i)
+        }
+        assert_eq!(i8_.parse_peek(&[0xff, 0x00][..]), Ok((&[][..], 127)));
+        assert_eq!(i8_.parse_peek(&[0x80, 0x7f][..]), Ok((&[][..], -128)));
+        assert_eq!(i8_.parse_peek(&[0xff, 0x7f][..]), Ok((&[][..], -1)));
+        assert!(i8_.parse_peek(&[0x80, 0x01][..]).is_err());
+        assert!(i8_.parse_peek(&[0xff, 0x7e][..]).is_err());
+
+        fn i128_<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], i128> {
+            sleb128.parse_next(i)
+        }
+        let mut min = [0x80; 19];
+        min[18] = 0x7e;
+        assert_eq!(i128_.parse_peek(&min[..]), Ok((&[][..], i128::MIN)));
+        min[18] = 0x7d;
+        assert!(i128_.parse_peek(&min[..]).is_err());
+        let mut max = [0xff; 19];
+        max[18] = 0x01;
+        assert_eq!(i128_.parse_peek(&max[..]), Ok((&[][..], i128::MAX)));
+    }
+
+    #[test]
+    fn zigzag_tests() {
+        fn i8_<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], i8> {
+            zigzag.parse_next(i)
+        }
+        assert_eq!(i8_.parse_peek(&[0x00][..]), Ok((&[][..], 0)));
+        assert_eq!(i8_.parse_peek(&[0xfe, 0x01][..]), Ok((&[][..], 127)));
+        assert_eq!(i8_.parse_peek(&[0xff, 0x01][..]), Ok((&[][..], -128)));
+        assert!(i8_.parse_peek(&[0x80, 0x02][..]).is_err());
+    }
+
+    #[test]
+    fn quic_varint_tests() {
+        fn quic<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], u64> {
+            quic_varint.parse_next(i)
+        }
+        assert_eq!(
+            quic.parse_peek(&[0xc2, 0x19, 0x7c, 0x5e, 0xff, 0x14, 0xe8, 0x8c, 0x00][..]),
+            Ok((&[0x00][..], 151288809941952652))
+        );
+        assert_eq!(
+            quic.parse_peek(&[0xff; 8][..]),
+            Ok((&[][..], (1 << 62) - 1))
+        );
+        assert!(quic.parse_peek(&[][..]).is_err());
+        assert!(quic.parse_peek(&[0xc2, 0x19][..]).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    fn encode_leb128(mut value: i128, signed: bool) -> crate::lib::std::vec::Vec<u8> {
+        let mut bytes = crate::lib::std::vec::Vec::new();
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            let done = if signed {
+                (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0)
+            } else {
+                value == 0
+            };
+            if done {
+                bytes.push(byte);
+                return bytes;
+            }
+            bytes.push(byte | 0x80);
+        }
+    }
+
+    proptest! {
+        #[test]
+        #[cfg(feature = "alloc")]
+        #[cfg_attr(miri, ignore)]  // See https://github.com/AltSysrq/proptest/issues/253
+        fn leb128_round_trip(unsigned in any::<u64>(), signed in any::<i64>()) {
+            let bytes = encode_leb128(i128::from(unsigned), false);
+            prop_assert_eq!(uleb128::<_, u64, InputError<_>>.parse_peek(&bytes[..]), Ok((&[][..], unsigned)));
+
+            let bytes = encode_leb128(i128::from(signed), true);
+            prop_assert_eq!(sleb128::<_, i64, InputError<_>>.parse_peek(&bytes[..]), Ok((&[][..], signed)));
+
+            let zigzagged = (signed << 1) ^ (signed >> 63);
+            let bytes = encode_leb128(i128::from(zigzagged as u64), false);
+            prop_assert_eq!(zigzag::<_, i64, InputError<_>>.parse_peek(&bytes[..]), Ok((&[][..], signed)));
+        }
+    }
 }
 
 mod partial {
@@ -3715,4 +3834,43 @@ Ok(
             .raw()
         );
     }
+
+    #[test]
+    fn varint_tests() {
+        use crate::error::ErrMode;
+
+        fn uleb<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, u16> {
+            uleb128.parse_next(i)
+        }
+        assert_eq!(
+            uleb.parse_peek(Partial::new(&[0xff, 0xff][..])),
+            Err(ErrMode::Incomplete(Needed::new(1)))
+        );
+        assert_eq!(
+            uleb.parse_peek(Partial::new(&[0xff, 0xff, 0x03][..])),
+            Ok((Partial::new(&[][..]), u16::MAX))
+        );
+        // Too long, regardless of what follows
+        assert!(matches!(
+            uleb.parse_peek(Partial::new(&[0x80, 0x80, 0x80][..])),
+            Err(ErrMode::Backtrack(_))
+        ));
+        // Overflow, regardless of what follows
+        assert!(matches!(
+            uleb.parse_peek(Partial::new(&[0xff, 0xff, 0x04][..])),
+            Err(ErrMode::Backtrack(_))
+        ));
+
+        fn quic<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, u64> {
+            quic_varint.parse_next(i)
+        }
+        assert_eq!(
+            quic.parse_peek(Partial::new(&[0x9d, 0x7f][..])),
+            Err(ErrMode::Incomplete(Needed::new(2)))
+        );
+        assert_eq!(
+            quic.parse_peek(Partial::new(&[0x9d, 0x7f, 0x3e, 0x7d][..])),
+            Ok((Partial::new(&[][..]), 494878333))
+        );
+    }
 }
@@ -0,0 +1,458 @@
+use crate::combinator::trace;
+use crate::error::ParserError;
+use crate::lib::std::convert::Infallible;
+use crate::stream::Stream;
+use crate::Parser;
+
+/// Parse an expression of `operand`s and operators, using precedence climbing
+///
+/// Operators are added with [`Expression::prefix`], [`Expression::postfix`], and
+/// [`Expression::infix`].  Each takes a parser for the operator, typically an [`alt`] or
+/// [`dispatch!`] over the operator table, which reports the operator's binding power along with a
+/// value identifying the operator, and a closure to fold the operator into the expression.
+///
+/// Operators with a higher binding power bind tighter.  Prefix operators apply to everything
+/// that binds tighter than them, so with `-` at a lower power than the `.` postfix, `-a.b` is
+/// `-(a.b)`, while at a higher power it is `(-a).b`.  Infix operators can be left or right
+/// associative, or non-associative, see [`Assoc`].
+///
+/// Operator parsers must always consume input when they succeed.
+///
+/// [`alt`]: crate::combinator::alt
+/// [`dispatch!`]: crate::combinator::dispatch
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::dec_int;
+/// use winnow::ascii::multispace0;
+/// use winnow::combinator::alt;
+/// use winnow::combinator::delimited;
+/// use winnow::combinator::dispatch;
+/// use winnow::combinator::empty;
+/// use winnow::combinator::expression;
+/// use winnow::combinator::fail;
+/// use winnow::combinator::Assoc;
+/// use winnow::token::any;
+///
+/// fn expr(input: &mut &str) -> ModalResult<i64> {
+///     expression(delimited(multispace0, operand, multispace0))
+///         .prefix(('-', multispace0).value((7, ())), |(), value: i64| -value)
+///         .postfix(('!', multispace0).value((9, ())), |value: i64, ()| {
+///             (1..=value).product()
+///         })
+///         .infix(
+///             dispatch! {(any, multispace0).map(|(op, _)| op);
+///                 '+' => empty.value((Assoc::Left(3), '+')),
+///                 '-' => empty.value((Assoc::Left(3), '-')),
+///                 '*' => empty.value((Assoc::Left(5), '*')),
+///                 '^' => empty.value((Assoc::Right(8), '^')),
+///                 _ => fail,
+///             },
+///             |lhs: i64, op, rhs: i64| match op {
+///                 '+' => lhs + rhs,
+///                 '-' => lhs - rhs,
+///                 '*' => lhs * rhs,
+///                 _ => lhs.pow(rhs as u32),
+///             },
+///         )
+///         .parse_next(input)
+/// }
+///
+/// fn operand(input: &mut &str) -> ModalResult<i64> {
+///     alt((dec_int, delimited('(', expr, ')'))).parse_next(input)
+/// }
+///
+/// assert_eq!(expr.parse("1 + 2 * 3"), Ok(7));
+/// assert_eq!(expr.parse("8 - 2 - 1"), Ok(5));
+/// assert_eq!(expr.parse("2 ^ 3 ^ 2"), Ok(512));
+/// assert_eq!(expr.parse("-2 ^ 2"), Ok(-4));
+/// assert_eq!(expr.parse("-3!"), Ok(-6));
+/// assert_eq!(expr.parse("(1 + 2)! * -(2)"), Ok(-12));
+/// ```
+#[doc(alias = "pratt")]
+#[doc(alias = "precedence")]
+#[doc(alias = "precedence_climbing")]
+#[doc(alias = "operator_precedence")]
+pub fn expression<Input, Output, Error, ParseOperand>(
+    operand: ParseOperand,
+) -> Expression<Input, Output, Error, ParseOperand, NoOperators, NoOperators, NoOperators>
+where
+    Input: Stream,
+    ParseOperand: Parser<Input, Output, Error>,
+    Error: ParserError<Input>,
+{
+    Expression {
+        operand,
+        prefix: NoOperators,
+        postfix: NoOperators,
+        infix: NoOperators,
+        i: Default::default(),
+        o: Default::default(),
+        e: Default::default(),
+    }
+}
+
+/// Associativity and binding power of an infix operator, see [`expression`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Assoc {
+    /// `a - b - c` is `(a - b) - c`
+    Left(u32),
+    /// `a ^ b ^ c` is `a ^ (b ^ c)`
+    Right(u32),
+    /// `a == b == c` is an error
+    Neither(u32),
+}
+
+impl Assoc {
+    fn power(self) -> u32 {
+        match self {
+            Self::Left(power) | Self::Right(power) | Self::Neither(power) => power,
+        }
+    }
+}
+
+/// Customizable [`Parser`] implementation for [`expression`]
+pub struct Expression<Input, Output, Error, ParseOperand, Prefix, Postfix, Infix> {
+    operand: ParseOperand,
+    prefix: Prefix,
+    postfix: Postfix,
+    infix: Infix,
+    i: core::marker::PhantomData<Input>,
+    o: core::marker::PhantomData<Output>,
+    e: core::marker::PhantomData<Error>,
+}
+
+impl<Input, Output, Error, ParseOperand, Prefix, Postfix, Infix>
+    Expression<Input, Output, Error, ParseOperand, Prefix, Postfix, Infix>
+where
+    Input: Stream,
+    ParseOperand: Parser<Input, Output, Error>,
+    Error: ParserError<Input>,
+{
+    /// Operators that come before their operand
+    ///
+    /// `parser` returns the binding power and the operator, which `fold` applies to the operand.
+    pub fn prefix<ParseOperator, Operator, Fold>(
+        self,
+        parser: ParseOperator,
+        fold: Fold,
+    ) -> Expression<
+        Input,
+        Output,
+        Error,
+        ParseOperand,
+        Operators<ParseOperator, Fold, Operator>,
+        Postfix,
+        Infix,
+    >
+    where
+        ParseOperator: Parser<Input, (u32, Operator), Error>,
+        Fold: FnMut(Operator, Output) -> Output,
+    {
+        Expression {
+            operand: self.operand,
+            prefix: Operators::new(parser, fold),
+            postfix: self.postfix,
+            infix: self.infix,
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+
+    /// Operators that come after their operand, like function calls or indexing
+    ///
+    /// `parser` returns the binding power and the operator, which `fold` applies to the operand.
+    pub fn postfix<ParseOperator, Operator, Fold>(
+        self,
+        parser: ParseOperator,
+        fold: Fold,
+    ) -> Expression<
+        Input,
+        Output,
+        Error,
+        ParseOperand,
+        Prefix,
+        Operators<ParseOperator, Fold, Operator>,
+        Infix,
+    >
+    where
+        ParseOperator: Parser<Input, (u32, Operator), Error>,
+        Fold: FnMut(Output, Operator) -> Output,
+    {
+        Expression {
+            operand: self.operand,
+            prefix: self.prefix,
+            postfix: Operators::new(parser, fold),
+            infix: self.infix,
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+
+    /// Operators that come between two operands
+    ///
+    /// `parser` returns the associativity and binding power and the operator, which `fold`
+    /// applies to the operands.
+    pub fn infix<ParseOperator, Operator, Fold>(
+        self,
+        parser: ParseOperator,
+        fold: Fold,
+    ) -> Expression<
+        Input,
+        Output,
+        Error,
+        ParseOperand,
+        Prefix,
+        Postfix,
+        Operators<ParseOperator, Fold, Operator>,
+    >
+    where
+        ParseOperator: Parser<Input, (Assoc, Operator), Error>,
+        Fold: FnMut(Output, Operator, Output) -> Output,
+    {
+        Expression {
+            operand: self.operand,
+            prefix: self.prefix,
+            postfix: self.postfix,
+            infix: Operators::new(parser, fold),
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+}
+
+impl<Input, Output, Error, ParseOperand, Prefix, Postfix, Infix>
+    Expression<Input, Output, Error, ParseOperand, Prefix, Postfix, Infix>
+where
+    Input: Stream,
+    ParseOperand: Parser<Input, Output, Error>,
+    Error: ParserError<Input>,
+    Prefix: table::PrefixTable<Input, Output, Error>,
+    Postfix: table::PostfixTable<Input, Output, Error>,
+    Infix: table::InfixTable<Input, Output, Error>,
+{
+    fn parse_power(&mut self, input: &mut Input, min_power: u32) -> Result<Output, Error> {
+        let mut lhs = match operator(input, |i| self.prefix.parse_operator(i))? {
+            Some((power, op)) => {
+                let rhs = self.parse_power(input, power)?;
+                self.prefix.fold(op, rhs)
+            }
+            None => self.operand.parse_next(input)?,
+        };
+
+        let mut non_assoc = None;
+        loop {
+            let start = input.checkpoint();
+            if let Some((power, op)) = operator(input, |i| self.postfix.parse_operator(i))? {
+                if power < min_power {
+                    input.reset(&start);
+                    break;
+                }
+                lhs = self.postfix.fold(lhs, op);
+                continue;
+            }
+
+            let Some((assoc, op)) = operator(input, |i| self.infix.parse_operator(i))? else {
+                break;
+            };
+            let power = assoc.power();
+            if power < min_power {
+                input.reset(&start);
+                break;
+            }
+            if non_assoc == Some(power) {
+                input.reset(&start);
+                return Err(ParserError::from_input(input));
+            }
+            let rhs_power = match assoc {
+                Assoc::Left(_) | Assoc::Neither(_) => power.saturating_add(1),
+                Assoc::Right(_) => power,
+            };
+            let rhs = self.parse_power(input, rhs_power)?;
+            lhs = self.infix.fold(lhs, op, rhs);
+            non_assoc = matches!(assoc, Assoc::Neither(_)).then_some(power);
+        }
+
+        Ok(lhs)
+    }
+}
+
+/// Run an operator parser, treating backtracking as the operator not being present
+fn operator<Input, Operator, Error>(
+    input: &mut Input,
+    mut parser: impl FnMut(&mut Input) -> Result<Operator, Error>,
+) -> Result<Option<Operator>, Error>
+where
+    Input: Stream,
+    Error: ParserError<Input>,
+{
+    let start = input.checkpoint();
+    let len = input.eof_offset();
+    match parser(input) {
+        Ok(op) => {
+            // infinite loop check: the parser must always consume
+            if input.eof_offset() == len {
+                return Err(ParserError::assert(
+                    input,
+                    "`expression` operators must always consume",
+                ));
+            }
+            Ok(Some(op))
+        }
+        Err(err) if err.is_backtrack() => {
+            input.reset(&start);
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+impl<Input, Output, Error, ParseOperand, Prefix, Postfix, Infix> Parser<Input, Output, Error>
+    for Expression<Input, Output, Error, ParseOperand, Prefix, Postfix, Infix>
+where
+    Input: Stream,
+    ParseOperand: Parser<Input, Output, Error>,
+    Error: ParserError<Input>,
+    Prefix: table::PrefixTable<Input, Output, Error>,
+    Postfix: table::PostfixTable<Input, Output, Error>,
+    Infix: table::InfixTable<Input, Output, Error>,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut Input) -> Result<Output, Error> {
+        trace("expression", |input: &mut Input| self.parse_power(input, 0)).parse_next(input)
+    }
+}
+
+/// Placeholder for operators not added to an [`Expression`]
+pub struct NoOperators;
+
+/// Operators added to an [`Expression`]
+pub struct Operators<ParseOperator, Fold, Operator> {
+    parser: ParseOperator,
+    fold: Fold,
+    op: core::marker::PhantomData<Operator>,
+}
+
+impl<ParseOperator, Fold, Operator> Operators<ParseOperator, Fold, Operator> {
+    fn new(parser: ParseOperator, fold: Fold) -> Self {
+        Self {
+            parser,
+            fold,
+            op: Default::default(),
+        }
+    }
+}
+
+mod table {
+    use super::*;
+
+    pub trait PrefixTable<Input, Output, Error> {
+        type Operator;
+        fn parse_operator(&mut self, input: &mut Input) -> Result<(u32, Self::Operator), Error>;
+        fn fold(&mut self, op: Self::Operator, operand: Output) -> Output;
+    }
+
+    pub trait PostfixTable<Input, Output, Error> {
+        type Operator;
+        fn parse_operator(&mut self, input: &mut Input) -> Result<(u32, Self::Operator), Error>;
+        fn fold(&mut self, operand: Output, op: Self::Operator) -> Output;
+    }
+
+    pub trait InfixTable<Input, Output, Error> {
+        type Operator;
+        fn parse_operator(&mut self, input: &mut Input) -> Result<(Assoc, Self::Operator), Error>;
+        fn fold(&mut self, lhs: Output, op: Self::Operator, rhs: Output) -> Output;
+    }
+
+    impl<Input, Output, Error> PrefixTable<Input, Output, Error> for NoOperators
+    where
+        Input: Stream,
+        Error: ParserError<Input>,
+    {
+        type Operator = Infallible;
+        fn parse_operator(&mut self, input: &mut Input) -> Result<(u32, Self::Operator), Error> {
+            Err(ParserError::from_input(input))
+        }
+        fn fold(&mut self, op: Self::Operator, _operand: Output) -> Output {
+            match op {}
+        }
+    }
+
+    impl<Input, Output, Error> PostfixTable<Input, Output, Error> for NoOperators
+    where
+        Input: Stream,
+        Error: ParserError<Input>,
+    {
+        type Operator = Infallible;
+        fn parse_operator(&mut self, input: &mut Input) -> Result<(u32, Self::Operator), Error> {
+            Err(ParserError::from_input(input))
+        }
+        fn fold(&mut self, _operand: Output, op: Self::Operator) -> Output {
+            match op {}
+        }
+    }
+
+    impl<Input, Output, Error> InfixTable<Input, Output, Error> for NoOperators
+    where
+        Input: Stream,
+        Error: ParserError<Input>,
+    {
+        type Operator = Infallible;
+        fn parse_operator(&mut self, input: &mut Input) -> Result<(Assoc, Self::Operator), Error> {
+            Err(ParserError::from_input(input))
+        }
+        fn fold(&mut self, _lhs: Output, op: Self::Operator, _rhs: Output) -> Output {
+            match op {}
+        }
+    }
+
+    impl<Input, Output, Error, ParseOperator, Fold, Operator> PrefixTable<Input, Output, Error>
+        for Operators<ParseOperator, Fold, Operator>
+    where
+        ParseOperator: Parser<Input, (u32, Operator), Error>,
+        Fold: FnMut(Operator, Output) -> Output,
+    {
+        type Operator = Operator;
+        fn parse_operator(&mut self, input: &mut Input) -> Result<(u32, Self::Operator), Error> {
+            self.parser.parse_next(input)
+        }
+        fn fold(&mut self, op: Self::Operator, operand: Output) -> Output {
+            (self.fold)(op, operand)
+        }
+    }
+
+    impl<Input, Output, Error, ParseOperator, Fold, Operator> PostfixTable<Input, Output, Error>
+        for Operators<ParseOperator, Fold, Operator>
+    where
+        ParseOperator: Parser<Input, (u32, Operator), Error>,
+        Fold: FnMut(Output, Operator) -> Output,
+    {
+        type Operator = Operator;
+        fn parse_operator(&mut self, input: &mut Input) -> Result<(u32, Self::Operator), Error> {
+            self.parser.parse_next(input)
+        }
+        fn fold(&mut self, operand: Output, op: Self::Operator) -> Output {
+            (self.fold)(operand, op)
+        }
+    }
+
+    impl<Input, Output, Error, ParseOperator, Fold, Operator> InfixTable<Input, Output, Error>
+        for Operators<ParseOperator, Fold, Operator>
+    where
+        ParseOperator: Parser<Input, (Assoc, Operator), Error>,
+        Fold: FnMut(Output, Operator, Output) -> Output,
+    {
+        type Operator = Operator;
+        fn parse_operator(&mut self, input: &mut Input) -> Result<(Assoc, Self::Operator), Error> {
+            self.parser.parse_next(input)
+        }
+        fn fold(&mut self, lhs: Output, op: Self::Operator, rhs: Output) -> Output {
+            (self.fold)(lhs, op, rhs)
+        }
+    }
+}
@@ -13,7 +13,7 @@ use crate::lib::std::ops::Range;
 #[cfg(feature = "std")]
 use crate::stream::Recover;
 use crate::stream::StreamIsPartial;
-use crate::stream::{Location, Stream};
+use crate::stream::{LineLocation, Location, Position, Stream};
 use crate::*;
 
 /// [`Parser`] implementation for [`Parser::by_ref`]
@@ -493,6 +493,60 @@ where
     }
 }
 
+/// [`Parser`] implementation for [`Parser::line_span`]
+pub struct LineSpan<F, I, O, E>
+where
+    F: Parser<I, O, E>,
+    I: Stream + LineLocation,
+{
+    pub(crate) parser: F,
+    pub(crate) i: core::marker::PhantomData<I>,
+    pub(crate) o: core::marker::PhantomData<O>,
+    pub(crate) e: core::marker::PhantomData<E>,
+}
+
+impl<I, O, E, F> Parser<I, Range<Position>, E> for LineSpan<F, I, O, E>
+where
+    F: Parser<I, O, E>,
+    I: Stream + LineLocation,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut I) -> Result<Range<Position>, E> {
+        let start = input.current_token_start_position();
+        self.parser.parse_next(input).map(move |_| {
+            let end = input.previous_token_end_position();
+            start..end
+        })
+    }
+}
+
+/// [`Parser`] implementation for [`Parser::with_line_span`]
+pub struct WithLineSpan<F, I, O, E>
+where
+    F: Parser<I, O, E>,
+    I: Stream + LineLocation,
+{
+    pub(crate) parser: F,
+    pub(crate) i: core::marker::PhantomData<I>,
+    pub(crate) o: core::marker::PhantomData<O>,
+    pub(crate) e: core::marker::PhantomData<E>,
+}
+
+impl<F, I, O, E> Parser<I, (O, Range<Position>), E> for WithLineSpan<F, I, O, E>
+where
+    F: Parser<I, O, E>,
+    I: Stream + LineLocation,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut I) -> Result<(O, Range<Position>), E> {
+        let start = input.current_token_start_position();
+        self.parser.parse_next(input).map(move |output| {
+            let end = input.previous_token_end_position();
+            (output, (start..end))
+        })
+    }
+}
+
 /// [`Parser`] implementation for [`Parser::output_into`]
 pub struct OutputInto<F, I, O, O2, E>
 where
@@ -0,0 +1,332 @@
+use core::any::TypeId;
+use core::marker::PhantomData;
+
+use crate::combinator::trace;
+use crate::error::ParserError;
+use crate::stream::Memo;
+use crate::stream::Relocate;
+use crate::stream::Stream;
+use crate::Parser;
+
+/// Run `parser` at most once per offset, replaying the cached result on later attempts
+///
+/// Wrapping rules that get re-tried by [`alt`][crate::combinator::alt] after backtracking turns
+/// them into packrat rules, bounding the parse to linear time.  Results, including failures, are
+/// stored in the [`MemoCache`][crate::stream::MemoCache] of a
+/// [`Memoized`][crate::stream::Memoized] stream.  [`ErrMode::Incomplete`][crate::error::ErrMode::Incomplete]
+/// is never cached as more data may change the result.
+///
+/// A rule is identified by the type of `parser`, so each function is a separate rule.  Only
+/// zero-sized parsers, like functions and closures that capture nothing, can be memoized, as
+/// their type alone decides what they parse.  Anything else, like a closure capturing a keyword,
+/// fails to compile:
+///
+/// ```rust,compile_fail
+/// # use winnow::prelude::*;
+/// use winnow::combinator::memoize;
+/// use winnow::stream::MemoCache;
+/// use winnow::stream::Memoized;
+///
+/// type Stream<'i, 'c> = Memoized<'c, &'i str>;
+///
+/// fn keyword(name: &'static str) -> impl FnMut(&mut Stream<'_, '_>) -> ModalResult<&'static str> {
+///     move |input| name.value(name).parse_next(input)
+/// }
+///
+/// let cache = MemoCache::new();
+/// let mut input = Memoized::new("a", &cache);
+/// let output = memoize(keyword("a")).parse_next(&mut input);
+/// ```
+///
+/// Memoized rules should not have side effects, like modifying [`Stateful`][crate::stream::Stateful]
+/// state, as they are skipped when replaying a cached result.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::alpha1;
+/// use winnow::combinator::alt;
+/// use winnow::combinator::memoize;
+/// use winnow::stream::MemoCache;
+/// use winnow::stream::Memoized;
+///
+/// type Stream<'i, 'c> = Memoized<'c, &'i str>;
+///
+/// fn word(input: &mut Stream<'_, '_>) -> ModalResult<String> {
+///     alpha1.map(ToOwned::to_owned).parse_next(input)
+/// }
+///
+/// fn statement(input: &mut Stream<'_, '_>) -> ModalResult<(String, char)> {
+///     alt((
+///         (memoize(word), ';'),
+///         (memoize(word), '.'),
+///     ))
+///     .parse_next(input)
+/// }
+///
+/// let cache = MemoCache::new();
+/// let output = statement.parse(Memoized::new("end.", &cache)).unwrap();
+/// assert_eq!(output, ("end".to_owned(), '.'));
+/// ```
+#[doc(alias = "packrat")]
+pub fn memoize<Input, Output, Error, ParseNext>(
+    mut parser: ParseNext,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream + Memo,
+    Output: Clone + 'static,
+    Error: ParserError<Input> + Clone + 'static,
+    ParseNext: Parser<Input, Output, Error> + 'static,
+{
+    let rule = rule_id::<Memoize, ParseNext>();
+    trace("memoize", move |input: &mut Input| {
+        let offset = input.memo_offset();
+        if let Some(entry) = input
+            .memo_cache()
+            .get::<Result<(Output, usize), Error>>(rule, offset)
+        {
+            return entry.map(|(output, consumed)| {
+                let _ = input.next_slice(consumed);
+                output
+            });
+        }
+
+        let start = input.checkpoint();
+        let result = parser.parse_next(input);
+        let entry = match &result {
+            Ok(output) => Ok((output.clone(), input.offset_from(&start))),
+            Err(err) if err.is_incomplete() => return result,
+            Err(err) => Err(err.clone()),
+        };
+        let len = entry.as_ref().ok().map(|(_, consumed)| *consumed);
+        input.memo_cache().insert(rule, offset, entry, len);
+        result
+    })
+}
+
+/// Allow `parser` to refer to itself as its first step, growing the match one iteration at a time
+///
+/// Left-recursive grammars, like `expr = expr '+' term | term`, would otherwise recurse forever.
+/// This is a [`memoize`]d rule that first records a failure as a seed for its offset, so the
+/// recursive call falls through to the other alternatives.  The rule is then re-run, with
+/// recursive calls replaying the previous result, for as long as each attempt consumes more
+/// input than the last.  The longest match is returned, making the rule left-associative.  Errors
+/// other than [`ErrMode::Backtrack`][crate::error::ErrMode::Backtrack], like from
+/// [`cut_err`][crate::combinator::cut_err], are returned as-is, even after an earlier match.
+///
+/// For indirect left recursion, like `a = b 'x' | 'y'` and `b = a 'z'`, wrap one rule in each
+/// cycle with `left_recursive`.  The other rules in the cycle must not be [`memoize`]d, as their
+/// results would be cached for an intermediate iteration.
+///
+/// As with [`memoize`], `parser` must be zero-sized to identify the rule.  The rule is best
+/// written as its own function, see the example.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::dec_int;
+/// use winnow::combinator::alt;
+/// use winnow::combinator::left_recursive;
+/// use winnow::stream::MemoCache;
+/// use winnow::stream::Memoized;
+///
+/// type Stream<'i, 'c> = Memoized<'c, &'i str>;
+///
+/// fn expr(input: &mut Stream<'_, '_>) -> ModalResult<i64> {
+///     left_recursive(expr_rule).parse_next(input)
+/// }
+///
+/// // expr = expr '-' term | term
+/// fn expr_rule(input: &mut Stream<'_, '_>) -> ModalResult<i64> {
+///     alt(((expr, '-', term).map(|(l, _, r)| l - r), term)).parse_next(input)
+/// }
+///
+/// fn term(input: &mut Stream<'_, '_>) -> ModalResult<i64> {
+///     left_recursive(term_rule).parse_next(input)
+/// }
+///
+/// // term = term '*' atom | atom
+/// fn term_rule(input: &mut Stream<'_, '_>) -> ModalResult<i64> {
+///     alt(((term, '*', atom).map(|(l, _, r)| l * r), atom)).parse_next(input)
+/// }
+///
+/// fn atom(input: &mut Stream<'_, '_>) -> ModalResult<i64> {
+///     dec_int.parse_next(input)
+/// }
+///
+/// let cache = MemoCache::new();
+/// assert_eq!(expr.parse(Memoized::new("10-2*3-1", &cache)).unwrap(), 3);
+/// ```
+#[doc(alias = "seed_growing")]
+pub fn left_recursive<Input, Output, Error, ParseNext>(
+    mut parser: ParseNext,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream + Memo,
+    Output: Clone + 'static,
+    Error: ParserError<Input> + Clone + 'static,
+    ParseNext: Parser<Input, Output, Error> + 'static,
+{
+    let rule = rule_id::<LeftRecursive, ParseNext>();
+    trace("left_recursive", move |input: &mut Input| {
+        let offset = input.memo_offset();
+        if let Some(entry) = input
+            .memo_cache()
+            .get::<Result<(Output, usize), Error>>(rule, offset)
+        {
+            return entry.map(|(output, consumed)| {
+                let _ = input.next_slice(consumed);
+                output
+            });
+        }
+
+        let start = input.checkpoint();
+        let seed: Result<(Output, usize), Error> = Err(Error::from_input(input));
+        input.memo_cache().insert(rule, offset, seed, None);
+        let mut best: Option<(Output, usize)> = None;
+        loop {
+            input.reset(&start);
+            match parser.parse_next(input) {
+                Ok(output) => {
+                    let consumed = input.offset_from(&start);
+                    if best
+                        .as_ref()
+                        .map(|(_, best_consumed)| consumed <= *best_consumed)
+                        .unwrap_or(false)
+                    {
+                        break;
+                    }
+                    input.memo_cache().insert(
+                        rule,
+                        offset,
+                        Ok::<_, Error>((output.clone(), consumed)),
+                        Some(consumed),
+                    );
+                    best = Some((output, consumed));
+                }
+                Err(err) if err.is_backtrack() && best.is_some() => break,
+                Err(err) if err.is_backtrack() => {
+                    input.memo_cache().insert(
+                        rule,
+                        offset,
+                        Err::<(Output, usize), _>(err.clone()),
+                        None,
+                    );
+                    return Err(err);
+                }
+                Err(err) => {
+                    input.memo_cache().remove(rule, offset);
+                    return Err(err);
+                }
+            }
+        }
+
+        let (output, consumed) = best.expect("loop only exits with a match");
+        input.reset(&start);
+        let _ = input.next_slice(consumed);
+        Ok(output)
+    })
+}
+
+/// Mark `parser` as a boundary for incremental reparsing, reusing its results across edits
+///
+/// Successful results are stored in the [`MemoCache`][crate::stream::MemoCache] of a
+/// [`Memoized`][crate::stream::Memoized] stream.  After changing the input, report the change
+/// with [`MemoCache::edit`][crate::stream::MemoCache::edit] and parse the new input with the same
+/// cache.  Rules whose results do not overlap the edit are skipped, replaying their cached output,
+/// so only the rules covering the edit are re-run.  Results after the edit are reused at their new
+/// offset, with any spans in the output moved by [`Relocate`].
+///
+/// Good boundaries are rules that make up a large part of the input and do not depend on what came
+/// before them, like items in a file or statements in a block.  They must not look ahead more than
+/// one token past what they consume.  As with [`memoize`], `parser` must be zero-sized to identify
+/// the rule and should not have side effects.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use std::ops::Range;
+/// use winnow::ascii::alpha1;
+/// use winnow::combinator::repeat;
+/// use winnow::combinator::reusable;
+/// use winnow::combinator::terminated;
+/// use winnow::stream::LocatingSlice;
+/// use winnow::stream::MemoCache;
+/// use winnow::stream::Memoized;
+///
+/// type Stream<'i, 'c> = Memoized<'c, LocatingSlice<&'i str>>;
+///
+/// fn words(input: &mut Stream<'_, '_>) -> ModalResult<Vec<Range<usize>>> {
+///     repeat(0.., reusable(word)).parse_next(input)
+/// }
+///
+/// fn word(input: &mut Stream<'_, '_>) -> ModalResult<Range<usize>> {
+///     terminated(alpha1, ' ').span().parse_next(input)
+/// }
+///
+/// let mut cache = MemoCache::new();
+/// let mut text = String::from("one two three ");
+/// let spans = words.parse(Memoized::new(LocatingSlice::new(text.as_str()), &cache)).unwrap();
+/// assert_eq!(spans, [0..4, 4..8, 8..14]);
+///
+/// // `one two three ` -> `one too three `
+/// text.replace_range(5..6, "o");
+/// cache.edit(5..6, 1);
+/// // `one` is kept and `three` is relocated, only `too` is re-parsed
+/// assert_eq!(cache.len(), 2);
+/// let spans = words.parse(Memoized::new(LocatingSlice::new(text.as_str()), &cache)).unwrap();
+/// assert_eq!(spans, [0..4, 4..8, 8..14]);
+/// ```
+#[doc(alias = "incremental")]
+pub fn reusable<Input, Output, Error, ParseNext>(
+    mut parser: ParseNext,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream + Memo,
+    Output: Relocate + Clone + 'static,
+    Error: ParserError<Input>,
+    ParseNext: Parser<Input, Output, Error> + 'static,
+{
+    let rule = rule_id::<Reusable, ParseNext>();
+    trace("reusable", move |input: &mut Input| {
+        let offset = input.memo_offset();
+        if let Some((output, consumed)) = input.memo_cache().get::<(Output, usize)>(rule, offset) {
+            let _ = input.next_slice(consumed);
+            return Ok(output);
+        }
+
+        let start = input.checkpoint();
+        let output = parser.parse_next(input)?;
+        let consumed = input.offset_from(&start);
+        input
+            .memo_cache()
+            .insert_reusable(rule, offset, output.clone(), consumed);
+        Ok(output)
+    })
+}
+
+/// Identify the rule `P` for the combinator `C`
+///
+/// Results of the same rule under different combinators are kept apart, as they differ in what
+/// they cache.
+fn rule_id<C: 'static, P: 'static>() -> TypeId {
+    #[allow(clippy::let_unit_value)]
+    let () = ZeroSized::<P>::ASSERT;
+    TypeId::of::<(C, P)>()
+}
+
+struct Memoize;
+struct LeftRecursive;
+struct Reusable;
+
+struct ZeroSized<P>(PhantomData<P>);
+
+impl<P> ZeroSized<P> {
+    const ASSERT: () = assert!(
+        core::mem::size_of::<P>() == 0,
+        "memoized rules must be functions or closures that capture nothing"
+    );
+}
@@ -15,10 +15,12 @@
 //! | [`one_of`][crate::token::one_of] | `one_of(['a', 'b', 'c'])` |  `"abc"` |  `"bc"` | `Ok('a')` |Matches one of the provided [set of tokens][crate::stream::ContainsToken] (works with non ASCII characters too)|
 //! | [`none_of`][crate::token::none_of] | `none_of(['a', 'b', 'c'])` |  `"xyab"` |  `"yab"` | `Ok('x')` |Matches anything but one of the provided [set of tokens][crate::stream::ContainsToken]|
 //! | [`literal`][crate::token::literal] | `"hello"` |  `"hello world"` |  `" world"` | `Ok("hello")` |Recognizes a specific suite of characters or bytes (see also [`Caseless`][crate::ascii::Caseless])|
+//! | [`one_of_literals`][crate::token::one_of_literals] | `one_of_literals(&set)` |  `"insert x"` |  `" x"` | `Ok(Keyword::Insert)` |Recognizes the longest literal in a [`LiteralSet`][crate::token::LiteralSet]|
 //! | [`take`][crate::token::take] | `take(4)` |  `"hello"` |  `"o"` | `Ok("hell")` |Takes a specific number of bytes or characters|
 //! | [`take_while`][crate::token::take_while] | `take_while(0.., is_alphabetic)` |  `"abc123"` |  `"123"` | `Ok("abc")` |Returns the longest slice of bytes or characters for which the provided [set of tokens][crate::stream::ContainsToken] matches.|
 //! | [`take_till`][crate::token::take_till] | `take_till(0.., is_alphabetic)` |  `"123abc"` |  `"abc"` | `Ok("123")` |Returns a slice of bytes or characters until the provided [set of tokens][crate::stream::ContainsToken] matches. This is the reverse behaviour from `take_while`: `take_till(f)` is equivalent to `take_while(0.., \|c\| !f(c))`|
 //! | [`take_until`][crate::token::take_until] | `take_until(0.., "world")` |  `"Hello world"` |  `"world"` | `Ok("Hello ")` |Returns a slice of bytes or characters until the provided [literal][crate::token::literal] is found.|
+//! | [`take_until_any`][crate::token::take_until_any] | `take_until_any(0.., ["{{", "{%"])` |  `"Hi {% x"` |  `"{% x"` | `Ok(("Hi ", 1))` |Returns a slice of bytes or characters until the first of several literals is found, and which one|
 //!
 //! ## Choice combinators
 //!
@@ -73,6 +75,10 @@
 //! - [`Parser::span`]: If the child parser was successful, return the location of the consumed input as the produced value
 //! - [`Parser::with_span`]: If the child parser was successful, return a tuple of the location of the consumed input and the produced output.
 //! - [`Parser::verify`]: Returns the result of the child parser if it satisfies a verification function
+//! - [`memoize`]: Cache the result of a rule at each offset for packrat parsing
+//! - [`left_recursive`]: Allow a rule to refer to itself as its first step
+//! - [`reusable`]: Reuse the results of a rule after an edit for incremental reparsing
+//! - [`expression`]: Parse operands combined by prefix, postfix, and infix operators according to their precedence
 //!
 //! ## Error management and debugging
 //!
@@ -111,9 +117,13 @@
 //! - [`dec_int`][crate::ascii::dec_int]: Decode a variable-width, decimal signed integer
 //! - [`dec_uint`][crate::ascii::dec_uint]: Decode a variable-width, decimal unsigned integer
 //! - [`hex_uint`][crate::ascii::hex_uint]: Decode a variable-width, hexadecimal integer
+//! - [`decimal`][crate::ascii::decimal]: Recognize the parts of a decimal literal, keeping its digits exact
+//! - [`radix_uint`][crate::ascii::radix_uint]: Decode an unsigned integer in any radix, with optional prefixes and digit separators. [`radix_int`][crate::ascii::radix_int] does the same for signed integers
+//! - [`rfc3339`][crate::ascii::datetime::rfc3339], [`iso8601_duration`][crate::ascii::datetime::iso8601_duration], [`http_date`][crate::ascii::datetime::http_date]: Parse timestamps and durations into their fields
 //!
 //! - [`take_escaped`][crate::ascii::take_escaped]: Recognize the input slice with escaped characters
 //! - [`escaped_transform`][crate::ascii::escaped_transform]: Parse escaped characters, unescaping them
+//! - [`escaped_cow`][crate::ascii::escaped_cow]: Parse escaped characters, unescaping them, borrowing from the input when there are no escapes
 //!
 //! ### Character test functions
 //!
@@ -150,6 +160,8 @@
 //!   - [`be_u8`][crate::binary::be_u8], [`be_u16`][crate::binary::be_u16], [`be_u24`][crate::binary::be_u24], [`be_u32`][crate::binary::be_u32], [`be_u64`][crate::binary::be_u64], [`be_u128`][crate::binary::be_u128]: Big endian unsigned integers
 //!   - [`le_i8`][crate::binary::le_i8], [`le_i16`][crate::binary::le_i16], [`le_i24`][crate::binary::le_i24], [`le_i32`][crate::binary::le_i32], [`le_i64`][crate::binary::le_i64], [`le_i128`][crate::binary::le_i128]: Little endian signed integers
 //!   - [`le_u8`][crate::binary::le_u8], [`le_u16`][crate::binary::le_u16], [`le_u24`][crate::binary::le_u24], [`le_u32`][crate::binary::le_u32], [`le_u64`][crate::binary::le_u64], [`le_u128`][crate::binary::le_u128]: Little endian unsigned integers
+//! - **variable length**: [`uleb128`][crate::binary::uleb128], [`sleb128`][crate::binary::sleb128], [`zigzag`][crate::binary::zigzag] and [`quic_varint`][crate::binary::quic_varint] decode integers whose encoding gives their length
+//! - **protobuf**: [`key`][crate::binary::protobuf::key], [`raw_value`][crate::binary::protobuf::raw_value] and [`packed`][crate::binary::protobuf::packed] build up [Protocol Buffers](https://protobuf.dev/programming-guides/encoding/) message parsers
 //!
 //! ### Bit stream parsing
 //!
@@ -158,10 +170,16 @@
 //! - [`take`][crate::binary::bits::take]: Take a set number of bits
 //! - [`pattern`][crate::binary::bits::pattern]: Check if a set number of bits matches a pattern
 //! - [`bool`][crate::binary::bits::bool]: Match any one bit
+//! - [`uint`][crate::binary::bits::uint], [`int`][crate::binary::bits::int], [`sign_magnitude`][crate::binary::bits::sign_magnitude]: Take a set number of bits as an integer, in either [bit order][crate::binary::bits::BitOrder]
+//! - [`uint_pattern`][crate::binary::bits::uint_pattern], [`bit`][crate::binary::bits::bit]: [`pattern`][crate::binary::bits::pattern] and [`bool`][crate::binary::bits::bool], in either [bit order][crate::binary::bits::BitOrder]
+//! - [`unary`][crate::binary::bits::unary], [`exp_golomb`][crate::binary::bits::exp_golomb], [`signed_exp_golomb`][crate::binary::bits::signed_exp_golomb]: Variable-length bit codes
 
 mod branch;
 mod core;
 mod debug;
+mod expression;
+#[cfg(feature = "alloc")]
+mod memo;
 mod multi;
 mod sequence;
 
@@ -173,6 +191,9 @@ pub mod impls;
 pub use self::branch::*;
 pub use self::core::*;
 pub use self::debug::*;
+pub use self::expression::*;
+#[cfg(feature = "alloc")]
+pub use self::memo::*;
 pub use self::multi::*;
 pub use self::sequence::*;
 
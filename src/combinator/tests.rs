@@ -4032,3 +4032,403 @@ Err(
         .raw()
     );
 }
+
+#[test]
+#[cfg(feature = "alloc")]
+fn memoize_reuses_results() {
+    use crate::ascii::dec_uint;
+    use crate::stream::MemoCache;
+    use crate::stream::Memoized;
+    use crate::stream::Stateful;
+    use ::core::cell::Cell;
+
+    type Input<'i, 'c, 's> = Stateful<Memoized<'c, &'i str>, &'s Cell<usize>>;
+
+    fn number(input: &mut Input<'_, '_, '_>) -> ModalResult<u32> {
+        input.state.set(input.state.get() + 1);
+        dec_uint.parse_next(input)
+    }
+
+    fn sum(input: &mut Input<'_, '_, '_>) -> ModalResult<u32> {
+        alt((
+            (memoize(number), '+', memoize(number)).map(|(a, _, b)| a + b),
+            (memoize(number), '-', memoize(number)).map(|(a, _, b)| a - b),
+            memoize(number),
+        ))
+        .parse_next(input)
+    }
+
+    let cache = MemoCache::new();
+    let calls = Cell::new(0);
+    let mut input = Stateful {
+        input: Memoized::new("40-2", &cache),
+        state: &calls,
+    };
+    assert_eq!(sum.parse_next(&mut input), Ok(38));
+    assert_eq!(calls.get(), 2);
+    assert_eq!(cache.len(), 2);
+
+    let mut cache = cache;
+    cache.clear();
+    let calls = Cell::new(0);
+    let mut input = Stateful {
+        input: Memoized::new("x", &cache),
+        state: &calls,
+    };
+    assert!(sum.parse_next(&mut input).is_err());
+    assert_eq!(calls.get(), 1);
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn memoize_skips_incomplete() {
+    use crate::stream::MemoCache;
+    use crate::stream::Memoized;
+
+    fn abc(input: &mut Partial<Memoized<'_, &str>>) -> ModalResult<&'static str> {
+        "abc".value("abc").parse_next(input)
+    }
+
+    let cache = MemoCache::new();
+    let mut input = Partial::new(Memoized::new("ab", &cache));
+    assert_eq!(
+        memoize(abc).parse_next(&mut input),
+        Err(ErrMode::Incomplete(crate::error::Needed::Unknown))
+    );
+    assert!(cache.is_empty());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn memoize_separates_combinators() {
+    use crate::stream::MemoCache;
+    use crate::stream::Memoized;
+
+    type Input<'i, 'c> = Memoized<'c, &'i str>;
+
+    fn item(input: &mut Input<'_, '_>) -> ModalResult<String> {
+        digit.map(ToOwned::to_owned).parse_next(input)
+    }
+
+    let cache = MemoCache::new();
+    let mut input = Memoized::new("12", &cache);
+    assert_eq!(memoize(item).parse_next(&mut input).as_deref(), Ok("12"));
+    let mut input = Memoized::new("12", &cache);
+    assert_eq!(
+        left_recursive(item).parse_next(&mut input).as_deref(),
+        Ok("12")
+    );
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn left_recursive_direct() {
+    use crate::stream::MemoCache;
+    use crate::stream::Memoized;
+
+    type Input<'i, 'c> = Memoized<'c, &'i str>;
+
+    fn list(input: &mut Input<'_, '_>) -> ModalResult<String> {
+        left_recursive(list_rule).parse_next(input)
+    }
+
+    // list = list ',' item | item
+    fn list_rule(input: &mut Input<'_, '_>) -> ModalResult<String> {
+        alt((
+            (list, ',', item).map(|(l, _, r)| format!("({l},{r})")),
+            item,
+        ))
+        .parse_next(input)
+    }
+
+    fn item(input: &mut Input<'_, '_>) -> ModalResult<String> {
+        digit.map(ToOwned::to_owned).parse_next(input)
+    }
+
+    let cache = MemoCache::new();
+    let mut input = Memoized::new("1,2,3;", &cache);
+    assert_eq!(list.parse_next(&mut input).as_deref(), Ok("((1,2),3)"));
+    assert_eq!(*input, ";");
+
+    let cache = MemoCache::new();
+    let mut input = Memoized::new("1,", &cache);
+    assert_eq!(list.parse_next(&mut input).as_deref(), Ok("1"));
+    assert_eq!(*input, ",");
+
+    let cache = MemoCache::new();
+    let mut input = Memoized::new(",", &cache);
+    assert!(list.parse_next(&mut input).is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn left_recursive_cut() {
+    use crate::ascii::dec_int;
+    use crate::stream::MemoCache;
+    use crate::stream::Memoized;
+
+    type Input<'i, 'c> = Memoized<'c, &'i str>;
+
+    fn expr(input: &mut Input<'_, '_>) -> ModalResult<i64> {
+        left_recursive(expr_rule).parse_next(input)
+    }
+
+    // expr = expr '-' ~ atom | atom
+    fn expr_rule(input: &mut Input<'_, '_>) -> ModalResult<i64> {
+        alt(((expr, '-', cut_err(atom)).map(|(l, _, r)| l - r), atom)).parse_next(input)
+    }
+
+    fn atom(input: &mut Input<'_, '_>) -> ModalResult<i64> {
+        dec_int.parse_next(input)
+    }
+
+    let cache = MemoCache::new();
+    let mut input = Memoized::new("3-1", &cache);
+    assert_eq!(expr.parse_next(&mut input), Ok(2));
+
+    let cache = MemoCache::new();
+    let mut input = Memoized::new("1-x", &cache);
+    assert!(matches!(expr.parse_next(&mut input), Err(ErrMode::Cut(_))));
+    assert!(cache.is_empty());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn left_recursive_indirect() {
+    use crate::stream::MemoCache;
+    use crate::stream::Memoized;
+
+    type Input<'i, 'c> = Memoized<'c, &'i str>;
+
+    fn a(input: &mut Input<'_, '_>) -> ModalResult<String> {
+        left_recursive(a_rule).parse_next(input)
+    }
+
+    // a = b 'x' | 'y'
+    fn a_rule(input: &mut Input<'_, '_>) -> ModalResult<String> {
+        alt((
+            (b, 'x').map(|(b, _)| format!("{b}x")),
+            "y".map(ToOwned::to_owned),
+        ))
+        .parse_next(input)
+    }
+
+    // b = a 'z'
+    fn b(input: &mut Input<'_, '_>) -> ModalResult<String> {
+        (a, 'z').map(|(a, _)| format!("[{a}z]")).parse_next(input)
+    }
+
+    let cache = MemoCache::new();
+    let output = a.parse(Memoized::new("yzxzx", &cache)).unwrap();
+    assert_eq!(output, "[[yz]xz]x");
+}
+
+#[cfg(feature = "alloc")]
+fn sexpr(input: &mut &str) -> ModalResult<String> {
+    use crate::token::one_of;
+
+    let operand = alt((
+        one_of(|c: char| c.is_ascii_alphanumeric()).map(|c: char| c.to_string()),
+        delimited('(', sexpr, ')'),
+    ));
+    expression(operand)
+        .prefix(
+            alt(('-'.value((5, '-')), '!'.value((12, '!')))),
+            |op, rhs| format!("({op} {rhs})"),
+        )
+        .postfix(
+            alt(("?".value((7, "?")), "[]".value((10, "[]")))),
+            |lhs, op| format!("({op} {lhs})"),
+        )
+        .infix(
+            alt((
+                "==".value((Assoc::Neither(1), "==")),
+                '+'.value((Assoc::Left(3), "+")),
+                '-'.value((Assoc::Left(3), "-")),
+                '*'.value((Assoc::Left(4), "*")),
+                '^'.value((Assoc::Right(6), "^")),
+                '.'.value((Assoc::Left(10), ".")),
+            )),
+            |lhs, op, rhs| format!("({op} {lhs} {rhs})"),
+        )
+        .parse_next(input)
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn expression_precedence() {
+    assert_eq!(sexpr.parse("1").unwrap(), "1");
+    assert_eq!(sexpr.parse("1+2*3").unwrap(), "(+ 1 (* 2 3))");
+    assert_eq!(sexpr.parse("1*2+3").unwrap(), "(+ (* 1 2) 3)");
+    assert_eq!(sexpr.parse("(1+2)*3").unwrap(), "(* (+ 1 2) 3)");
+    assert_eq!(sexpr.parse("1-2-3").unwrap(), "(- (- 1 2) 3)");
+    assert_eq!(sexpr.parse("1^2^3").unwrap(), "(^ 1 (^ 2 3))");
+    assert_eq!(sexpr.parse("a.b.c").unwrap(), "(. (. a b) c)");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn expression_unary() {
+    assert_eq!(sexpr.parse("-1").unwrap(), "(- 1)");
+    assert_eq!(sexpr.parse("1--2").unwrap(), "(- 1 (- 2))");
+    assert_eq!(sexpr.parse("--1*2").unwrap(), "(* (- (- 1)) 2)");
+    assert_eq!(sexpr.parse("-1^2").unwrap(), "(- (^ 1 2))");
+    assert_eq!(sexpr.parse("!1*2").unwrap(), "(* (! 1) 2)");
+    assert_eq!(sexpr.parse("-a.b").unwrap(), "(- (. a b))");
+    assert_eq!(sexpr.parse("!a.b").unwrap(), "(. (! a) b)");
+    assert_eq!(sexpr.parse("-a?").unwrap(), "(- (? a))");
+    assert_eq!(sexpr.parse("!a?").unwrap(), "(? (! a))");
+    assert_eq!(sexpr.parse("a[]?[]").unwrap(), "([] (? ([] a)))");
+    assert_eq!(sexpr.parse("a.b[]").unwrap(), "([] (. a b))");
+    assert_eq!(sexpr.parse("a.b?").unwrap(), "(? (. a b))");
+    assert_eq!(sexpr.parse("a*b[]").unwrap(), "(* a ([] b))");
+    assert_eq!(sexpr.parse("a+b?").unwrap(), "(+ a (? b))");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn expression_non_associative() {
+    assert_eq!(sexpr.parse("a+b==c*d").unwrap(), "(== (+ a b) (* c d))");
+    assert_eq!(sexpr.parse("(a==b)==c").unwrap(), "(== (== a b) c)");
+    assert!(sexpr.parse("a==b==c").is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn expression_errors() {
+    assert!(sexpr.parse("").is_err());
+    assert!(sexpr.parse("1+").is_err());
+    assert!(sexpr.parse("-").is_err());
+    assert!(sexpr.parse("(1+2").is_err());
+    assert_eq!(sexpr.parse_peek("1+2)"), Ok((")", "(+ 1 2)".to_owned())));
+    assert_eq!(sexpr.parse_peek("1 + 2"), Ok((" + 2", "1".to_owned())));
+}
+
+#[test]
+fn expression_incomplete() {
+    fn sum(input: &mut Partial<&str>) -> ModalResult<u32> {
+        expression(digit.parse_to::<u32>())
+            .infix('+'.value((Assoc::Left(1), ())), |lhs, (), rhs| lhs + rhs)
+            .parse_next(input)
+    }
+
+    assert_eq!(
+        sum.parse_peek(Partial::new("1+2")),
+        Err(ErrMode::Incomplete(crate::error::Needed::new(1)))
+    );
+    assert_eq!(
+        sum.parse_peek(Partial::new("1+2;")),
+        Ok((Partial::new(";"), 3))
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn reusable_after_edit() {
+    use crate::ascii::alpha1;
+    use crate::ascii::dec_uint;
+    use crate::lib::std::ops::Range;
+    use crate::lib::std::string::String;
+    use crate::stream::LocatingSlice;
+    use crate::stream::MemoCache;
+    use crate::stream::Memoized;
+    use crate::stream::Relocate;
+    use crate::stream::Stateful;
+    use ::core::cell::Cell;
+
+    type Input<'i, 'c, 's> = Stateful<Memoized<'c, LocatingSlice<&'i str>>, &'s Cell<usize>>;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Assign {
+        name: String,
+        value: u32,
+        span: Range<usize>,
+    }
+
+    impl Relocate for Assign {
+        fn relocate(&mut self, from: usize, to: usize) {
+            self.span.relocate(from, to);
+        }
+    }
+
+    fn assign(input: &mut Input<'_, '_, '_>) -> ModalResult<Assign> {
+        input.state.set(input.state.get() + 1);
+        (alpha1, '=', dec_uint, ';')
+            .with_span()
+            .map(|((name, _, value, _), span): ((&str, _, _, _), _)| Assign {
+                name: name.to_owned(),
+                value,
+                span,
+            })
+            .parse_next(input)
+    }
+
+    fn block(input: &mut Input<'_, '_, '_>) -> ModalResult<Vec<Assign>> {
+        repeat(0.., reusable(assign)).parse_next(input)
+    }
+
+    fn parse(text: &str, cache: &MemoCache) -> (Vec<(String, u32, Range<usize>)>, usize) {
+        let calls = Cell::new(0);
+        let input = Stateful {
+            input: Memoized::new(LocatingSlice::new(text), cache),
+            state: &calls,
+        };
+        let output = block
+            .parse(input)
+            .unwrap()
+            .into_iter()
+            .map(|a| (a.name, a.value, a.span))
+            .collect();
+        (output, calls.get())
+    }
+
+    let mut cache = MemoCache::new();
+    let (output, calls) = parse("a=1;b=2;c=3;", &cache);
+    assert_eq!(
+        output,
+        [
+            ("a".to_owned(), 1, 0..4),
+            ("b".to_owned(), 2, 4..8),
+            ("c".to_owned(), 3, 8..12)
+        ]
+    );
+    // including the failed attempt at the end
+    assert_eq!(calls, 4);
+
+    // grow `b`'s value
+    cache.edit(6..7, 2);
+    let (output, calls) = parse("a=1;b=22;c=3;", &cache);
+    assert_eq!(
+        output,
+        [
+            ("a".to_owned(), 1, 0..4),
+            ("b".to_owned(), 22, 4..9),
+            ("c".to_owned(), 3, 9..13)
+        ]
+    );
+    assert_eq!(calls, 2);
+
+    // remove `b`, re-parsing `a` as it ends at the edit
+    cache.edit(4..9, 0);
+    let (output, calls) = parse("a=1;c=3;", &cache);
+    assert_eq!(
+        output,
+        [("a".to_owned(), 1, 0..4), ("c".to_owned(), 3, 4..8)]
+    );
+    assert_eq!(calls, 2);
+
+    // insert `d`
+    cache.edit(4..4, 4);
+    let (output, calls) = parse("a=1;d=4;c=3;", &cache);
+    assert_eq!(
+        output,
+        [
+            ("a".to_owned(), 1, 0..4),
+            ("d".to_owned(), 4, 4..8),
+            ("c".to_owned(), 3, 8..12)
+        ]
+    );
+    assert_eq!(calls, 3);
+}
@@ -0,0 +1,509 @@
+//! # Deserializing lexed tokens with [`serde`]
+//!
+//! Building blocks for implementing [`serde::Deserializer`] for a format that is
+//! [lexed][crate::_topic::lexing] into a [`TokenSlice`] and parsed with winnow.
+//!
+//! Your deserializer owns the [`TokenSlice`] and implements [`TokenInput`].  Its `deserialize_*`
+//! methods run parsers over the tokens with [`parse_tokens`], handing the result to the
+//! [`Visitor`][serde::de::Visitor], using [`SeqAccess`] and [`MapAccess`] for compound values.
+//! As visitors for tuples and structs stop once they have what they need, call
+//! [`SeqAccess::end`] and [`MapAccess::end`] afterwards to consume the rest of the value.
+//! [`from_tokens`] then deserializes a value, reporting failures as a [`ParseError`] whose
+//! [offset][ParseError::offset] is the index of the offending token.  If the tokens implement
+//! [`Location`][crate::stream::Location], that token points back to the source.
+//!
+//! Errors raised by the visitor, like a missing field or an unexpected type, are reported at the
+//! start of the value being deserialized, see [`locate`].
+//!
+//! # Example
+//!
+//! ```rust
+//! # use winnow::prelude::*;
+//! use serde::de::Visitor;
+//! use winnow::de::{from_tokens, parse_tokens, Error, MapAccess, SeqAccess, TokenInput};
+//! use winnow::error::ContextError;
+//! use winnow::stream::TokenSlice;
+//! use winnow::token::any;
+//!
+//! #[derive(Clone, Debug, PartialEq, Eq)]
+//! enum Token<'s> {
+//!     Int(i64),
+//!     Str(&'s str),
+//!     Punct(char),
+//! }
+//!
+//! impl PartialEq<char> for Token<'_> {
+//!     fn eq(&self, other: &char) -> bool {
+//!         *self == Token::Punct(*other)
+//!     }
+//! }
+//!
+//! struct Deserializer<'t, 's> {
+//!     input: TokenSlice<'t, Token<'s>>,
+//! }
+//!
+//! impl<'t, 's> TokenInput<'t> for Deserializer<'t, 's> {
+//!     type Token = Token<'s>;
+//!     type Error = ContextError;
+//!
+//!     fn tokens(&mut self) -> &mut TokenSlice<'t, Token<'s>> {
+//!         &mut self.input
+//!     }
+//! }
+//!
+//! impl<'de> serde::Deserializer<'de> for &mut Deserializer<'_, 'de> {
+//!     type Error = Error<ContextError>;
+//!
+//!     fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+//!         match parse_tokens(&mut self.input, any::<_, ContextError>)? {
+//!             Token::Int(value) => visitor.visit_i64(*value),
+//!             Token::Str(value) => visitor.visit_borrowed_str(value),
+//!             Token::Punct('[') => {
+//!                 let mut seq = SeqAccess::new(self, ',', ']');
+//!                 let value = visitor.visit_seq(&mut seq)?;
+//!                 seq.end()?;
+//!                 Ok(value)
+//!             }
+//!             Token::Punct('{') => {
+//!                 let mut map = MapAccess::new(self, ',', ':', '}');
+//!                 let value = visitor.visit_map(&mut map)?;
+//!                 map.end()?;
+//!                 Ok(value)
+//!             }
+//!             Token::Punct(c) => Err(serde::de::Error::custom(format!("unexpected `{c}`"))),
+//!         }
+//!     }
+//!
+//!     serde::forward_to_deserialize_any! {
+//!         bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+//!         bytes byte_buf option unit unit_struct newtype_struct seq tuple
+//!         tuple_struct map struct enum identifier ignored_any
+//!     }
+//! }
+//!
+//! #[derive(serde::Deserialize, Debug, PartialEq)]
+//! struct Server<'s> {
+//!     name: &'s str,
+//!     ports: Vec<u16>,
+//! }
+//!
+//! // { "name": "web", "ports": [80, 443] }
+//! let tokens = [
+//!     Token::Punct('{'),
+//!     Token::Str("name"), Token::Punct(':'), Token::Str("web"), Token::Punct(','),
+//!     Token::Str("ports"), Token::Punct(':'),
+//!     Token::Punct('['), Token::Int(80), Token::Punct(','), Token::Int(443), Token::Punct(']'),
+//!     Token::Punct('}'),
+//! ];
+//! let server: Server<'_> = from_tokens(Deserializer { input: TokenSlice::new(&tokens) }).unwrap();
+//! assert_eq!(server, Server { name: "web", ports: vec![80, 443] });
+//!
+//! // { "name": "web", "ports": [80, -1] }
+//! let tokens = [
+//!     Token::Punct('{'),
+//!     Token::Str("name"), Token::Punct(':'), Token::Str("web"), Token::Punct(','),
+//!     Token::Str("ports"), Token::Punct(':'),
+//!     Token::Punct('['), Token::Int(80), Token::Punct(','), Token::Int(-1), Token::Punct(']'),
+//!     Token::Punct('}'),
+//! ];
+//! let err = from_tokens::<_, Server<'_>>(Deserializer { input: TokenSlice::new(&tokens) })
+//!     .unwrap_err();
+//! assert_eq!(err.offset(), 10);
+//! assert_eq!(err.input()[err.offset()], Token::Int(-1));
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use crate::combinator::eof;
+use crate::combinator::opt;
+use crate::error::ErrMode;
+use crate::error::ParseError;
+use crate::error::ParserError;
+use crate::lib::std::fmt;
+use crate::lib::std::string::String;
+use crate::lib::std::string::ToString as _;
+use crate::stream::Compare;
+use crate::stream::Offset;
+use crate::stream::Stream;
+use crate::stream::TokenSlice;
+use crate::token::literal;
+use crate::Parser;
+
+/// Access to the tokens underlying a [`serde::Deserializer`]
+pub trait TokenInput<'t> {
+    /// The lexed token type
+    type Token: fmt::Debug + Clone + 't;
+    /// The [`ParserError`] reported when parsing the tokens
+    type Error: fmt::Debug + fmt::Display;
+
+    /// The tokens that have not been deserialized yet
+    fn tokens(&mut self) -> &mut TokenSlice<'t, Self::Token>;
+}
+
+/// A deserialization failure, located at a token
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error<E> {
+    kind: ErrorKind<E>,
+    offset: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum ErrorKind<E> {
+    Parse(E),
+    Custom(String),
+}
+
+impl<E> Error<E> {
+    /// Report a failure from parsing the tokens
+    pub fn parse(err: E) -> Self {
+        Self {
+            kind: ErrorKind::Parse(err),
+            offset: None,
+        }
+    }
+
+    /// The [`ParserError`], if parsing the tokens failed
+    pub fn parse_error(&self) -> Option<&E> {
+        match &self.kind {
+            ErrorKind::Parse(err) => Some(err),
+            ErrorKind::Custom(_) => None,
+        }
+    }
+
+    /// The message, if the failure was raised by [`serde`]
+    pub fn message(&self) -> Option<&str> {
+        match &self.kind {
+            ErrorKind::Parse(_) => None,
+            ErrorKind::Custom(msg) => Some(msg),
+        }
+    }
+
+    /// The index of the token where deserialization failed, if known
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    fn or_offset(mut self, offset: usize) -> Self {
+        self.offset.get_or_insert(offset);
+        self
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::Parse(err) => err.fmt(f),
+            ErrorKind::Custom(msg) => msg.fmt(f),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for Error<E> {}
+
+impl<E: fmt::Debug + fmt::Display> serde::de::Error for Error<E> {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self {
+            kind: ErrorKind::Custom(msg.to_string()),
+            offset: None,
+        }
+    }
+}
+
+/// Run `parser` over the tokens, reporting a failure at the token where it stopped
+///
+/// <div class="warning">
+///
+/// **Note:** this panics if the parser reports `ErrMode::Incomplete`
+///
+/// </div>
+pub fn parse_tokens<'t, T, O, E, P>(
+    input: &mut TokenSlice<'t, T>,
+    mut parser: P,
+) -> Result<O, Error<E::Inner>>
+where
+    T: fmt::Debug + Clone,
+    P: Parser<TokenSlice<'t, T>, O, E>,
+    E: ParserError<TokenSlice<'t, T>>,
+{
+    parser.parse_next(input).map_err(|err| {
+        let err = err.into_inner().unwrap_or_else(|_err| {
+            panic!("complete parsers should not report `ErrMode::Incomplete(_)`")
+        });
+        Error::parse(err).or_offset(token_offset(input))
+    })
+}
+
+/// Run `f`, reporting failures that are not yet located at the token where it started
+///
+/// Use this around visitor calls where the cause of an error is the value as a whole, rather than
+/// the token the deserializer has reached.  [`SeqAccess`], [`MapAccess`], and [`from_tokens`] do
+/// this for each value they deserialize.
+pub fn locate<'t, D, O, E>(
+    de: &mut D,
+    f: impl FnOnce(&mut D) -> Result<O, Error<E>>,
+) -> Result<O, Error<E>>
+where
+    D: TokenInput<'t>,
+{
+    let start = token_offset(de.tokens());
+    f(de).map_err(|err| err.or_offset(start))
+}
+
+/// Deserialize a value from all of the tokens in `de`
+///
+/// On failure, the [`ParseError::input`] holds all of the tokens with [`ParseError::offset`]
+/// being the index of the token where deserialization failed.
+#[allow(clippy::type_complexity)]
+pub fn from_tokens<'de, 't, D, V>(
+    mut de: D,
+) -> Result<V, ParseError<TokenSlice<'t, D::Token>, Error<D::Error>>>
+where
+    D: TokenInput<'t>,
+    D::Error: ParserError<TokenSlice<'t, D::Token>>,
+    for<'b> &'b mut D: serde::Deserializer<'de, Error = Error<D::Error>>,
+    V: serde::Deserialize<'de>,
+{
+    let mut input = de.tokens().clone();
+    input.reset_to_start();
+    let start = input.checkpoint();
+
+    locate(&mut de, |de| V::deserialize(de))
+        .and_then(|value| {
+            parse_tokens::<_, _, ErrMode<D::Error>, _>(de.tokens(), eof)?;
+            Ok(value)
+        })
+        .map_err(|err| {
+            let offset = err.offset().unwrap_or(0);
+            let _ = input.next_slice(offset);
+            ParseError::new(input, start, err)
+        })
+}
+
+/// [`serde::de::SeqAccess`] for elements separated by a token, ending with another
+///
+/// The opening token is expected to be consumed already.
+pub struct SeqAccess<'a, D, K> {
+    de: &'a mut D,
+    separator: K,
+    terminator: K,
+    first: bool,
+    done: bool,
+}
+
+impl<'a, D, K> SeqAccess<'a, D, K> {
+    /// Deserialize elements from `de` separated by `separator` until `terminator`
+    pub fn new(de: &'a mut D, separator: K, terminator: K) -> Self {
+        Self {
+            de,
+            separator,
+            terminator,
+            first: true,
+            done: false,
+        }
+    }
+
+    /// Consume the rest of the sequence, after the visitor is done with it
+    ///
+    /// This allows a trailing `separator` and fails if any elements remain.
+    pub fn end<'t>(self) -> Result<(), Error<D::Error>>
+    where
+        D: TokenInput<'t>,
+        D::Error: ParserError<TokenSlice<'t, D::Token>>,
+        TokenSlice<'t, D::Token>: Compare<K>,
+        K: Clone + fmt::Debug,
+    {
+        end_items(
+            self.de,
+            &self.separator,
+            &self.terminator,
+            self.first,
+            self.done,
+            "trailing elements",
+        )
+    }
+}
+
+impl<'de, 'a, 't, D, K> serde::de::SeqAccess<'de> for SeqAccess<'a, D, K>
+where
+    D: TokenInput<'t>,
+    D::Error: ParserError<TokenSlice<'t, D::Token>>,
+    for<'b> &'b mut D: serde::Deserializer<'de, Error = Error<D::Error>>,
+    TokenSlice<'t, D::Token>: Compare<K>,
+    K: Clone + fmt::Debug,
+{
+    type Error = <&'a mut D as serde::Deserializer<'de>>::Error;
+
+    fn next_element_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>, Self::Error>
+    where
+        S: serde::de::DeserializeSeed<'de>,
+    {
+        if next_item(
+            self.de,
+            &self.separator,
+            &self.terminator,
+            &mut self.first,
+            &mut self.done,
+        )? {
+            locate(self.de, |de| seed.deserialize(de)).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// [`serde::de::MapAccess`] for entries separated by a token, ending with another
+///
+/// The opening token is expected to be consumed already.
+pub struct MapAccess<'a, D, K> {
+    de: &'a mut D,
+    separator: K,
+    assign: K,
+    terminator: K,
+    first: bool,
+    done: bool,
+}
+
+impl<'a, D, K> MapAccess<'a, D, K> {
+    /// Deserialize entries from `de` separated by `separator` until `terminator`, with `assign`
+    /// between each key and value
+    pub fn new(de: &'a mut D, separator: K, assign: K, terminator: K) -> Self {
+        Self {
+            de,
+            separator,
+            assign,
+            terminator,
+            first: true,
+            done: false,
+        }
+    }
+
+    /// Consume the rest of the map, after the visitor is done with it
+    ///
+    /// This allows a trailing `separator` and fails if any entries remain.
+    pub fn end<'t>(self) -> Result<(), Error<D::Error>>
+    where
+        D: TokenInput<'t>,
+        D::Error: ParserError<TokenSlice<'t, D::Token>>,
+        TokenSlice<'t, D::Token>: Compare<K>,
+        K: Clone + fmt::Debug,
+    {
+        end_items(
+            self.de,
+            &self.separator,
+            &self.terminator,
+            self.first,
+            self.done,
+            "trailing entries",
+        )
+    }
+}
+
+impl<'de, 'a, 't, D, K> serde::de::MapAccess<'de> for MapAccess<'a, D, K>
+where
+    D: TokenInput<'t>,
+    D::Error: ParserError<TokenSlice<'t, D::Token>>,
+    for<'b> &'b mut D: serde::Deserializer<'de, Error = Error<D::Error>>,
+    TokenSlice<'t, D::Token>: Compare<K>,
+    K: Clone + fmt::Debug,
+{
+    type Error = <&'a mut D as serde::Deserializer<'de>>::Error;
+
+    fn next_key_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>, Self::Error>
+    where
+        S: serde::de::DeserializeSeed<'de>,
+    {
+        if next_item(
+            self.de,
+            &self.separator,
+            &self.terminator,
+            &mut self.first,
+            &mut self.done,
+        )? {
+            locate(self.de, |de| seed.deserialize(de)).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn next_value_seed<S>(&mut self, seed: S) -> Result<S::Value, Self::Error>
+    where
+        S: serde::de::DeserializeSeed<'de>,
+    {
+        parse_tokens::<_, _, ErrMode<D::Error>, _>(self.de.tokens(), literal(self.assign.clone()))?;
+        locate(self.de, |de| seed.deserialize(de))
+    }
+}
+
+/// Move to the next item in a delimited list, returning whether there is one
+fn next_item<'t, D, K>(
+    de: &mut D,
+    separator: &K,
+    terminator: &K,
+    first: &mut bool,
+    done: &mut bool,
+) -> Result<bool, Error<D::Error>>
+where
+    D: TokenInput<'t>,
+    D::Error: ParserError<TokenSlice<'t, D::Token>>,
+    TokenSlice<'t, D::Token>: Compare<K>,
+    K: Clone + fmt::Debug,
+{
+    if *done {
+        return Ok(false);
+    }
+    let end =
+        parse_tokens::<_, _, ErrMode<D::Error>, _>(de.tokens(), opt(literal(terminator.clone())))?;
+    if end.is_some() {
+        *done = true;
+        return Ok(false);
+    }
+    if !*first {
+        parse_tokens::<_, _, ErrMode<D::Error>, _>(de.tokens(), literal(separator.clone()))?;
+    }
+    *first = false;
+    Ok(true)
+}
+
+/// Finish a delimited list, failing with `message` if items remain
+fn end_items<'t, D, K>(
+    de: &mut D,
+    separator: &K,
+    terminator: &K,
+    first: bool,
+    done: bool,
+    message: &str,
+) -> Result<(), Error<D::Error>>
+where
+    D: TokenInput<'t>,
+    D::Error: ParserError<TokenSlice<'t, D::Token>>,
+    TokenSlice<'t, D::Token>: Compare<K>,
+    K: Clone + fmt::Debug,
+{
+    if done {
+        return Ok(());
+    }
+    if !first {
+        parse_tokens::<_, _, ErrMode<D::Error>, _>(de.tokens(), opt(literal(separator.clone())))?;
+    }
+    let end =
+        parse_tokens::<_, _, ErrMode<D::Error>, _>(de.tokens(), opt(literal(terminator.clone())))?;
+    if end.is_some() {
+        return Ok(());
+    }
+    if de.tokens().eof_offset() == 0 {
+        // Report the missing terminator
+        parse_tokens::<_, _, ErrMode<D::Error>, _>(de.tokens(), literal(terminator.clone()))?;
+    }
+    Err(<Error<D::Error> as serde::de::Error>::custom(message).or_offset(token_offset(de.tokens())))
+}
+
+/// The index of the next token within all of the tokens
+fn token_offset<T>(input: &TokenSlice<'_, T>) -> usize
+where
+    T: fmt::Debug + Clone,
+{
+    let mut start = input.clone();
+    start.reset_to_start();
+    input.offset_from(&start)
+}
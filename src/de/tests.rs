@@ -0,0 +1,238 @@
+use super::*;
+
+use serde::de::Visitor;
+use serde::Deserialize;
+
+use crate::ascii::alpha1;
+use crate::ascii::dec_int;
+use crate::ascii::multispace0;
+use crate::combinator::alt;
+use crate::combinator::delimited;
+use crate::combinator::repeat;
+use crate::error::ContextError;
+use crate::prelude::*;
+use crate::token::any;
+use crate::token::one_of;
+use crate::token::take_till;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token<'s> {
+    Ident(&'s str),
+    Int(i64),
+    Str(&'s str),
+    Punct(char),
+}
+
+impl PartialEq<char> for Token<'_> {
+    fn eq(&self, other: &char) -> bool {
+        *self == Token::Punct(*other)
+    }
+}
+
+fn lex<'s>(input: &mut &'s str) -> ModalResult<Vec<Token<'s>>> {
+    let token = alt((
+        dec_int.map(Token::Int),
+        delimited('"', take_till(0.., '"'), '"').map(Token::Str),
+        alpha1.map(Token::Ident),
+        one_of(['{', '}', '[', ']', ',', '=']).map(Token::Punct),
+    ));
+    delimited(
+        multispace0,
+        repeat(0.., (token, multispace0).map(|(t, _)| t)),
+        multispace0,
+    )
+    .parse_next(input)
+}
+
+struct Deserializer<'t, 's> {
+    input: TokenSlice<'t, Token<'s>>,
+}
+
+impl<'t, 's> TokenInput<'t> for Deserializer<'t, 's> {
+    type Token = Token<'s>;
+    type Error = ContextError;
+
+    fn tokens(&mut self) -> &mut TokenSlice<'t, Token<'s>> {
+        &mut self.input
+    }
+}
+
+impl<'de> serde::Deserializer<'de> for &mut Deserializer<'_, 'de> {
+    type Error = Error<ContextError>;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match parse_tokens(&mut self.input, any::<_, ContextError>)? {
+            Token::Int(value) => visitor.visit_i64(*value),
+            Token::Str(value) | Token::Ident(value) => visitor.visit_borrowed_str(value),
+            Token::Punct('[') => {
+                let mut seq = SeqAccess::new(self, ',', ']');
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Token::Punct('{') => {
+                let mut map = MapAccess::new(self, ',', '=', '}');
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+            Token::Punct(c) => Err(serde::de::Error::custom(format!("unexpected `{c}`"))),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Config<'s> {
+    name: &'s str,
+    ports: Vec<u16>,
+    limits: Limits,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Limits {
+    min: i32,
+    max: i32,
+}
+
+fn config<'t, 's>(
+    tokens: &'t [Token<'s>],
+) -> Result<Config<'s>, ParseError<TokenSlice<'t, Token<'s>>, Error<ContextError>>> {
+    from_tokens(Deserializer {
+        input: TokenSlice::new(tokens),
+    })
+}
+
+#[test]
+fn de_struct() {
+    let tokens = lex
+        .parse(r#"{ name = "web", ports = [80, 443], limits = { min = -1, max = 10 } }"#)
+        .unwrap();
+    assert_eq!(
+        config(&tokens).unwrap(),
+        Config {
+            name: "web",
+            ports: vec![80, 443],
+            limits: Limits { min: -1, max: 10 },
+        }
+    );
+}
+
+#[test]
+fn de_empty_seq() {
+    let tokens = lex
+        .parse(r#"{ name = "web", ports = [], limits = { min = 0, max = 0 } }"#)
+        .unwrap();
+    assert_eq!(config(&tokens).unwrap().ports, Vec::<u16>::new());
+}
+
+#[test]
+fn de_invalid_value() {
+    let tokens = lex.parse(r#"{ name = "web", ports = [80, -1] }"#).unwrap();
+    let err = config(&tokens).unwrap_err();
+    assert_eq!(err.offset(), 10);
+    assert_eq!(err.input()[err.offset()], Token::Int(-1));
+    assert!(err.inner().message().is_some());
+    assert_eq!(err.inner().offset(), Some(10));
+}
+
+#[test]
+fn de_missing_field() {
+    let tokens = lex
+        .parse(r#"{ name = "web", ports = [], limits = { min = 0 } }"#)
+        .unwrap();
+    let err = config(&tokens).unwrap_err();
+    assert_eq!(err.input()[err.offset()], Token::Punct('{'));
+    assert_eq!(err.offset(), 12);
+    assert_eq!(err.inner().message(), Some("missing field `max`"));
+}
+
+#[test]
+fn de_missing_separator() {
+    let tokens = lex.parse(r#"{ name = "web" ports = [] }"#).unwrap();
+    let err = config(&tokens).unwrap_err();
+    assert_eq!(err.offset(), 4);
+    assert_eq!(err.input()[err.offset()], Token::Ident("ports"));
+    assert!(err.inner().parse_error().is_some());
+}
+
+#[test]
+fn de_unexpected_eof() {
+    let tokens = lex.parse(r#"{ name = "web", ports = [80"#).unwrap();
+    let err = config(&tokens).unwrap_err();
+    assert_eq!(err.offset(), tokens.len());
+    assert!(err.inner().parse_error().is_some());
+}
+
+#[test]
+fn de_trailing_tokens() {
+    let tokens = lex.parse("[1, 2] 3").unwrap();
+    let err = from_tokens::<_, Vec<u8>>(Deserializer {
+        input: TokenSlice::new(&tokens),
+    })
+    .unwrap_err();
+    assert_eq!(err.offset(), 5);
+    assert!(err.inner().parse_error().is_some());
+}
+
+fn de<'t, 's, V: Deserialize<'s>>(
+    tokens: &'t [Token<'s>],
+) -> Result<V, ParseError<TokenSlice<'t, Token<'s>>, Error<ContextError>>> {
+    from_tokens(Deserializer {
+        input: TokenSlice::new(tokens),
+    })
+}
+
+#[test]
+fn de_tuple() {
+    let tokens = lex.parse("[1, 2]").unwrap();
+    assert_eq!(de::<(u8, u8)>(&tokens).unwrap(), (1, 2));
+
+    let tokens = lex.parse("[1, 2,]").unwrap();
+    assert_eq!(de::<(u8, u8)>(&tokens).unwrap(), (1, 2));
+
+    let tokens = lex.parse("[1, 2, 3]").unwrap();
+    let err = de::<(u8, u8)>(&tokens).unwrap_err();
+    assert_eq!(err.offset(), 5);
+    assert_eq!(err.input()[err.offset()], Token::Int(3));
+    assert_eq!(err.inner().message(), Some("trailing elements"));
+
+    let tokens = lex.parse("[1, 2").unwrap();
+    let err = de::<(u8, u8)>(&tokens).unwrap_err();
+    assert_eq!(err.offset(), tokens.len());
+    assert!(err.inner().parse_error().is_some());
+}
+
+#[test]
+fn de_array() {
+    let tokens = lex.parse("[1, 2, 3]").unwrap();
+    assert_eq!(de::<[u8; 3]>(&tokens).unwrap(), [1, 2, 3]);
+
+    let tokens = lex.parse("[1, 2, 3, 4]").unwrap();
+    let err = de::<[u8; 3]>(&tokens).unwrap_err();
+    assert_eq!(err.input()[err.offset()], Token::Int(4));
+    assert_eq!(err.inner().message(), Some("trailing elements"));
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Range(i32, i32);
+
+#[test]
+fn de_tuple_struct() {
+    let tokens = lex.parse("[[-1, 1], [0, 10]]").unwrap();
+    assert_eq!(
+        de::<Vec<Range>>(&tokens).unwrap(),
+        vec![Range(-1, 1), Range(0, 10)]
+    );
+
+    let tokens = lex.parse("[[-1, 1, 2], [0, 10]]").unwrap();
+    let err = de::<Vec<Range>>(&tokens).unwrap_err();
+    assert_eq!(err.offset(), 6);
+    assert_eq!(err.input()[err.offset()], Token::Int(2));
+    assert_eq!(err.inner().message(), Some("trailing elements"));
+}
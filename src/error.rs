@@ -1245,7 +1245,7 @@ pub struct ParseError<I, E> {
     inner: E,
 }
 
-impl<I: Stream, E: ParserError<I>> ParseError<I, E> {
+impl<I: Stream, E> ParseError<I, E> {
     pub(crate) fn new(mut input: I, start: I::Checkpoint, inner: E) -> Self {
         let offset = input.offset_from(&start);
         input.reset(&start);
@@ -1298,6 +1298,54 @@ impl<I: AsBStr, E> ParseError<I, E> {
     pub fn char_span(&self) -> crate::lib::std::ops::Range<usize> {
         char_boundary(self.input.as_bstr(), self.offset())
     }
+
+    /// The 1-based line and column for [`ParseError::offset`]
+    ///
+    /// Columns are counted in `char`s, falling back to bytes when the line is not valid UTF-8.
+    #[inline]
+    pub fn line_column(&self) -> (usize, usize) {
+        let (line_idx, col_idx) = translate_position(self.input.as_bstr(), self.offset());
+        (line_idx + 1, col_idx + 1)
+    }
+
+    /// Render the error with the offending source lines
+    ///
+    /// See [`Snippet`] for customizing the rendering.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// # use winnow::error::StrContext;
+    /// # use winnow::error::StrContextValue;
+    /// use winnow::ascii::dec_uint;
+    ///
+    /// fn port(input: &mut &str) -> ModalResult<u16> {
+    ///     ("port = ", dec_uint)
+    ///         .map(|(_, port)| port)
+    ///         .context(StrContext::Label("port"))
+    ///         .context(StrContext::Expected(StrContextValue::Description("integer")))
+    ///         .parse_next(input)
+    /// }
+    ///
+    /// let error = port.parse("port = http").unwrap_err();
+    /// assert_eq!(
+    ///     error.snippet().to_string(),
+    ///     "\
+    /// error: invalid port
+    ///  --> 1:8
+    ///   |
+    /// 1 | port = http
+    ///   |        ^
+    ///   = expected integer
+    /// "
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn snippet(&self) -> Snippet<'_, I, E> {
+        Snippet::new(self)
+    }
 }
 
 fn char_boundary(input: &[u8], offset: usize) -> crate::lib::std::ops::Range<usize> {
@@ -1400,7 +1448,6 @@ where
     }
 }
 
-#[cfg(feature = "std")]
 fn translate_position(input: &[u8], index: usize) -> (usize, usize) {
     if input.is_empty() {
         return (0, index);
@@ -1431,6 +1478,255 @@ fn translate_position(input: &[u8], index: usize) -> (usize, usize) {
     (line, column)
 }
 
+/// Render a [`ParseError`] with the offending source lines, see [`ParseError::snippet`]
+///
+/// The first line of the inner error's [`Display`][fmt::Display] is used as the headline, with
+/// any further lines rendered as notes.  For [`ContextError`], this means the
+/// [`StrContext::Label`] is the headline and each [`StrContext::Expected`] is a note.
+///
+/// ```text
+/// error: invalid time
+///  --> 1:10
+///   |
+/// 1 | 00:32:00.a999999
+///   |          ^
+///   = expected digit
+/// ```
+#[cfg(feature = "alloc")]
+pub struct Snippet<'e, I, E> {
+    error: &'e ParseError<I, E>,
+    span: Option<crate::lib::std::ops::Range<usize>>,
+    tab_width: usize,
+    #[cfg(feature = "color")]
+    color: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl<'e, I: AsBStr, E> Snippet<'e, I, E> {
+    fn new(error: &'e ParseError<I, E>) -> Self {
+        Self {
+            error,
+            span: None,
+            tab_width: 4,
+            #[cfg(feature = "color")]
+            color: false,
+        }
+    }
+
+    /// Underline this byte range within [`ParseError::input`]
+    ///
+    /// Defaults to [`ParseError::char_span`].  The range may cover multiple lines.
+    #[inline]
+    pub fn span(mut self, span: crate::lib::std::ops::Range<usize>) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /// Number of columns a `\t` advances to (default: 4)
+    #[inline]
+    pub fn tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width.max(1);
+        self
+    }
+
+    /// Style the output with ANSI escape codes
+    ///
+    /// To respect the terminal's capabilities and the user's preferences, write this through
+    /// [`anstream`](https://docs.rs/anstream).
+    #[cfg(feature = "color")]
+    #[inline]
+    pub fn color(mut self, yes: bool) -> Self {
+        self.color = yes;
+        self
+    }
+
+    fn write_styled(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        highlight: Highlight,
+        text: impl fmt::Display,
+    ) -> fmt::Result {
+        #[cfg(feature = "color")]
+        {
+            let style = if self.color {
+                match highlight {
+                    Highlight::Error => anstyle::Style::new()
+                        .fg_color(Some(anstyle::AnsiColor::Red.into()))
+                        .bold(),
+                    Highlight::Gutter => anstyle::Style::new()
+                        .fg_color(Some(anstyle::AnsiColor::Blue.into()))
+                        .bold(),
+                }
+            } else {
+                anstyle::Style::new()
+            };
+            write!(f, "{}{text}{}", style.render(), style.render_reset())
+        }
+        #[cfg(not(feature = "color"))]
+        {
+            let _ = highlight;
+            write!(f, "{text}")
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Copy, Clone)]
+enum Highlight {
+    Error,
+    Gutter,
+}
+
+#[cfg(feature = "alloc")]
+impl<I, E> fmt::Display for Snippet<'_, I, E>
+where
+    I: AsBStr,
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use crate::lib::std::string::ToString as _;
+
+        let input = self.error.input.as_bstr();
+        let span = self.span.clone().unwrap_or_else(|| self.error.char_span());
+        let span_start = span.start.min(input.len());
+        let span_end = span.end.clamp(span_start, input.len());
+
+        let message = self.error.inner.to_string();
+        let mut message = message.lines();
+        let headline = message
+            .next()
+            .filter(|l| !l.is_empty())
+            .unwrap_or("parse error");
+
+        let (line_idx, col_idx) = translate_position(input, span_start);
+        let first_line = line_idx + 1;
+        let mut lines = crate::lib::std::vec::Vec::new();
+        let mut line_start = input[..span_start]
+            .iter()
+            .rposition(|b| *b == b'\n')
+            .map(|nl| nl + 1)
+            .unwrap_or(0);
+        loop {
+            let line_end = input[line_start..]
+                .iter()
+                .position(|b| *b == b'\n')
+                .map(|nl| line_start + nl)
+                .unwrap_or(input.len());
+            lines.push(line_start..line_end);
+            if span_end <= line_end || line_end == input.len() {
+                break;
+            }
+            line_start = line_end + 1;
+            if span_end <= line_start {
+                break;
+            }
+        }
+        let last_line = first_line + lines.len() - 1;
+        let gutter = last_line.to_string().len();
+
+        self.write_styled(f, Highlight::Error, "error")?;
+        writeln!(f, ": {headline}")?;
+        write!(f, "{:gutter$}", "")?;
+        self.write_styled(f, Highlight::Gutter, "-->")?;
+        writeln!(f, " {}:{}", first_line, col_idx + 1)?;
+        write!(f, "{:gutter$} ", "")?;
+        self.write_styled(f, Highlight::Gutter, "|")?;
+        writeln!(f)?;
+
+        for (line_num, line) in (first_line..).zip(lines) {
+            let mut content = &input[line.clone()];
+            if let Some(stripped) = content.strip_suffix(b"\r") {
+                content = stripped;
+            }
+            let (rendered, columns) = expand_line(content, self.tab_width);
+
+            self.write_styled(f, Highlight::Gutter, format_args!("{line_num:<gutter$} |"))?;
+            if rendered.is_empty() {
+                writeln!(f)?;
+            } else {
+                writeln!(f, " {rendered}")?;
+            }
+
+            let underline_start = span_start.max(line.start) - line.start;
+            let underline_end = span_end.min(line.end) - line.start;
+            let underline_start = columns[underline_start.min(content.len())];
+            let underline_end = columns[underline_end.min(content.len())];
+            // The span will be empty at eof, so we need to make sure we always print at least
+            // one `^`
+            let underline_len = underline_end.saturating_sub(underline_start).max(1);
+            write!(f, "{:gutter$} ", "")?;
+            self.write_styled(f, Highlight::Gutter, "|")?;
+            write!(f, " {:underline_start$}", "")?;
+            self.write_styled(f, Highlight::Error, "^".repeat(underline_len))?;
+            writeln!(f)?;
+        }
+
+        for note in message {
+            write!(f, "{:gutter$} ", "")?;
+            self.write_styled(f, Highlight::Gutter, "=")?;
+            writeln!(f, " {note}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Lossily decode `line`, expanding tabs
+///
+/// Also returns the rendered column for each byte offset within `line` (including the end).
+#[cfg(feature = "alloc")]
+fn expand_line(
+    line: &[u8],
+    tab_width: usize,
+) -> (
+    crate::lib::std::string::String,
+    crate::lib::std::vec::Vec<usize>,
+) {
+    let mut rendered = crate::lib::std::string::String::new();
+    let mut columns = crate::lib::std::vec::Vec::with_capacity(line.len() + 1);
+    let mut column = 0;
+    let mut push = |c: char, width: usize, rendered: &mut crate::lib::std::string::String| {
+        for _ in 0..width {
+            columns.push(column);
+        }
+        if c == '\t' {
+            let spaces = tab_width - column % tab_width;
+            for _ in 0..spaces {
+                rendered.push(' ');
+            }
+            column += spaces;
+        } else {
+            rendered.push(c);
+            column += 1;
+        }
+    };
+
+    let mut remaining = line;
+    while !remaining.is_empty() {
+        let (valid, invalid_len) = match crate::lib::std::str::from_utf8(remaining) {
+            Ok(valid) => (valid, 0),
+            Err(err) => {
+                let (valid, _) = remaining.split_at(err.valid_up_to());
+                let valid = crate::lib::std::str::from_utf8(valid).expect("checked by `from_utf8`");
+                let invalid_len = err
+                    .error_len()
+                    .unwrap_or(remaining.len() - err.valid_up_to());
+                (valid, invalid_len)
+            }
+        };
+        for c in valid.chars() {
+            push(c, c.len_utf8(), &mut rendered);
+        }
+        if invalid_len != 0 {
+            push(char::REPLACEMENT_CHARACTER, invalid_len, &mut rendered);
+        }
+        remaining = &remaining[valid.len() + invalid_len..];
+    }
+    columns.push(column);
+
+    (rendered, columns)
+}
+
 #[cfg(test)]
 mod test_char_boundary {
     use super::*;
@@ -1498,6 +1794,140 @@ failed to parse starting at: Z123";
     }
 }
 
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod test_snippet {
+    use super::*;
+
+    fn error<'i, E: ParserError<&'i str>>(
+        mut input: &'i str,
+        offset: usize,
+        inner: E,
+    ) -> ParseError<&'i str, E> {
+        let start = input.checkpoint();
+        let _ = input.next_slice(offset);
+        ParseError::new(input, start, inner)
+    }
+
+    #[test]
+    fn single_line() {
+        let error = error("0xZ123", 2, InputError::at("Z123"));
+        let expected = "\
+error: failed to parse starting at: Z123
+ --> 1:3
+  |
+1 | 0xZ123
+  |   ^
+";
+        assert_eq!(error.snippet().to_string(), expected);
+        assert_eq!(error.line_column(), (1, 3));
+    }
+
+    #[test]
+    fn eof() {
+        let error = error("Hello\nWorld", 11, EmptyError);
+        let expected = "\
+error: failed to parse
+ --> 2:6
+  |
+2 | World
+  |      ^
+";
+        assert_eq!(error.snippet().to_string(), expected);
+    }
+
+    #[test]
+    fn context_notes() {
+        let inner = ContextError::new()
+            .add_context(&"", &"".checkpoint(), StrContext::Label("digit"))
+            .add_context(
+                &"",
+                &"".checkpoint(),
+                StrContext::Expected(StrContextValue::Description("binary")),
+            );
+        let error = error("0b012", 4, inner);
+        let expected = "\
+error: invalid digit
+ --> 1:5
+  |
+1 | 0b012
+  |     ^
+  = expected binary
+";
+        assert_eq!(error.snippet().to_string(), expected);
+    }
+
+    #[test]
+    fn multi_line_span() {
+        let input = "1\n2\n3\n4\n5\n6\n7\n8\nfirst = [\n  2,\n  3\n";
+        let start = input.find('[').unwrap();
+        let error = error(input, start, EmptyError);
+        let expected = "\
+error: failed to parse
+  --> 9:9
+   |
+9  | first = [
+   |         ^
+10 |   2,
+   | ^^^^
+11 |   3
+   | ^^^
+";
+        assert_eq!(
+            error.snippet().span(start..input.len()).to_string(),
+            expected
+        );
+    }
+
+    #[test]
+    fn tabs() {
+        let input = "\tkey\t= value";
+        let start = input.find('=').unwrap();
+        let error = error(input, start, EmptyError);
+        let expected = "\
+error: failed to parse
+ --> 1:6
+  |
+1 |     key = value
+  |         ^
+";
+        assert_eq!(error.snippet().to_string(), expected);
+        let expected = "\
+error: failed to parse
+ --> 1:6
+  |
+1 |   key = value
+  |       ^
+";
+        assert_eq!(error.snippet().tab_width(2).to_string(), expected);
+    }
+
+    #[test]
+    fn unicode() {
+        let input = "βèƒôřè = 1";
+        let start = input.find('=').unwrap();
+        let error = error(input, start, EmptyError);
+        let expected = "\
+error: failed to parse
+ --> 1:8
+  |
+1 | βèƒôřè = 1
+  |        ^
+";
+        assert_eq!(error.snippet().to_string(), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "color")]
+    fn color() {
+        let error = error("0xZ123", 2, EmptyError);
+        let rendered = error.snippet().color(true).to_string();
+        assert!(rendered.contains('\x1b'));
+        let rendered = error.snippet().color(false).to_string();
+        assert!(!rendered.contains('\x1b'));
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "std")]
 mod test_translate_position {
@@ -0,0 +1,184 @@
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+use crate::error::ErrMode;
+use crate::error::ParserError;
+use crate::io::buffer::Buffer;
+use crate::io::buffer::Step;
+use crate::io::ReadError;
+use crate::io::DEFAULT_CAPACITY;
+use crate::stream::Partial;
+use crate::Parser;
+
+/// A [`Stream`][futures_core::Stream] of values parsed from a [`futures_io::AsyncRead`]
+///
+/// This is the asynchronous counterpart to [`ReadIterator`][crate::io::ReadIterator]: data is
+/// read into an internal buffer and the parser is re-run whenever it reports
+/// [`ErrMode::Incomplete`] and more data has arrived.
+///
+/// The stream ends once the reader is exhausted and all of its data has been parsed.
+/// After an `Err` is yielded, the stream is fused.
+///
+/// As the buffer is reused between values, the output may not borrow from the input.  For
+/// protocols whose messages borrow from the input, like HTTP, parse the framing here and hand
+/// each frame to a complete parser.
+///
+/// For tokio, adapt the reader with
+/// [`tokio_util::compat`](https://docs.rs/tokio-util/latest/tokio_util/compat/index.html).
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use futures::StreamExt as _;
+/// use winnow::combinator::terminated;
+/// use winnow::error::ContextError;
+/// use winnow::io::AsyncReadStream;
+/// use winnow::token::take_until;
+/// use winnow::Partial;
+///
+/// /// Frame HTTP requests on the blank line ending their head
+/// fn request_head(input: &mut Partial<&[u8]>) -> ModalResult<Vec<u8>> {
+///     terminated(take_until(1.., "\r\n\r\n"), "\r\n\r\n")
+///         .map(<[u8]>::to_vec)
+///         .parse_next(input)
+/// }
+///
+/// let reader = futures::io::Cursor::new(
+///     "GET / HTTP/1.1\r\nHost: a\r\n\r\nGET /b HTTP/1.1\r\nHost: b\r\n\r\n",
+/// );
+/// let stream = AsyncReadStream::<_, _, _, ContextError>::new(reader, request_head);
+/// let heads = futures::executor::block_on(stream.collect::<Vec<_>>());
+/// assert_eq!(heads.len(), 2);
+/// assert_eq!(heads[1].as_ref().unwrap(), b"GET /b HTTP/1.1\r\nHost: b");
+/// ```
+pub struct AsyncReadStream<R, P, O, E> {
+    reader: R,
+    parser: P,
+    buffer: Buffer,
+    fill_target: Option<usize>,
+    done: bool,
+    o: core::marker::PhantomData<O>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<R, P, O, E> AsyncReadStream<R, P, O, E>
+where
+    R: futures_io::AsyncRead + Unpin,
+    P: for<'i> Parser<Partial<&'i [u8]>, O, ErrMode<E>>,
+    E: for<'i> ParserError<Partial<&'i [u8]>>,
+{
+    /// Parse values from `reader` with `parser`
+    pub fn new(reader: R, parser: P) -> Self {
+        Self {
+            reader,
+            parser,
+            buffer: Buffer::with_capacity(DEFAULT_CAPACITY),
+            fill_target: None,
+            done: false,
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+
+    /// Initial size of the read buffer
+    ///
+    /// Defaults to [`DEFAULT_CAPACITY`]
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.buffer = Buffer::with_capacity(capacity);
+        self
+    }
+
+    /// Limit how large the read buffer may grow
+    ///
+    /// When a single value needs more than this, [`ReadError::BufferFull`] is returned.
+    pub fn max_capacity(mut self, max_capacity: usize) -> Self {
+        self.buffer.set_max_capacity(max_capacity);
+        self
+    }
+
+    /// The underlying reader
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Data that has been read but not yet parsed
+    pub fn buffer(&self) -> &[u8] {
+        self.buffer.data()
+    }
+
+    /// Stop parsing, returning the reader and any data that was read but not parsed
+    pub fn into_parts(self) -> (R, Vec<u8>) {
+        let rest = self.buffer.data().to_vec();
+        (self.reader, rest)
+    }
+
+    fn poll_fill(&mut self, cx: &mut Context<'_>, target: usize) -> Poll<Result<(), ReadError<E>>> {
+        while !self.buffer.is_filled(target) {
+            let additional = target - self.buffer.data().len();
+            let space = self.buffer.space(additional)?;
+            match Pin::new(&mut self.reader).poll_read(cx, space) {
+                Poll::Ready(Ok(read)) => self.buffer.fill(read),
+                Poll::Ready(Err(err)) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Poll::Ready(Err(err)) => return Poll::Ready(Err(ReadError::Io(err))),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<R, P, O, E> futures_core::Stream for AsyncReadStream<R, P, O, E>
+where
+    R: futures_io::AsyncRead + Unpin,
+    P: for<'i> Parser<Partial<&'i [u8]>, O, ErrMode<E>>,
+    E: for<'i> ParserError<Partial<&'i [u8]>>,
+{
+    type Item = Result<O, ReadError<E>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+        loop {
+            if let Some(target) = this.fill_target {
+                match this.poll_fill(cx, target) {
+                    Poll::Ready(Ok(())) => this.fill_target = None,
+                    Poll::Ready(Err(err)) => {
+                        this.done = true;
+                        return Poll::Ready(Some(Err(err)));
+                    }
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+            match this.buffer.parse(&mut this.parser) {
+                Step::Item(output) => return Poll::Ready(Some(Ok(output))),
+                Step::Fill(needed) => this.fill_target = Some(this.buffer.fill_target(needed)),
+                Step::Error(err) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+                Step::Done => {
+                    this.done = true;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+}
+
+impl<R, P, O, E> futures_core::FusedStream for AsyncReadStream<R, P, O, E>
+where
+    R: futures_io::AsyncRead + Unpin,
+    P: for<'i> Parser<Partial<&'i [u8]>, O, ErrMode<E>>,
+    E: for<'i> ParserError<Partial<&'i [u8]>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+// The buffer is never handed out across a suspension point, so nothing needs to be pinned
+impl<R, P, O, E> Unpin for AsyncReadStream<R, P, O, E> {}
@@ -0,0 +1,150 @@
+use crate::error::ErrMode;
+use crate::error::Needed;
+use crate::error::ParserError;
+use crate::io::ReadError;
+use crate::stream::Offset;
+use crate::stream::Partial;
+use crate::stream::Stream;
+use crate::stream::StreamIsPartial;
+use crate::Parser;
+
+/// Growable, compacting buffer of not-yet-parsed bytes
+#[derive(Debug)]
+pub(crate) struct Buffer {
+    data: Vec<u8>,
+    start: usize,
+    end: usize,
+    max_capacity: usize,
+    eof: bool,
+}
+
+impl Buffer {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            data: vec![0; capacity.max(1)],
+            start: 0,
+            end: 0,
+            max_capacity: usize::MAX,
+            eof: false,
+        }
+    }
+
+    pub(crate) fn set_max_capacity(&mut self, max_capacity: usize) {
+        self.max_capacity = max_capacity.max(1);
+    }
+
+    /// Bytes read but not yet consumed
+    pub(crate) fn data(&self) -> &[u8] {
+        &self.data[self.start..self.end]
+    }
+
+    /// How many bytes must be buffered to satisfy a parser's [`Needed`]
+    pub(crate) fn fill_target(&self, needed: Needed) -> usize {
+        let needed = match needed {
+            Needed::Size(n) => n.get(),
+            Needed::Unknown => 1,
+        };
+        self.data().len().saturating_add(needed)
+    }
+
+    /// Whether enough data has been buffered to re-run the parser
+    pub(crate) fn is_filled(&self, target: usize) -> bool {
+        target <= self.data().len() || self.eof
+    }
+
+    /// Make room for at least `needed` more bytes, returning the space to read into
+    pub(crate) fn space<E>(&mut self, needed: usize) -> Result<&mut [u8], ReadError<E>> {
+        let available = self.end - self.start;
+        let required = available
+            .checked_add(needed)
+            .filter(|required| *required <= self.max_capacity)
+            .ok_or(ReadError::BufferFull)?;
+        if self.data.len() - self.end < needed {
+            // Compact before growing so we only grow when the buffer is actually full
+            self.data.copy_within(self.start..self.end, 0);
+            self.start = 0;
+            self.end = available;
+        }
+        if self.data.len() < required {
+            let capacity = (self.data.len() * 2).clamp(required, self.max_capacity);
+            self.data.resize(capacity, 0);
+        }
+        Ok(&mut self.data[self.end..])
+    }
+
+    /// Record that `read` bytes were written into [`Buffer::space`]
+    pub(crate) fn fill(&mut self, read: usize) {
+        if read == 0 {
+            self.eof = true;
+        }
+        self.end += read;
+        debug_assert!(self.end <= self.data.len());
+    }
+
+    /// Run `parser` against the buffered data, consuming what it parsed
+    pub(crate) fn parse<P, O, E>(&mut self, parser: &mut P) -> Step<O, E>
+    where
+        P: for<'i> Parser<Partial<&'i [u8]>, O, ErrMode<E>>,
+        E: for<'i> ParserError<Partial<&'i [u8]>>,
+    {
+        match parse(self.data(), self.eof, parser) {
+            Step::Item((output, consumed)) => {
+                self.start += consumed;
+                Step::Item(output)
+            }
+            Step::Fill(needed) => Step::Fill(needed),
+            Step::Error(err) => Step::Error(err),
+            Step::Done => Step::Done,
+        }
+    }
+}
+
+/// Run `parser` once against `data`, reporting the output and how many bytes it consumed
+pub(crate) fn parse<P, O, E>(data: &[u8], eof: bool, parser: &mut P) -> Step<(O, usize), E>
+where
+    P: for<'i> Parser<Partial<&'i [u8]>, O, ErrMode<E>>,
+    E: for<'i> ParserError<Partial<&'i [u8]>>,
+{
+    if data.is_empty() {
+        if eof {
+            return Step::Done;
+        } else {
+            return Step::Fill(Needed::Unknown);
+        }
+    }
+
+    let mut input = Partial::new(data);
+    if eof {
+        let _ = input.complete();
+    }
+    let start = input.checkpoint();
+    match parser.parse_next(&mut input) {
+        Ok(output) => {
+            let consumed = input.offset_from(&start);
+            if consumed == 0 {
+                // infinite loop check: the parser must always consume
+                let err = ErrMode::<E>::assert(&input, "`io` parsers must always consume");
+                return Step::Error(ReadError::from_mode(err));
+            }
+            Step::Item((output, consumed))
+        }
+        Err(ErrMode::Incomplete(needed)) if !eof => Step::Fill(needed),
+        Err(err) => Step::Error(ReadError::from_mode(err)),
+    }
+}
+
+pub(crate) enum Step<O, E> {
+    Item(O),
+    Fill(Needed),
+    Error(ReadError<E>),
+    Done,
+}
+
+impl<E> ReadError<E> {
+    fn from_mode(err: ErrMode<E>) -> Self {
+        match err {
+            ErrMode::Backtrack(e) | ErrMode::Cut(e) => ReadError::Parse(e),
+            ErrMode::Incomplete(needed) => ReadError::Incomplete(needed),
+        }
+    }
+}
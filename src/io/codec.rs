@@ -0,0 +1,134 @@
+use bytes::Buf as _;
+use bytes::BytesMut;
+
+use crate::error::ErrMode;
+use crate::error::Needed;
+use crate::error::ParserError;
+use crate::io::buffer::parse;
+use crate::io::buffer::Step;
+use crate::io::ReadError;
+use crate::stream::Partial;
+use crate::Parser;
+
+/// Adapt a [`Parser`] into a [`tokio_util::codec::Decoder`]
+///
+/// The parser sees the decoder's buffer as a [`Partial`] stream.  On success, the bytes it
+/// consumed are removed from the buffer.  When it reports [`ErrMode::Incomplete`], the decoder
+/// asks for more data, reserving space for what the parser said it [`Needed`].  At the end of
+/// the stream, the input is marked as complete before parsing what is left.
+///
+/// As the buffer is reused between values, the output may not borrow from the input.
+/// For zero-copy outputs, the parser can frame the message and the frame can be split off as a
+/// [`Bytes`][bytes::Bytes] which is itself a [`Stream`][crate::stream::Stream].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use bytes::BytesMut;
+/// use tokio_util::codec::Decoder as _;
+/// use winnow::binary::be_u16;
+/// use winnow::binary::length_take;
+/// use winnow::error::ContextError;
+/// use winnow::io::ParserDecoder;
+/// use winnow::Partial;
+///
+/// fn message(input: &mut Partial<&[u8]>) -> ModalResult<String> {
+///     length_take(be_u16)
+///         .try_map(std::str::from_utf8)
+///         .map(ToOwned::to_owned)
+///         .parse_next(input)
+/// }
+///
+/// let mut decoder = ParserDecoder::<_, _, ContextError>::new(message);
+/// let mut buffer = BytesMut::from(&b"\x00\x05hello\x00\x05wor"[..]);
+/// assert_eq!(decoder.decode(&mut buffer).unwrap(), Some("hello".to_owned()));
+/// assert_eq!(decoder.decode(&mut buffer).unwrap(), None);
+/// buffer.extend_from_slice(b"ld");
+/// assert_eq!(decoder.decode(&mut buffer).unwrap(), Some("world".to_owned()));
+/// assert!(buffer.is_empty());
+/// ```
+pub struct ParserDecoder<P, O, E> {
+    parser: P,
+    max_length: usize,
+    o: core::marker::PhantomData<O>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<P, O, E> ParserDecoder<P, O, E>
+where
+    P: for<'i> Parser<Partial<&'i [u8]>, O, ErrMode<E>>,
+    E: for<'i> ParserError<Partial<&'i [u8]>>,
+{
+    /// Decode values with `parser`
+    pub fn new(parser: P) -> Self {
+        Self {
+            parser,
+            max_length: usize::MAX,
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+
+    /// Limit how much data may be buffered for a single value
+    ///
+    /// When a value needs more than this, [`ReadError::BufferFull`] is returned.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// The underlying parser
+    pub fn get_ref(&self) -> &P {
+        &self.parser
+    }
+
+    /// The underlying parser
+    pub fn get_mut(&mut self) -> &mut P {
+        &mut self.parser
+    }
+
+    /// Stop decoding, returning the underlying parser
+    pub fn into_inner(self) -> P {
+        self.parser
+    }
+
+    fn decode_step(&mut self, src: &mut BytesMut, eof: bool) -> Result<Option<O>, ReadError<E>> {
+        match parse(src, eof, &mut self.parser) {
+            Step::Item((output, consumed)) => {
+                src.advance(consumed);
+                Ok(Some(output))
+            }
+            Step::Fill(needed) => {
+                let additional = match needed {
+                    Needed::Size(n) => n.get(),
+                    Needed::Unknown => 1,
+                };
+                if self.max_length < src.len().saturating_add(additional) {
+                    return Err(ReadError::BufferFull);
+                }
+                src.reserve(additional);
+                Ok(None)
+            }
+            Step::Error(err) => Err(err),
+            Step::Done => Ok(None),
+        }
+    }
+}
+
+impl<P, O, E> tokio_util::codec::Decoder for ParserDecoder<P, O, E>
+where
+    P: for<'i> Parser<Partial<&'i [u8]>, O, ErrMode<E>>,
+    E: for<'i> ParserError<Partial<&'i [u8]>>,
+{
+    type Item = O;
+    type Error = ReadError<E>;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        self.decode_step(src, false)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        self.decode_step(src, true)
+    }
+}
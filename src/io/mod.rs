@@ -0,0 +1,81 @@
+//! # Parsing from I/O sources
+//!
+//! Drivers for running a [`Parser`][crate::Parser] over data that arrives incrementally, rather
+//! than being available up front as a single slice.
+//!
+//! The parser sees the buffered data as a [`Partial`][crate::stream::Partial] stream, so it
+//! needs to be written with [partial input][crate::_topic::partial] in mind: when it reports
+//! [`ErrMode::Incomplete`][crate::error::ErrMode::Incomplete], more data is read and the parser
+//! is re-run from the start of the unconsumed data.  Once the source is exhausted, the input is
+//! marked as [complete][crate::stream::StreamIsPartial::complete].
+//!
+//! - [`ReadIterator`]: iterate over values parsed from a [`std::io::Read`]
+//! - [`AsyncReadStream`]: a [`Stream`][futures_core::Stream] of values parsed from a
+//!   [`futures_io::AsyncRead`] (requires the `futures-io` feature)
+//! - [`ParserDecoder`]: a [`tokio_util::codec::Decoder`] for use with `FramedRead` (requires the
+//!   `tokio-util` feature)
+
+#[cfg(feature = "futures-io")]
+mod async_read;
+mod buffer;
+#[cfg(feature = "tokio-util")]
+mod codec;
+mod read;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "futures-io")]
+pub use async_read::AsyncReadStream;
+#[cfg(feature = "tokio-util")]
+pub use codec::ParserDecoder;
+pub use read::ReadIterator;
+
+use crate::error::Needed;
+use crate::lib::std::fmt;
+
+/// The default initial buffer size for the I/O drivers
+pub const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+/// Errors produced by the I/O drivers
+#[derive(Debug)]
+pub enum ReadError<E> {
+    /// The underlying source failed
+    Io(std::io::Error),
+    /// The parser failed on the buffered data
+    Parse(E),
+    /// The source ended in the middle of a value
+    Incomplete(Needed),
+    /// Making progress would require growing the buffer past its maximum capacity
+    BufferFull,
+}
+
+impl<E> From<std::io::Error> for ReadError<E> {
+    #[inline]
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ReadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read input: {err}"),
+            Self::Parse(err) => err.fmt(f),
+            Self::Incomplete(Needed::Size(n)) => {
+                write!(f, "unexpected end of input, needed {n} more bytes")
+            }
+            Self::Incomplete(Needed::Unknown) => write!(f, "unexpected end of input"),
+            Self::BufferFull => write!(f, "input exceeded maximum buffer capacity"),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for ReadError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::error::ErrMode;
+use crate::error::Needed;
+use crate::error::ParserError;
+use crate::io::buffer::Buffer;
+use crate::io::buffer::Step;
+use crate::io::ReadError;
+use crate::io::DEFAULT_CAPACITY;
+use crate::stream::Partial;
+use crate::Parser;
+
+/// Iterate over values parsed from a [`std::io::Read`]
+///
+/// Data is read into an internal buffer that is compacted as values are consumed and grown when
+/// a single value does not fit.  Each call to [`Iterator::next`] runs the parser once, reading
+/// more data whenever it reports [`ErrMode::Incomplete`].
+///
+/// Iteration ends with `None` once the reader is exhausted and all of its data has been parsed.
+/// After an `Err` is returned, the iterator is fused.
+///
+/// As the buffer is reused between values, the output may not borrow from the input.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::dec_uint;
+/// use winnow::ascii::line_ending;
+/// use winnow::combinator::terminated;
+/// use winnow::error::ContextError;
+/// use winnow::io::ReadIterator;
+/// use winnow::Partial;
+///
+/// fn line(input: &mut Partial<&[u8]>) -> ModalResult<u32> {
+///     terminated(dec_uint, line_ending).parse_next(input)
+/// }
+///
+/// let reader = std::io::Cursor::new("1\n22\n333\n");
+/// let values = ReadIterator::<_, _, _, ContextError>::new(reader, line)
+///     .capacity(2)
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+/// assert_eq!(values, [1, 22, 333]);
+/// ```
+pub struct ReadIterator<R, P, O, E> {
+    reader: R,
+    parser: P,
+    buffer: Buffer,
+    done: bool,
+    o: core::marker::PhantomData<O>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<R, P, O, E> ReadIterator<R, P, O, E>
+where
+    R: std::io::Read,
+    P: for<'i> Parser<Partial<&'i [u8]>, O, ErrMode<E>>,
+    E: for<'i> ParserError<Partial<&'i [u8]>>,
+{
+    /// Parse values from `reader` with `parser`
+    pub fn new(reader: R, parser: P) -> Self {
+        Self {
+            reader,
+            parser,
+            buffer: Buffer::with_capacity(DEFAULT_CAPACITY),
+            done: false,
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+
+    /// Initial size of the read buffer
+    ///
+    /// Defaults to [`DEFAULT_CAPACITY`]
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.buffer = Buffer::with_capacity(capacity);
+        self
+    }
+
+    /// Limit how large the read buffer may grow
+    ///
+    /// When a single value needs more than this, [`ReadError::BufferFull`] is returned.
+    pub fn max_capacity(mut self, max_capacity: usize) -> Self {
+        self.buffer.set_max_capacity(max_capacity);
+        self
+    }
+
+    /// The underlying reader
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Data that has been read but not yet parsed
+    pub fn buffer(&self) -> &[u8] {
+        self.buffer.data()
+    }
+
+    /// Stop parsing, returning the reader and any data that was read but not parsed
+    pub fn into_parts(self) -> (R, Vec<u8>) {
+        let rest = self.buffer.data().to_vec();
+        (self.reader, rest)
+    }
+
+    fn fill(&mut self, needed: Needed) -> Result<(), ReadError<E>> {
+        let target = self.buffer.fill_target(needed);
+        while !self.buffer.is_filled(target) {
+            let additional = target - self.buffer.data().len();
+            let space = self.buffer.space(additional)?;
+            match self.reader.read(space) {
+                Ok(read) => self.buffer.fill(read),
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(ReadError::Io(err)),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<R, P, O, E> Iterator for ReadIterator<R, P, O, E>
+where
+    R: std::io::Read,
+    P: for<'i> Parser<Partial<&'i [u8]>, O, ErrMode<E>>,
+    E: for<'i> ParserError<Partial<&'i [u8]>>,
+{
+    type Item = Result<O, ReadError<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        loop {
+            match self.buffer.parse(&mut self.parser) {
+                Step::Item(output) => return Some(Ok(output)),
+                Step::Fill(needed) => {
+                    if let Err(err) = self.fill(needed) {
+                        self.done = true;
+                        return Some(Err(err));
+                    }
+                }
+                Step::Error(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+                Step::Done => {
+                    self.done = true;
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+impl<R, P, O, E> crate::lib::std::iter::FusedIterator for ReadIterator<R, P, O, E>
+where
+    R: std::io::Read,
+    P: for<'i> Parser<Partial<&'i [u8]>, O, ErrMode<E>>,
+    E: for<'i> ParserError<Partial<&'i [u8]>>,
+{
+}
@@ -0,0 +1,309 @@
+use super::*;
+
+use crate::ascii::dec_uint;
+use crate::ascii::line_ending;
+use crate::binary::length_take;
+use crate::binary::u8;
+use crate::combinator::alt;
+use crate::combinator::eof;
+use crate::combinator::terminated;
+use crate::error::ContextError;
+use crate::error::ErrMode;
+use crate::error::Needed;
+use crate::prelude::*;
+use crate::stream::Partial;
+use crate::token::take;
+
+/// Yield at most one byte per `read`, forcing the parser through `Incomplete`
+struct Trickle<'i> {
+    data: &'i [u8],
+    interrupt: bool,
+}
+
+impl<'i> Trickle<'i> {
+    fn new(data: &'i [u8]) -> Self {
+        Self {
+            data,
+            interrupt: false,
+        }
+    }
+}
+
+impl std::io::Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.interrupt = !self.interrupt;
+        if self.interrupt {
+            return Err(std::io::ErrorKind::Interrupted.into());
+        }
+        let Some((first, rest)) = self.data.split_first() else {
+            return Ok(0);
+        };
+        buf[0] = *first;
+        self.data = rest;
+        Ok(1)
+    }
+}
+
+fn line(input: &mut Partial<&[u8]>) -> ModalResult<u32> {
+    terminated(dec_uint, line_ending).parse_next(input)
+}
+
+fn record(input: &mut Partial<&[u8]>) -> ModalResult<Vec<u8>> {
+    length_take(u8).map(<[u8]>::to_vec).parse_next(input)
+}
+
+#[test]
+fn read_lines() {
+    let values = ReadIterator::<_, _, _, ContextError>::new(Trickle::new(b"1\n22\n333\n"), line)
+        .capacity(1)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(values, [1, 22, 333]);
+}
+
+#[test]
+fn read_last_value_without_terminator() {
+    fn value(input: &mut Partial<&[u8]>) -> ModalResult<u32> {
+        terminated(dec_uint, alt((line_ending, eof))).parse_next(input)
+    }
+
+    let values = ReadIterator::<_, _, _, ContextError>::new(Trickle::new(b"1\n22"), value)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(values, [1, 22]);
+}
+
+#[test]
+fn read_grows_for_large_values() {
+    let mut data = vec![200];
+    data.extend([b'a'; 200]);
+    data.extend([1, b'b']);
+    let mut iter = ReadIterator::<_, _, _, ContextError>::new(&data[..], record).capacity(4);
+    assert_eq!(iter.next().unwrap().unwrap().len(), 200);
+    assert_eq!(iter.next().unwrap().unwrap(), b"b");
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn read_empty() {
+    let mut iter = ReadIterator::<_, _, _, ContextError>::new(&b""[..], line);
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn read_buffer_full() {
+    let data = [
+        10, b'a', b'b', b'c', b'd', b'e', b'f', b'g', b'h', b'i', b'j',
+    ];
+    let mut iter = ReadIterator::<_, _, _, ContextError>::new(&data[..], record)
+        .capacity(2)
+        .max_capacity(8);
+    assert!(matches!(iter.next(), Some(Err(ReadError::BufferFull))));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn read_incomplete_at_eof() {
+    let data = [3, b'a'];
+    let mut iter = ReadIterator::<_, _, _, ContextError>::new(Trickle::new(&data), record);
+    assert!(matches!(iter.next(), Some(Err(ReadError::Parse(_)))));
+    assert!(iter.next().is_none());
+
+    fn streaming(input: &mut Partial<&[u8]>) -> ModalResult<()> {
+        take(3usize).parse_next(input)?;
+        Err(ErrMode::Incomplete(Needed::new(2)))
+    }
+    let mut iter = ReadIterator::<_, _, _, ContextError>::new(&b"abc"[..], streaming);
+    assert!(matches!(
+        iter.next(),
+        Some(Err(ReadError::Incomplete(Needed::Size(_))))
+    ));
+}
+
+#[test]
+fn read_parse_error() {
+    let mut iter = ReadIterator::<_, _, _, ContextError>::new(&b"1\nx\n2\n"[..], line);
+    assert_eq!(iter.next().unwrap().unwrap(), 1);
+    assert!(matches!(iter.next(), Some(Err(ReadError::Parse(_)))));
+    assert!(iter.next().is_none());
+    let (_, rest) = iter.into_parts();
+    assert_eq!(rest, b"x\n2\n");
+}
+
+#[test]
+fn read_io_error() {
+    struct Failing;
+    impl std::io::Read for Failing {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::BrokenPipe.into())
+        }
+    }
+
+    let mut iter = ReadIterator::<_, _, _, ContextError>::new(Failing, line);
+    let err = iter.next().unwrap().unwrap_err();
+    assert!(matches!(err, ReadError::Io(_)));
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic)]
+fn read_must_consume() {
+    fn nothing(_input: &mut Partial<&[u8]>) -> ModalResult<()> {
+        Ok(())
+    }
+
+    let mut iter = ReadIterator::<_, _, _, ContextError>::new(&b"abc"[..], nothing);
+    assert!(matches!(iter.next(), Some(Err(ReadError::Parse(_)))));
+}
+
+/// Yield at most one byte per `poll_read`, returning `Pending` in between
+#[cfg(feature = "futures-io")]
+struct AsyncTrickle<'i> {
+    data: &'i [u8],
+    pending: bool,
+}
+
+#[cfg(feature = "futures-io")]
+impl<'i> AsyncTrickle<'i> {
+    fn new(data: &'i [u8]) -> Self {
+        Self {
+            data,
+            pending: false,
+        }
+    }
+}
+
+#[cfg(feature = "futures-io")]
+impl futures_io::AsyncRead for AsyncTrickle<'_> {
+    fn poll_read(
+        mut self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        buf: &mut [u8],
+    ) -> core::task::Poll<std::io::Result<usize>> {
+        self.pending = !self.pending;
+        if self.pending {
+            cx.waker().wake_by_ref();
+            return core::task::Poll::Pending;
+        }
+        let Some((first, rest)) = self.data.split_first() else {
+            return core::task::Poll::Ready(Ok(0));
+        };
+        buf[0] = *first;
+        self.data = rest;
+        core::task::Poll::Ready(Ok(1))
+    }
+}
+
+#[test]
+#[cfg(feature = "futures-io")]
+fn async_read_lines() {
+    use futures::StreamExt as _;
+
+    let stream =
+        AsyncReadStream::<_, _, _, ContextError>::new(AsyncTrickle::new(b"1\n22\n333\n"), line)
+            .capacity(1);
+    let values = futures::executor::block_on(stream.collect::<Vec<_>>())
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(values, [1, 22, 333]);
+}
+
+#[test]
+#[cfg(feature = "futures-io")]
+fn async_read_grows_for_large_values() {
+    use futures::StreamExt as _;
+
+    let mut data = vec![200];
+    data.extend([b'a'; 200]);
+    data.extend([1, b'b']);
+    let mut stream =
+        AsyncReadStream::<_, _, _, ContextError>::new(AsyncTrickle::new(&data), record).capacity(4);
+    futures::executor::block_on(async {
+        assert_eq!(stream.next().await.unwrap().unwrap().len(), 200);
+        assert_eq!(stream.next().await.unwrap().unwrap(), b"b");
+        assert!(stream.next().await.is_none());
+    });
+}
+
+#[test]
+#[cfg(feature = "futures-io")]
+fn async_read_errors() {
+    use futures::stream::FusedStream as _;
+    use futures::StreamExt as _;
+
+    let mut stream =
+        AsyncReadStream::<_, _, _, ContextError>::new(AsyncTrickle::new(b"1\nx\n2\n"), line);
+    futures::executor::block_on(async {
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(ReadError::Parse(_)))
+        ));
+        assert!(stream.is_terminated());
+        assert!(stream.next().await.is_none());
+    });
+
+    let data = [
+        10, b'a', b'b', b'c', b'd', b'e', b'f', b'g', b'h', b'i', b'j',
+    ];
+    let mut stream =
+        AsyncReadStream::<_, _, _, ContextError>::new(AsyncTrickle::new(&data), record)
+            .max_capacity(8);
+    futures::executor::block_on(async {
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(ReadError::BufferFull))
+        ));
+    });
+}
+
+#[test]
+#[cfg(feature = "tokio-util")]
+fn decoder_lines() {
+    use bytes::BytesMut;
+    use tokio_util::codec::Decoder as _;
+
+    let mut decoder = ParserDecoder::<_, _, ContextError>::new(line);
+    let mut buffer = BytesMut::new();
+    assert_eq!(decoder.decode(&mut buffer).unwrap(), None);
+    buffer.extend_from_slice(b"1\n2");
+    assert_eq!(decoder.decode(&mut buffer).unwrap(), Some(1));
+    assert_eq!(decoder.decode(&mut buffer).unwrap(), None);
+    assert_eq!(&buffer[..], b"2");
+    buffer.extend_from_slice(b"2\n");
+    assert_eq!(decoder.decode(&mut buffer).unwrap(), Some(22));
+    assert_eq!(decoder.decode_eof(&mut buffer).unwrap(), None);
+}
+
+#[test]
+#[cfg(feature = "tokio-util")]
+fn decoder_eof() {
+    use bytes::BytesMut;
+    use tokio_util::codec::Decoder as _;
+
+    let mut decoder = ParserDecoder::<_, _, ContextError>::new(record);
+    let mut buffer = BytesMut::from(&[3, b'a'][..]);
+    assert_eq!(decoder.decode(&mut buffer).unwrap(), None);
+    assert!(buffer.capacity() >= 4);
+    assert!(matches!(
+        decoder.decode_eof(&mut buffer),
+        Err(ReadError::Parse(_))
+    ));
+}
+
+#[test]
+#[cfg(feature = "tokio-util")]
+fn decoder_max_length() {
+    use bytes::BytesMut;
+    use tokio_util::codec::Decoder as _;
+
+    let mut decoder = ParserDecoder::<_, _, ContextError>::new(record).max_length(8);
+    let mut buffer = BytesMut::from(&[4, b'a'][..]);
+    assert_eq!(decoder.decode(&mut buffer).unwrap(), None);
+    let mut buffer = BytesMut::from(&[10, b'a'][..]);
+    assert!(matches!(
+        decoder.decode(&mut buffer),
+        Err(ReadError::BufferFull)
+    ));
+}
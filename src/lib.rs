@@ -114,7 +114,13 @@ pub mod stream;
 pub mod ascii;
 pub mod binary;
 pub mod combinator;
+#[cfg(feature = "serde")]
+pub mod de;
+#[cfg(feature = "std")]
+pub mod io;
 pub mod token;
+#[cfg(feature = "unicode")]
+pub mod unicode;
 
 #[cfg(feature = "unstable-doc")]
 pub mod _topic;
@@ -165,10 +171,13 @@ pub use error::Result;
 pub use parser::*;
 pub use stream::BStr;
 pub use stream::Bytes;
+pub use stream::LineLocatingSlice;
 pub use stream::LocatingSlice;
 pub use stream::Partial;
 pub use stream::Stateful;
 pub use stream::Str;
+#[cfg(feature = "derive")]
+pub use winnow_derive::Parse;
 
 #[cfg(test)]
 pub(crate) use error::TestResult;
@@ -6,10 +6,12 @@ use crate::combinator::impls;
 #[cfg(feature = "std")]
 use crate::error::FromRecoverableError;
 use crate::error::{AddContext, FromExternalError, ParseError, ParserError, Result};
-use crate::stream::{Compare, Location, ParseSlice, Stream, StreamIsPartial};
+use crate::stream::{Compare, LineLocation, Location, ParseSlice, Stream, StreamIsPartial};
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 use crate::stream::{Recover, Recoverable};
+#[cfg(feature = "unicode")]
+use crate::unicode::{Caseless as UnicodeCaseless, SimpleCaseless};
 
 /// Core trait for parsing
 ///
@@ -445,6 +447,80 @@ pub trait Parser<I, O, E> {
         }
     }
 
+    /// Produce the line and column of the consumed input as produced value.
+    ///
+    /// Like [`Parser::span`] but with the [`Position`][crate::stream::Position]s tracked by
+    /// [`LineLocatingSlice`][crate::stream::LineLocatingSlice].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// # use winnow::{error::ErrMode, stream::Stream};
+    /// use winnow::stream::LineLocatingSlice;
+    /// use winnow::ascii::alpha1;
+    /// use winnow::combinator::separated_pair;
+    ///
+    /// fn parser<'i>(input: &mut LineLocatingSlice<&'i str>) -> ModalResult<(usize, usize)> {
+    ///     separated_pair(alpha1, '\n', alpha1.line_span())
+    ///         .map(|(_, span)| (span.start.line(), span.start.column()))
+    ///         .parse_next(input)
+    /// }
+    ///
+    /// assert_eq!(parser.parse(LineLocatingSlice::new("abcd\nefgh")), Ok((1, 0)));
+    /// assert!(parser.parse_peek(LineLocatingSlice::new("abcd;")).is_err());
+    /// ```
+    #[inline(always)]
+    fn line_span(self) -> impls::LineSpan<Self, I, O, E>
+    where
+        Self: core::marker::Sized,
+        I: Stream + LineLocation,
+    {
+        impls::LineSpan {
+            parser: self,
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+
+    /// Produce the line and column of consumed input with the output
+    ///
+    /// Functions similarly to [`Parser::line_span`] except it
+    /// returns the parser output as well.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// # use winnow::{error::ErrMode, stream::Stream};
+    /// use winnow::stream::LineLocatingSlice;
+    /// use winnow::ascii::alpha1;
+    /// use winnow::combinator::separated_pair;
+    ///
+    /// fn parser<'i>(input: &mut LineLocatingSlice<&'i str>) -> ModalResult<(&'i str, usize)> {
+    ///     separated_pair(alpha1, '\n', alpha1.with_line_span())
+    ///         .map(|(_, (word, span))| (word, span.end.column_utf16()))
+    ///         .parse_next(input)
+    /// }
+    ///
+    /// assert_eq!(parser.parse(LineLocatingSlice::new("abcd\nefgh")), Ok(("efgh", 4)));
+    /// assert!(parser.parse_peek(LineLocatingSlice::new("abcd;")).is_err());
+    /// ```
+    #[inline(always)]
+    fn with_line_span(self) -> impls::WithLineSpan<Self, I, O, E>
+    where
+        Self: core::marker::Sized,
+        I: Stream + LineLocation,
+    {
+        impls::WithLineSpan {
+            parser: self,
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+
     /// Maps a function over the output of a parser
     ///
     /// # Example
@@ -1113,6 +1189,64 @@ where
     }
 }
 
+/// This is a shortcut for [`literal`][crate::token::literal].
+///
+/// # Example
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::combinator::alt;
+/// # use winnow::token::take;
+/// # use winnow::unicode::Caseless;
+///
+/// fn parser<'s>(s: &mut &'s str) -> ModalResult<&'s str> {
+///   alt((Caseless("straße"), take(5usize))).parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek("STRASSE!"), Ok(("!", "STRASSE")));
+/// assert_eq!(parser.parse_peek("Something"), Ok(("hing", "Somet")));
+/// assert!(parser.parse_peek("Some").is_err());
+/// ```
+#[cfg(feature = "unicode")]
+impl<'s, I, E: ParserError<I>> Parser<I, <I as Stream>::Slice, E> for UnicodeCaseless<&'s str>
+where
+    I: Compare<UnicodeCaseless<&'s str>> + StreamIsPartial,
+    I: Stream,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, i: &mut I) -> Result<<I as Stream>::Slice, E> {
+        crate::token::literal(*self).parse_next(i)
+    }
+}
+
+/// This is a shortcut for [`literal`][crate::token::literal].
+///
+/// # Example
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::combinator::alt;
+/// # use winnow::token::take;
+/// # use winnow::unicode::SimpleCaseless;
+///
+/// fn parser<'s>(s: &mut &'s str) -> ModalResult<&'s str> {
+///   alt((SimpleCaseless("ΣΕΛΕΚΤ"), take(5usize))).parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek("σελεκτ!"), Ok(("!", "σελεκτ")));
+/// assert_eq!(parser.parse_peek("Something"), Ok(("hing", "Somet")));
+/// assert!(parser.parse_peek("Some").is_err());
+/// ```
+#[cfg(feature = "unicode")]
+impl<'s, I, E: ParserError<I>> Parser<I, <I as Stream>::Slice, E> for SimpleCaseless<&'s str>
+where
+    I: Compare<SimpleCaseless<&'s str>> + StreamIsPartial,
+    I: Stream,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, i: &mut I) -> Result<<I as Stream>::Slice, E> {
+        crate::token::literal(*self).parse_next(i)
+    }
+}
+
 impl<I: Stream, E: ParserError<I>> Parser<I, (), E> for () {
     #[inline(always)]
     fn parse_next(&mut self, _i: &mut I) -> Result<(), E> {
@@ -1191,6 +1325,57 @@ pub trait ModalParser<I, O, E>: Parser<I, O, crate::error::ErrMode<E>> {}
 
 impl<I, O, E, P> ModalParser<I, O, E> for P where P: Parser<I, O, crate::error::ErrMode<E>> {}
 
+/// A type with a canonical parser
+///
+/// This is usually implemented with `#[derive(Parse)]`, see the `derive` feature.
+///
+/// Reference the parser as `T::parse_next`, e.g. `Header::parse_next.parse_peek(input)`.
+///
+/// # Example
+///
+/// ```rust
+/// use winnow::prelude::*;
+/// use winnow::binary::be_u16;
+/// use winnow::error::ParserError;
+/// use winnow::stream::{Stream, StreamIsPartial};
+/// use winnow::Parse;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Point {
+///     x: u16,
+///     y: u16,
+/// }
+///
+/// impl<I, E> Parse<I, E> for Point
+/// where
+///     I: StreamIsPartial + Stream<Token = u8>,
+///     E: ParserError<I>,
+/// {
+///     fn parse_next(input: &mut I) -> Result<Self, E> {
+///         let (x, y) = (be_u16, be_u16).parse_next(input)?;
+///         Ok(Point { x, y })
+///     }
+/// }
+///
+/// fn parser(input: &mut &[u8]) -> ModalResult<Point> {
+///     Point::parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&b"\x00\x01\x00\x02"[..]), Ok((&b""[..], Point { x: 1, y: 2 })));
+/// ```
+pub trait Parse<I, E>: Sized {
+    /// Parse `Self` from the start of the input, advancing past it
+    fn parse_next(input: &mut I) -> Result<Self, E>;
+}
+
+#[cfg(feature = "alloc")]
+impl<I, E, T: Parse<I, E>> Parse<I, E> for Box<T> {
+    #[inline(always)]
+    fn parse_next(input: &mut I) -> Result<Self, E> {
+        T::parse_next(input).map(Box::new)
+    }
+}
+
 /// Collect all errors when parsing the input
 ///
 /// [`Parser`]s will need to use [`Recoverable<I, _>`] for their input.
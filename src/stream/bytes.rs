@@ -9,6 +9,7 @@ use crate::stream::Checkpoint;
 use crate::stream::Compare;
 use crate::stream::CompareResult;
 use crate::stream::FindSlice;
+use crate::stream::FindSliceAny;
 use crate::stream::Offset;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
@@ -209,6 +210,21 @@ where
     }
 }
 
+impl<'i, T> FindSliceAny<T> for &'i Bytes
+where
+    &'i [u8]: FindSliceAny<T>,
+{
+    #[inline(always)]
+    fn find_slice_any(&self, substrs: T) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+        (*self).as_bytes().find_slice_any(substrs)
+    }
+
+    #[inline(always)]
+    fn find_partial_any(&self, substrs: T) -> Option<usize> {
+        (*self).as_bytes().find_partial_any(substrs)
+    }
+}
+
 impl UpdateSlice for &Bytes {
     #[inline(always)]
     fn update_slice(self, inner: Self::Slice) -> Self {
@@ -0,0 +1,204 @@
+use core::num::NonZeroUsize;
+
+use crate::error::Needed;
+use crate::lib::std::iter::Enumerate;
+use crate::stream::AsBStr;
+use crate::stream::AsBytes;
+use crate::stream::Checkpoint;
+use crate::stream::Compare;
+use crate::stream::CompareResult;
+use crate::stream::FindSlice;
+use crate::stream::FindSliceAny;
+use crate::stream::Offset;
+#[cfg(feature = "unstable-recover")]
+#[cfg(feature = "std")]
+use crate::stream::Recover;
+use crate::stream::SliceLen;
+use crate::stream::Stream;
+use crate::stream::StreamIsPartial;
+use crate::stream::UpdateSlice;
+
+use bytes::Bytes;
+
+impl SliceLen for Bytes {
+    #[inline(always)]
+    fn slice_len(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Slices share the underlying buffer, rather than borrowing from the stream
+impl Stream for Bytes {
+    type Token = u8;
+    type Slice = Bytes;
+
+    type IterOffsets = Enumerate<bytes::buf::IntoIter<Bytes>>;
+
+    type Checkpoint = Checkpoint<Self, Self>;
+
+    #[inline(always)]
+    fn iter_offsets(&self) -> Self::IterOffsets {
+        self.clone().into_iter().enumerate()
+    }
+    #[inline(always)]
+    fn eof_offset(&self) -> usize {
+        self.len()
+    }
+
+    #[inline(always)]
+    fn next_token(&mut self) -> Option<Self::Token> {
+        let token = self.peek_token()?;
+        bytes::Buf::advance(self, 1);
+        Some(token)
+    }
+
+    #[inline(always)]
+    fn peek_token(&self) -> Option<Self::Token> {
+        self.first().copied()
+    }
+
+    #[inline(always)]
+    fn offset_for<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(Self::Token) -> bool,
+    {
+        self.iter().position(|b| predicate(*b))
+    }
+    #[inline(always)]
+    fn offset_at(&self, tokens: usize) -> Result<usize, Needed> {
+        if let Some(needed) = tokens.checked_sub(self.len()).and_then(NonZeroUsize::new) {
+            Err(Needed::Size(needed))
+        } else {
+            Ok(tokens)
+        }
+    }
+    #[inline(always)]
+    fn next_slice(&mut self, offset: usize) -> Self::Slice {
+        self.split_to(offset)
+    }
+    #[inline(always)]
+    fn peek_slice(&self, offset: usize) -> Self::Slice {
+        self.slice(..offset)
+    }
+
+    #[inline(always)]
+    fn checkpoint(&self) -> Self::Checkpoint {
+        Checkpoint::<_, Self>::new(self.clone())
+    }
+    #[inline(always)]
+    fn reset(&mut self, checkpoint: &Self::Checkpoint) {
+        *self = checkpoint.inner.clone();
+    }
+
+    #[inline(always)]
+    fn raw(&self) -> &dyn crate::lib::std::fmt::Debug {
+        self
+    }
+}
+
+#[cfg(feature = "unstable-recover")]
+#[cfg(feature = "std")]
+impl<E> Recover<E> for Bytes {
+    #[inline(always)]
+    fn record_err(
+        &mut self,
+        _token_start: &Self::Checkpoint,
+        _err_start: &Self::Checkpoint,
+        err: E,
+    ) -> Result<(), E> {
+        Err(err)
+    }
+
+    /// Report whether the [`Stream`] can save off errors for recovery
+    #[inline(always)]
+    fn is_recovery_supported() -> bool {
+        false
+    }
+}
+
+impl StreamIsPartial for Bytes {
+    type PartialState = ();
+
+    #[inline]
+    fn complete(&mut self) -> Self::PartialState {
+        // Already complete
+    }
+
+    #[inline]
+    fn restore_partial(&mut self, _state: Self::PartialState) {}
+
+    #[inline(always)]
+    fn is_partial_supported() -> bool {
+        false
+    }
+}
+
+impl Offset for Bytes {
+    #[inline(always)]
+    fn offset_from(&self, start: &Self) -> usize {
+        self.as_ref().offset_from(&start.as_ref())
+    }
+}
+
+impl Offset<<Bytes as Stream>::Checkpoint> for Bytes {
+    #[inline(always)]
+    fn offset_from(&self, other: &<Bytes as Stream>::Checkpoint) -> usize {
+        self.offset_from(&other.inner)
+    }
+}
+
+impl AsBytes for Bytes {
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+impl AsBStr for Bytes {
+    #[inline(always)]
+    fn as_bstr(&self) -> &[u8] {
+        self
+    }
+}
+
+impl<T> Compare<T> for Bytes
+where
+    for<'a> &'a [u8]: Compare<T>,
+{
+    #[inline(always)]
+    fn compare(&self, t: T) -> CompareResult {
+        self.as_ref().compare(t)
+    }
+}
+
+impl<S> FindSlice<S> for Bytes
+where
+    for<'a> &'a [u8]: FindSlice<S>,
+{
+    #[inline(always)]
+    fn find_slice(&self, substr: S) -> Option<crate::lib::std::ops::Range<usize>> {
+        self.as_ref().find_slice(substr)
+    }
+}
+
+impl<T> FindSliceAny<T> for Bytes
+where
+    for<'a> &'a [u8]: FindSliceAny<T>,
+{
+    #[inline(always)]
+    fn find_slice_any(&self, substrs: T) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+        self.as_ref().find_slice_any(substrs)
+    }
+
+    #[inline(always)]
+    fn find_partial_any(&self, substrs: T) -> Option<usize> {
+        self.as_ref().find_partial_any(substrs)
+    }
+}
+
+impl UpdateSlice for Bytes {
+    #[inline(always)]
+    fn update_slice(self, inner: Self::Slice) -> Self {
+        inner
+    }
+}
@@ -0,0 +1,370 @@
+use crate::error::Needed;
+use crate::stream::AsBStr;
+use crate::stream::AsBytes;
+use crate::stream::Checkpoint;
+use crate::stream::Compare;
+use crate::stream::CompareResult;
+use crate::stream::FindSlice;
+use crate::stream::FindSliceAny;
+use crate::stream::LineLocation;
+use crate::stream::Location;
+#[cfg(feature = "alloc")]
+use crate::stream::Memo;
+#[cfg(feature = "alloc")]
+use crate::stream::MemoCache;
+use crate::stream::Offset;
+use crate::stream::Position;
+#[cfg(feature = "unstable-recover")]
+#[cfg(feature = "std")]
+use crate::stream::Recover;
+use crate::stream::SliceLen;
+use crate::stream::Stream;
+use crate::stream::StreamIsPartial;
+use crate::stream::UpdateSlice;
+
+/// Allow collecting the line and column of a parsed token within a slice
+///
+/// Like [`LocatingSlice`][crate::stream::LocatingSlice] but the [`Position`] is updated as
+/// tokens are consumed, so reporting a line and column does not require a second pass over the
+/// input.  Columns are tracked in bytes, `char`s, and UTF-16 code units.
+///
+/// See [`Parser::line_span`][crate::Parser::line_span] and
+/// [`Parser::with_line_span`][crate::Parser::with_line_span] for more details
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use std::ops::Range;
+/// use winnow::stream::LineLocatingSlice;
+/// use winnow::stream::Position;
+/// use winnow::token::take_till;
+/// use winnow::ascii::multispace1;
+///
+/// fn second_word(input: &mut LineLocatingSlice<&str>) -> ModalResult<Range<Position>> {
+///     let _ = (take_till(1.., ' '), multispace1).parse_next(input)?;
+///     take_till(1.., ' ').line_span().parse_next(input)
+/// }
+///
+/// let span = second_word.parse(LineLocatingSlice::new("hello\n  wörld")).unwrap();
+/// assert_eq!(span.start.line(), 1);
+/// assert_eq!(span.start.column(), 2);
+/// assert_eq!(span.end.column(), 8);
+/// assert_eq!(span.end.column_chars(), 7);
+/// ```
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+#[doc(alias = "LineColumn")]
+pub struct LineLocatingSlice<I> {
+    input: I,
+    position: Position,
+}
+
+impl<I> LineLocatingSlice<I> {
+    /// Wrap another Stream with line and column tracking
+    pub fn new(input: I) -> Self {
+        Self {
+            input,
+            position: Position::default(),
+        }
+    }
+
+    /// The location of the next token
+    #[inline(always)]
+    pub fn position(&self) -> Position {
+        self.position
+    }
+}
+
+impl<I> LineLocatingSlice<I>
+where
+    I: Stream,
+    I::Slice: AsBStr,
+{
+    #[inline]
+    fn advance(&mut self, slice: I::Slice) -> I::Slice {
+        self.position.advance(slice.as_bstr());
+        slice
+    }
+}
+
+impl<I> AsRef<I> for LineLocatingSlice<I> {
+    #[inline(always)]
+    fn as_ref(&self) -> &I {
+        &self.input
+    }
+}
+
+impl<I> crate::lib::std::ops::Deref for LineLocatingSlice<I> {
+    type Target = I;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.input
+    }
+}
+
+impl<I: crate::lib::std::fmt::Display> crate::lib::std::fmt::Display for LineLocatingSlice<I> {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        self.input.fmt(f)
+    }
+}
+
+impl<I> SliceLen for LineLocatingSlice<I>
+where
+    I: SliceLen,
+{
+    #[inline(always)]
+    fn slice_len(&self) -> usize {
+        self.input.slice_len()
+    }
+}
+
+impl<I> Stream for LineLocatingSlice<I>
+where
+    I: Stream + Clone,
+    I::Slice: AsBStr,
+{
+    type Token = <I as Stream>::Token;
+    type Slice = <I as Stream>::Slice;
+
+    type IterOffsets = <I as Stream>::IterOffsets;
+
+    type Checkpoint = Checkpoint<Self, Self>;
+
+    #[inline(always)]
+    fn iter_offsets(&self) -> Self::IterOffsets {
+        self.input.iter_offsets()
+    }
+    #[inline(always)]
+    fn eof_offset(&self) -> usize {
+        self.input.eof_offset()
+    }
+
+    #[inline(always)]
+    fn next_token(&mut self) -> Option<Self::Token> {
+        let offset = self.input.offset_at(1).ok()?;
+        let slice = self.input.peek_slice(offset);
+        let token = self.input.next_token()?;
+        self.position.advance(slice.as_bstr());
+        Some(token)
+    }
+
+    #[inline(always)]
+    fn peek_token(&self) -> Option<Self::Token> {
+        self.input.peek_token()
+    }
+
+    #[inline(always)]
+    fn offset_for<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(Self::Token) -> bool,
+    {
+        self.input.offset_for(predicate)
+    }
+    #[inline(always)]
+    fn offset_at(&self, tokens: usize) -> Result<usize, Needed> {
+        self.input.offset_at(tokens)
+    }
+    #[inline(always)]
+    fn next_slice(&mut self, offset: usize) -> Self::Slice {
+        let slice = self.input.next_slice(offset);
+        self.advance(slice)
+    }
+    #[inline(always)]
+    fn peek_slice(&self, offset: usize) -> Self::Slice {
+        self.input.peek_slice(offset)
+    }
+
+    #[inline(always)]
+    fn checkpoint(&self) -> Self::Checkpoint {
+        Checkpoint::<_, Self>::new(self.clone())
+    }
+    #[inline(always)]
+    fn reset(&mut self, checkpoint: &Self::Checkpoint) {
+        *self = checkpoint.inner.clone();
+    }
+
+    #[inline(always)]
+    fn raw(&self) -> &dyn crate::lib::std::fmt::Debug {
+        &self.input
+    }
+}
+
+impl<I> Location for LineLocatingSlice<I> {
+    #[inline(always)]
+    fn previous_token_end(&self) -> usize {
+        // Assumptions:
+        // - Tokens are continuous
+        self.position.offset()
+    }
+    #[inline(always)]
+    fn current_token_start(&self) -> usize {
+        self.position.offset()
+    }
+}
+
+impl<I> LineLocation for LineLocatingSlice<I> {
+    #[inline(always)]
+    fn previous_token_end_position(&self) -> Position {
+        // Assumptions:
+        // - Tokens are continuous
+        self.position
+    }
+    #[inline(always)]
+    fn current_token_start_position(&self) -> Position {
+        self.position
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Memo for LineLocatingSlice<I>
+where
+    I: Memo + Clone,
+    I::Slice: AsBStr,
+{
+    #[inline(always)]
+    fn memo_offset(&self) -> usize {
+        self.input.memo_offset()
+    }
+    #[inline(always)]
+    fn memo_cache(&self) -> &MemoCache {
+        self.input.memo_cache()
+    }
+}
+
+#[cfg(feature = "unstable-recover")]
+#[cfg(feature = "std")]
+impl<I, E> Recover<E> for LineLocatingSlice<I>
+where
+    I: Recover<E>,
+    I: Stream + Clone,
+    I::Slice: AsBStr,
+{
+    #[inline(always)]
+    fn record_err(
+        &mut self,
+        _token_start: &Self::Checkpoint,
+        _err_start: &Self::Checkpoint,
+        err: E,
+    ) -> Result<(), E> {
+        Err(err)
+    }
+
+    /// Report whether the [`Stream`] can save off errors for recovery
+    #[inline(always)]
+    fn is_recovery_supported() -> bool {
+        false
+    }
+}
+
+impl<I> StreamIsPartial for LineLocatingSlice<I>
+where
+    I: StreamIsPartial,
+{
+    type PartialState = I::PartialState;
+
+    #[inline]
+    fn complete(&mut self) -> Self::PartialState {
+        self.input.complete()
+    }
+
+    #[inline]
+    fn restore_partial(&mut self, state: Self::PartialState) {
+        self.input.restore_partial(state);
+    }
+
+    #[inline(always)]
+    fn is_partial_supported() -> bool {
+        I::is_partial_supported()
+    }
+
+    #[inline(always)]
+    fn is_partial(&self) -> bool {
+        self.input.is_partial()
+    }
+}
+
+impl<I> Offset for LineLocatingSlice<I> {
+    #[inline(always)]
+    fn offset_from(&self, other: &Self) -> usize {
+        self.position.offset() - other.position.offset()
+    }
+}
+
+impl<I> Offset<<LineLocatingSlice<I> as Stream>::Checkpoint> for LineLocatingSlice<I>
+where
+    I: Stream + Clone,
+    I::Slice: AsBStr,
+{
+    #[inline(always)]
+    fn offset_from(&self, other: &<LineLocatingSlice<I> as Stream>::Checkpoint) -> usize {
+        self.offset_from(&other.inner)
+    }
+}
+
+impl<I> AsBytes for LineLocatingSlice<I>
+where
+    I: AsBytes,
+{
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        self.input.as_bytes()
+    }
+}
+
+impl<I> AsBStr for LineLocatingSlice<I>
+where
+    I: AsBStr,
+{
+    #[inline(always)]
+    fn as_bstr(&self) -> &[u8] {
+        self.input.as_bstr()
+    }
+}
+
+impl<I, U> Compare<U> for LineLocatingSlice<I>
+where
+    I: Compare<U>,
+{
+    #[inline(always)]
+    fn compare(&self, other: U) -> CompareResult {
+        self.input.compare(other)
+    }
+}
+
+impl<I, T> FindSlice<T> for LineLocatingSlice<I>
+where
+    I: FindSlice<T>,
+{
+    #[inline(always)]
+    fn find_slice(&self, substr: T) -> Option<crate::lib::std::ops::Range<usize>> {
+        self.input.find_slice(substr)
+    }
+}
+
+impl<I, T> FindSliceAny<T> for LineLocatingSlice<I>
+where
+    I: FindSliceAny<T>,
+{
+    #[inline(always)]
+    fn find_slice_any(&self, substrs: T) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+        self.input.find_slice_any(substrs)
+    }
+
+    #[inline(always)]
+    fn find_partial_any(&self, substrs: T) -> Option<usize> {
+        self.input.find_partial_any(substrs)
+    }
+}
+
+impl<I> UpdateSlice for LineLocatingSlice<I>
+where
+    I: UpdateSlice + Clone,
+    I::Slice: AsBStr,
+{
+    #[inline(always)]
+    fn update_slice(mut self, inner: Self::Slice) -> Self {
+        self.input = I::update_slice(self.input, inner);
+        self
+    }
+}
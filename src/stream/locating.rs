@@ -5,7 +5,12 @@ use crate::stream::Checkpoint;
 use crate::stream::Compare;
 use crate::stream::CompareResult;
 use crate::stream::FindSlice;
+use crate::stream::FindSliceAny;
 use crate::stream::Location;
+#[cfg(feature = "alloc")]
+use crate::stream::Memo;
+#[cfg(feature = "alloc")]
+use crate::stream::MemoCache;
 use crate::stream::Offset;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
@@ -182,6 +187,21 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<I> Memo for LocatingSlice<I>
+where
+    I: Memo,
+{
+    #[inline(always)]
+    fn memo_offset(&self) -> usize {
+        self.input.memo_offset()
+    }
+    #[inline(always)]
+    fn memo_cache(&self) -> &MemoCache {
+        self.input.memo_cache()
+    }
+}
+
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 impl<I, E> Recover<E> for LocatingSlice<I>
@@ -293,6 +313,21 @@ where
     }
 }
 
+impl<I, T> FindSliceAny<T> for LocatingSlice<I>
+where
+    I: FindSliceAny<T>,
+{
+    #[inline(always)]
+    fn find_slice_any(&self, substrs: T) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+        self.input.find_slice_any(substrs)
+    }
+
+    #[inline(always)]
+    fn find_partial_any(&self, substrs: T) -> Option<usize> {
+        self.input.find_partial_any(substrs)
+    }
+}
+
 impl<I> UpdateSlice for LocatingSlice<I>
 where
     I: UpdateSlice,
@@ -0,0 +1,618 @@
+use core::any::Any;
+use core::any::TypeId;
+use core::cell::RefCell;
+
+use crate::error::Needed;
+use crate::lib::std::boxed::Box;
+use crate::lib::std::collections::BTreeMap;
+use crate::stream::AsBStr;
+use crate::stream::AsBytes;
+use crate::stream::Checkpoint;
+use crate::stream::Compare;
+use crate::stream::CompareResult;
+use crate::stream::FindSlice;
+use crate::stream::FindSliceAny;
+use crate::stream::LineLocation;
+use crate::stream::Location;
+use crate::stream::Memo;
+use crate::stream::Offset;
+use crate::stream::Position;
+#[cfg(feature = "unstable-recover")]
+#[cfg(feature = "std")]
+use crate::stream::Recover;
+use crate::stream::SliceLen;
+use crate::stream::Stream;
+use crate::stream::StreamIsPartial;
+use crate::stream::UpdateSlice;
+
+/// Cache parse results for [`memoize`][crate::combinator::memoize]d rules
+///
+/// Rules wrapped in [`memoize`][crate::combinator::memoize] run at most once per offset, giving
+/// packrat parsing's linear-time guarantee even when [`alt`][crate::combinator::alt] backtracks
+/// over shared prefixes.
+///
+/// The [`MemoCache`] is keyed by offset, so it must only be used for a single input.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::dec_uint;
+/// use winnow::combinator::alt;
+/// use winnow::combinator::memoize;
+/// use winnow::stream::MemoCache;
+/// use winnow::stream::Memoized;
+///
+/// type Stream<'i, 'c> = Memoized<'c, &'i str>;
+///
+/// fn number(input: &mut Stream<'_, '_>) -> ModalResult<u32> {
+///     dec_uint.parse_next(input)
+/// }
+///
+/// fn pair(input: &mut Stream<'_, '_>) -> ModalResult<(u32, u32)> {
+///     alt((
+///         (memoize(number), '+', memoize(number)).map(|(a, _, b)| (a, b)),
+///         (memoize(number), '-', memoize(number)).map(|(a, _, b)| (a, b)),
+///     ))
+///     .parse_next(input)
+/// }
+///
+/// let cache = MemoCache::new();
+/// let input = Memoized::new("12-34", &cache);
+/// assert_eq!(pair.parse(input).unwrap(), (12, 34));
+/// // `12` was parsed once and reused by the second alternative
+/// assert_eq!(cache.len(), 2);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Memoized<'c, I> {
+    initial: I,
+    input: I,
+    cache: &'c MemoCache,
+}
+
+impl<'c, I> Memoized<'c, I>
+where
+    I: Clone + Offset,
+{
+    /// Wrap another Stream with a cache of parse results
+    pub fn new(input: I, cache: &'c MemoCache) -> Self {
+        let initial = input.clone();
+        Self {
+            initial,
+            input,
+            cache,
+        }
+    }
+}
+
+impl<I> Memoized<'_, I> {
+    /// The cache of parse results
+    #[inline(always)]
+    pub fn cache(&self) -> &MemoCache {
+        self.cache
+    }
+}
+
+impl<I> AsRef<I> for Memoized<'_, I> {
+    #[inline(always)]
+    fn as_ref(&self) -> &I {
+        &self.input
+    }
+}
+
+impl<I> crate::lib::std::ops::Deref for Memoized<'_, I> {
+    type Target = I;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.input
+    }
+}
+
+impl<I: crate::lib::std::fmt::Display> crate::lib::std::fmt::Display for Memoized<'_, I> {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        self.input.fmt(f)
+    }
+}
+
+impl<I> SliceLen for Memoized<'_, I>
+where
+    I: SliceLen,
+{
+    #[inline(always)]
+    fn slice_len(&self) -> usize {
+        self.input.slice_len()
+    }
+}
+
+impl<I: Stream> Stream for Memoized<'_, I> {
+    type Token = <I as Stream>::Token;
+    type Slice = <I as Stream>::Slice;
+
+    type IterOffsets = <I as Stream>::IterOffsets;
+
+    type Checkpoint = Checkpoint<I::Checkpoint, Self>;
+
+    #[inline(always)]
+    fn iter_offsets(&self) -> Self::IterOffsets {
+        self.input.iter_offsets()
+    }
+    #[inline(always)]
+    fn eof_offset(&self) -> usize {
+        self.input.eof_offset()
+    }
+
+    #[inline(always)]
+    fn next_token(&mut self) -> Option<Self::Token> {
+        self.input.next_token()
+    }
+
+    #[inline(always)]
+    fn peek_token(&self) -> Option<Self::Token> {
+        self.input.peek_token()
+    }
+
+    #[inline(always)]
+    fn offset_for<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(Self::Token) -> bool,
+    {
+        self.input.offset_for(predicate)
+    }
+    #[inline(always)]
+    fn offset_at(&self, tokens: usize) -> Result<usize, Needed> {
+        self.input.offset_at(tokens)
+    }
+    #[inline(always)]
+    fn next_slice(&mut self, offset: usize) -> Self::Slice {
+        self.input.next_slice(offset)
+    }
+    #[inline(always)]
+    fn peek_slice(&self, offset: usize) -> Self::Slice {
+        self.input.peek_slice(offset)
+    }
+
+    #[inline(always)]
+    fn checkpoint(&self) -> Self::Checkpoint {
+        Checkpoint::<_, Self>::new(self.input.checkpoint())
+    }
+    #[inline(always)]
+    fn reset(&mut self, checkpoint: &Self::Checkpoint) {
+        self.input.reset(&checkpoint.inner);
+    }
+
+    #[inline(always)]
+    fn raw(&self) -> &dyn crate::lib::std::fmt::Debug {
+        &self.input
+    }
+}
+
+impl<I> Memo for Memoized<'_, I>
+where
+    I: Stream + Clone,
+{
+    #[inline(always)]
+    fn memo_offset(&self) -> usize {
+        self.input.offset_from(&self.initial.checkpoint())
+    }
+    #[inline(always)]
+    fn memo_cache(&self) -> &MemoCache {
+        self.cache
+    }
+}
+
+impl<I> Location for Memoized<'_, I>
+where
+    I: Location,
+{
+    #[inline(always)]
+    fn previous_token_end(&self) -> usize {
+        self.input.previous_token_end()
+    }
+    #[inline(always)]
+    fn current_token_start(&self) -> usize {
+        self.input.current_token_start()
+    }
+}
+
+impl<I> LineLocation for Memoized<'_, I>
+where
+    I: LineLocation,
+{
+    #[inline(always)]
+    fn previous_token_end_position(&self) -> Position {
+        self.input.previous_token_end_position()
+    }
+    #[inline(always)]
+    fn current_token_start_position(&self) -> Position {
+        self.input.current_token_start_position()
+    }
+}
+
+#[cfg(feature = "unstable-recover")]
+#[cfg(feature = "std")]
+impl<I, E> Recover<E> for Memoized<'_, I>
+where
+    I: Recover<E>,
+    I: Stream,
+{
+    #[inline(always)]
+    fn record_err(
+        &mut self,
+        _token_start: &Self::Checkpoint,
+        _err_start: &Self::Checkpoint,
+        err: E,
+    ) -> Result<(), E> {
+        Err(err)
+    }
+
+    /// Report whether the [`Stream`] can save off errors for recovery
+    #[inline(always)]
+    fn is_recovery_supported() -> bool {
+        false
+    }
+}
+
+impl<I> StreamIsPartial for Memoized<'_, I>
+where
+    I: StreamIsPartial,
+{
+    type PartialState = I::PartialState;
+
+    #[inline]
+    fn complete(&mut self) -> Self::PartialState {
+        self.input.complete()
+    }
+
+    #[inline]
+    fn restore_partial(&mut self, state: Self::PartialState) {
+        self.input.restore_partial(state);
+    }
+
+    #[inline(always)]
+    fn is_partial_supported() -> bool {
+        I::is_partial_supported()
+    }
+
+    #[inline(always)]
+    fn is_partial(&self) -> bool {
+        self.input.is_partial()
+    }
+}
+
+impl<I> Offset for Memoized<'_, I>
+where
+    I: Stream,
+{
+    #[inline(always)]
+    fn offset_from(&self, other: &Self) -> usize {
+        self.offset_from(&other.checkpoint())
+    }
+}
+
+impl<'c, I> Offset<<Memoized<'c, I> as Stream>::Checkpoint> for Memoized<'c, I>
+where
+    I: Stream,
+{
+    #[inline(always)]
+    fn offset_from(&self, other: &<Memoized<'c, I> as Stream>::Checkpoint) -> usize {
+        self.checkpoint().offset_from(other)
+    }
+}
+
+impl<I> AsBytes for Memoized<'_, I>
+where
+    I: AsBytes,
+{
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        self.input.as_bytes()
+    }
+}
+
+impl<I> AsBStr for Memoized<'_, I>
+where
+    I: AsBStr,
+{
+    #[inline(always)]
+    fn as_bstr(&self) -> &[u8] {
+        self.input.as_bstr()
+    }
+}
+
+impl<I, U> Compare<U> for Memoized<'_, I>
+where
+    I: Compare<U>,
+{
+    #[inline(always)]
+    fn compare(&self, other: U) -> CompareResult {
+        self.input.compare(other)
+    }
+}
+
+impl<I, T> FindSlice<T> for Memoized<'_, I>
+where
+    I: FindSlice<T>,
+{
+    #[inline(always)]
+    fn find_slice(&self, substr: T) -> Option<crate::lib::std::ops::Range<usize>> {
+        self.input.find_slice(substr)
+    }
+}
+
+impl<I, T> FindSliceAny<T> for Memoized<'_, I>
+where
+    I: FindSliceAny<T>,
+{
+    #[inline(always)]
+    fn find_slice_any(&self, substrs: T) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+        self.input.find_slice_any(substrs)
+    }
+
+    #[inline(always)]
+    fn find_partial_any(&self, substrs: T) -> Option<usize> {
+        self.input.find_partial_any(substrs)
+    }
+}
+
+impl<I> UpdateSlice for Memoized<'_, I>
+where
+    I: UpdateSlice,
+{
+    #[inline(always)]
+    fn update_slice(mut self, inner: Self::Slice) -> Self {
+        self.input = I::update_slice(self.input, inner);
+        self
+    }
+}
+
+/// Parse results of [`memoize`][crate::combinator::memoize]d rules, see [`Memoized`]
+///
+/// Entries are keyed by rule and offset, so a cache must only be used for a single input.  To keep
+/// using it after the input is changed, report the change with [`MemoCache::edit`].
+#[derive(Default)]
+pub struct MemoCache {
+    entries: RefCell<BTreeMap<(TypeId, usize), Entry>>,
+}
+
+struct Entry {
+    value: Box<dyn Any>,
+    /// The amount of input the result covers, if it can be kept across edits
+    len: Option<usize>,
+    /// Move the result to a new offset, if it is [`reusable`][crate::combinator::reusable]
+    relocate: Option<fn(&mut dyn Any, usize, usize)>,
+}
+
+impl MemoCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of cached results
+    pub fn len(&self) -> usize {
+        self.entries.borrow().len()
+    }
+
+    /// Whether any results have been cached
+    pub fn is_empty(&self) -> bool {
+        self.entries.borrow().is_empty()
+    }
+
+    /// Forget all cached results, allowing reuse with a new input
+    pub fn clear(&mut self) {
+        self.entries.get_mut().clear();
+    }
+
+    /// Update the cache for `replaced` being replaced with `inserted` tokens of new input
+    ///
+    /// Successful results that end before the edit are kept as-is, allowing one token of
+    /// lookahead.  Results of [`reusable`][crate::combinator::reusable] rules that start after
+    /// the edit are [relocated][Relocate] to their new offset.  Everything else is dropped,
+    /// to be re-parsed.
+    ///
+    /// Offsets are relative to the start of the input passed to [`Memoized::new`].
+    pub fn edit(&mut self, replaced: crate::lib::std::ops::Range<usize>, inserted: usize) {
+        let new_end = replaced.start + inserted;
+        let entries = crate::lib::std::mem::take(self.entries.get_mut());
+        for ((rule, offset), mut entry) in entries {
+            let Some(len) = entry.len else {
+                continue;
+            };
+            let offset = if offset + len < replaced.start {
+                offset
+            } else if replaced.end <= offset {
+                let Some(relocate) = entry.relocate else {
+                    continue;
+                };
+                relocate(&mut *entry.value, replaced.end, new_end);
+                offset - replaced.end + new_end
+            } else {
+                continue;
+            };
+            self.entries.get_mut().insert((rule, offset), entry);
+        }
+    }
+
+    pub(crate) fn get<T: Clone + 'static>(&self, rule: TypeId, offset: usize) -> Option<T> {
+        self.entries
+            .borrow()
+            .get(&(rule, offset))
+            .and_then(|entry| entry.value.downcast_ref::<T>())
+            .cloned()
+    }
+
+    /// Cache `value`, covering `len` tokens if it should be kept across edits
+    pub(crate) fn insert<T: 'static>(
+        &self,
+        rule: TypeId,
+        offset: usize,
+        value: T,
+        len: Option<usize>,
+    ) {
+        let entry = Entry {
+            value: Box::new(value),
+            len,
+            relocate: None,
+        };
+        self.entries.borrow_mut().insert((rule, offset), entry);
+    }
+
+    /// Cache the `output` of a [`reusable`][crate::combinator::reusable] rule, covering `len`
+    /// tokens
+    pub(crate) fn insert_reusable<O: Relocate + 'static>(
+        &self,
+        rule: TypeId,
+        offset: usize,
+        output: O,
+        len: usize,
+    ) {
+        fn relocate<O: Relocate + 'static>(value: &mut dyn Any, from: usize, to: usize) {
+            if let Some((output, _)) = value.downcast_mut::<(O, usize)>() {
+                output.relocate(from, to);
+            }
+        }
+
+        let entry = Entry {
+            value: Box::new((output, len)),
+            len: Some(len),
+            relocate: Some(relocate::<O>),
+        };
+        self.entries.borrow_mut().insert((rule, offset), entry);
+    }
+
+    pub(crate) fn remove(&self, rule: TypeId, offset: usize) {
+        self.entries.borrow_mut().remove(&(rule, offset));
+    }
+}
+
+impl crate::lib::std::fmt::Debug for MemoCache {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        f.debug_struct("MemoCache")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// Update offsets in the output of a [`reusable`][crate::combinator::reusable] rule
+///
+/// When the input is [edited][MemoCache::edit], results after the edit are reused at a new offset.
+/// Every offset in them, like the spans from [`Parser::span`][crate::Parser::span], is at or after
+/// `from` and needs to move to `to`, i.e. `offset - from + to`.
+///
+/// `usize` is not implemented as it is unclear whether it is an offset.
+///
+/// # Example
+///
+/// ```rust
+/// use std::ops::Range;
+/// use winnow::stream::Relocate;
+///
+/// #[derive(Clone)]
+/// struct Ident {
+///     name: String,
+///     span: Range<usize>,
+/// }
+///
+/// impl Relocate for Ident {
+///     fn relocate(&mut self, from: usize, to: usize) {
+///         self.span.relocate(from, to);
+///     }
+/// }
+/// ```
+pub trait Relocate {
+    /// Move offsets at or after `from` to start at `to`
+    fn relocate(&mut self, from: usize, to: usize);
+}
+
+impl Relocate for crate::lib::std::ops::Range<usize> {
+    #[inline]
+    fn relocate(&mut self, from: usize, to: usize) {
+        self.start = self.start - from + to;
+        self.end = self.end - from + to;
+    }
+}
+
+impl<T: Relocate> Relocate for Option<T> {
+    #[inline]
+    fn relocate(&mut self, from: usize, to: usize) {
+        if let Some(value) = self {
+            value.relocate(from, to);
+        }
+    }
+}
+
+impl<T: Relocate + ?Sized> Relocate for Box<T> {
+    #[inline]
+    fn relocate(&mut self, from: usize, to: usize) {
+        (**self).relocate(from, to);
+    }
+}
+
+impl<T: Relocate> Relocate for [T] {
+    #[inline]
+    fn relocate(&mut self, from: usize, to: usize) {
+        for value in self {
+            value.relocate(from, to);
+        }
+    }
+}
+
+impl<T: Relocate> Relocate for crate::lib::std::vec::Vec<T> {
+    #[inline]
+    fn relocate(&mut self, from: usize, to: usize) {
+        self.as_mut_slice().relocate(from, to);
+    }
+}
+
+macro_rules! impl_relocate_noop {
+    ($($ty:ty),*) => {
+        $(
+            impl Relocate for $ty {
+                #[inline(always)]
+                fn relocate(&mut self, _from: usize, _to: usize) {}
+            }
+        )*
+    };
+}
+
+impl_relocate_noop!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    &'static str,
+    crate::lib::std::string::String
+);
+
+macro_rules! impl_relocate_tuple {
+    ($($name:ident)+) => {
+        impl<$($name: Relocate),+> Relocate for ($($name,)+) {
+            #[inline]
+            #[allow(non_snake_case)]
+            fn relocate(&mut self, from: usize, to: usize) {
+                let ($($name,)+) = self;
+                $($name.relocate(from, to);)+
+            }
+        }
+    };
+}
+
+impl_relocate_tuple!(A);
+impl_relocate_tuple!(A B);
+impl_relocate_tuple!(A B C);
+impl_relocate_tuple!(A B C D);
+impl_relocate_tuple!(A B C D E);
+impl_relocate_tuple!(A B C D E F);
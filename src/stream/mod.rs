@@ -2,10 +2,15 @@
 //!
 //! Stream types include:
 //! - `&[u8]` and [`Bytes`] for binary data
+//! - [`bytes::Bytes`](https://docs.rs/bytes/latest/bytes/struct.Bytes.html) for shared binary data,
+//!   returning zero-copy slices (requires the `bytes` feature)
 //! - `&str` (aliased as [`Str`]) and [`BStr`] for UTF-8 data
 //! - [`LocatingSlice`] can track the location within the original buffer to report
 //!   [spans][crate::Parser::with_span]
+//! - [`LineLocatingSlice`] can track the line and column within the original buffer to report
+//!   [spans][crate::Parser::with_line_span]
 //! - [`Stateful`] to thread global state through your parsers
+//! - [`Memoized`] to cache the results of [`memoize`][crate::combinator::memoize]d rules
 //! - [`Partial`] can mark an input as partial buffer that is being streamed into
 //! - [Custom stream types][crate::_topic::stream]
 
@@ -19,11 +24,15 @@ use crate::lib::std::slice::Iter;
 use crate::lib::std::str::from_utf8;
 use crate::lib::std::str::CharIndices;
 use crate::lib::std::str::FromStr;
+#[cfg(feature = "unicode")]
+use crate::unicode::{Caseless as UnicodeCaseless, Folding, SimpleCaseless};
 
 #[allow(unused_imports)]
 #[cfg(any(feature = "unstable-doc", feature = "unstable-recover"))]
 use crate::error::ErrMode;
 
+#[cfg(feature = "alloc")]
+use crate::lib::std::borrow::Cow;
 #[cfg(feature = "alloc")]
 use crate::lib::std::collections::BTreeMap;
 #[cfg(feature = "alloc")]
@@ -39,7 +48,12 @@ use crate::lib::std::vec::Vec;
 
 mod bstr;
 mod bytes;
+#[cfg(feature = "bytes")]
+mod bytes_crate;
+mod line_locating;
 mod locating;
+#[cfg(feature = "alloc")]
+mod memoized;
 mod partial;
 mod range;
 #[cfg(feature = "unstable-recover")]
@@ -52,7 +66,14 @@ mod token;
 
 pub use bstr::BStr;
 pub use bytes::Bytes;
+pub use line_locating::LineLocatingSlice;
 pub use locating::LocatingSlice;
+#[cfg(feature = "alloc")]
+pub use memoized::MemoCache;
+#[cfg(feature = "alloc")]
+pub use memoized::Memoized;
+#[cfg(feature = "alloc")]
+pub use memoized::Relocate;
 pub use partial::Partial;
 pub use range::Range;
 #[cfg(feature = "unstable-recover")]
@@ -78,6 +99,22 @@ impl<S: SliceLen> SliceLen for AsciiCaseless<S> {
     }
 }
 
+#[cfg(feature = "unicode")]
+impl<S: SliceLen> SliceLen for UnicodeCaseless<S> {
+    #[inline(always)]
+    fn slice_len(&self) -> usize {
+        self.0.slice_len()
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<S: SliceLen> SliceLen for SimpleCaseless<S> {
+    #[inline(always)]
+    fn slice_len(&self) -> usize {
+        self.0.slice_len()
+    }
+}
+
 impl<T> SliceLen for &[T] {
     #[inline(always)]
     fn slice_len(&self) -> usize {
@@ -548,6 +585,103 @@ pub trait Location {
     fn current_token_start(&self) -> usize;
 }
 
+/// Current parse locations as line and column
+///
+/// See [`LineLocatingSlice`] for adding line and column tracking to your [`Stream`]
+pub trait LineLocation: Location {
+    /// Previous token's end position
+    fn previous_token_end_position(&self) -> Position;
+    /// Current token's start position
+    fn current_token_start_position(&self) -> Position;
+}
+
+/// Cached parse results for [`memoize`][crate::combinator::memoize]
+///
+/// See [`Memoized`] for adding a cache to your [`Stream`]
+#[cfg(feature = "alloc")]
+pub trait Memo: Stream {
+    /// Offset of the next token from the start of the input
+    fn memo_offset(&self) -> usize;
+    /// Cache shared by all checkpoints of the input
+    fn memo_cache(&self) -> &MemoCache;
+}
+
+/// Line and column of a location within the input, see [`LineLocation`]
+///
+/// Lines and columns are 0-based, like offsets, and lines are delimited by `\n`.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    offset: usize,
+    line: usize,
+    line_start: usize,
+    column_chars: usize,
+    column_utf16: usize,
+}
+
+impl Position {
+    /// Offset from the start of the input, in bytes
+    #[inline(always)]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// 0-based line number
+    #[inline(always)]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Offset of the start of [`Position::line`] from the start of the input, in bytes
+    #[inline(always)]
+    pub fn line_start(&self) -> usize {
+        self.line_start
+    }
+
+    /// 0-based column, in bytes
+    #[inline(always)]
+    pub fn column(&self) -> usize {
+        self.offset - self.line_start
+    }
+
+    /// 0-based column, in `char`s
+    ///
+    /// Invalid UTF-8 is counted as one `char` per byte that is not a UTF-8 continuation byte.
+    #[inline(always)]
+    pub fn column_chars(&self) -> usize {
+        self.column_chars
+    }
+
+    /// 0-based column, in UTF-16 code units
+    ///
+    /// This is what the [Language Server Protocol](https://microsoft.github.io/language-server-protocol/)
+    /// uses by default.
+    #[inline(always)]
+    pub fn column_utf16(&self) -> usize {
+        self.column_utf16
+    }
+
+    /// Move past `consumed`, which starts at this position
+    #[inline]
+    pub(crate) fn advance(&mut self, consumed: &[u8]) {
+        let mut line = consumed;
+        if let Some(nl) = consumed.iter().rposition(|b| *b == b'\n') {
+            self.line += consumed[..nl].iter().filter(|b| **b == b'\n').count() + 1;
+            self.line_start = self.offset + nl + 1;
+            self.column_chars = 0;
+            self.column_utf16 = 0;
+            line = &consumed[nl + 1..];
+        }
+        for b in line {
+            // Count on the lead byte of each UTF-8 sequence
+            if (*b as i8) >= -0x40 {
+                self.column_chars += 1;
+                self.column_utf16 += if 0xF0 <= *b { 2 } else { 1 };
+            }
+        }
+        self.offset += consumed.len();
+    }
+}
+
 /// Capture top-level errors in the middle of parsing so parsing can resume
 ///
 /// See [`Recoverable`] for adding error recovery tracking to your [`Stream`]
@@ -990,6 +1124,70 @@ impl Compare<AsciiCaseless<char>> for &str {
     }
 }
 
+#[cfg(feature = "unicode")]
+impl<'b> Compare<UnicodeCaseless<&'b str>> for &[u8] {
+    #[inline]
+    fn compare(&self, t: UnicodeCaseless<&'b str>) -> CompareResult {
+        crate::unicode::compare_folded(self, t.0, Folding::Full)
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl Compare<UnicodeCaseless<char>> for &[u8] {
+    #[inline(always)]
+    fn compare(&self, t: UnicodeCaseless<char>) -> CompareResult {
+        self.compare(UnicodeCaseless(&*t.0.encode_utf8(&mut [0; 4])))
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<'b> Compare<UnicodeCaseless<&'b str>> for &str {
+    #[inline(always)]
+    fn compare(&self, t: UnicodeCaseless<&'b str>) -> CompareResult {
+        self.as_bytes().compare(t)
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl Compare<UnicodeCaseless<char>> for &str {
+    #[inline(always)]
+    fn compare(&self, t: UnicodeCaseless<char>) -> CompareResult {
+        self.as_bytes().compare(t)
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<'b> Compare<SimpleCaseless<&'b str>> for &[u8] {
+    #[inline]
+    fn compare(&self, t: SimpleCaseless<&'b str>) -> CompareResult {
+        crate::unicode::compare_folded(self, t.0, Folding::Simple)
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl Compare<SimpleCaseless<char>> for &[u8] {
+    #[inline(always)]
+    fn compare(&self, t: SimpleCaseless<char>) -> CompareResult {
+        self.compare(SimpleCaseless(&*t.0.encode_utf8(&mut [0; 4])))
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<'b> Compare<SimpleCaseless<&'b str>> for &str {
+    #[inline(always)]
+    fn compare(&self, t: SimpleCaseless<&'b str>) -> CompareResult {
+        self.as_bytes().compare(t)
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl Compare<SimpleCaseless<char>> for &str {
+    #[inline(always)]
+    fn compare(&self, t: SimpleCaseless<char>) -> CompareResult {
+        self.as_bytes().compare(t)
+    }
+}
+
 /// Look for a slice in self
 pub trait FindSlice<T> {
     /// Returns the offset of the slice if it is found
@@ -1197,6 +1395,252 @@ impl FindSlice<(char, char, char)> for &str {
     }
 }
 
+#[cfg(feature = "unicode")]
+impl<'s> FindSlice<UnicodeCaseless<&'s str>> for &[u8] {
+    #[inline]
+    fn find_slice(
+        &self,
+        substr: UnicodeCaseless<&'s str>,
+    ) -> Option<crate::lib::std::ops::Range<usize>> {
+        crate::unicode::find_folded(self, substr.0, Folding::Full)
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl FindSlice<UnicodeCaseless<char>> for &[u8] {
+    #[inline(always)]
+    fn find_slice(
+        &self,
+        substr: UnicodeCaseless<char>,
+    ) -> Option<crate::lib::std::ops::Range<usize>> {
+        let mut b = [0; 4];
+        let substr = substr.0.encode_utf8(&mut b);
+        self.find_slice(UnicodeCaseless(&*substr))
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<'s> FindSlice<UnicodeCaseless<&'s str>> for &str {
+    #[inline(always)]
+    fn find_slice(
+        &self,
+        substr: UnicodeCaseless<&'s str>,
+    ) -> Option<crate::lib::std::ops::Range<usize>> {
+        self.as_bytes().find_slice(substr)
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl FindSlice<UnicodeCaseless<char>> for &str {
+    #[inline(always)]
+    fn find_slice(
+        &self,
+        substr: UnicodeCaseless<char>,
+    ) -> Option<crate::lib::std::ops::Range<usize>> {
+        self.as_bytes().find_slice(substr)
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<'s> FindSlice<SimpleCaseless<&'s str>> for &[u8] {
+    #[inline]
+    fn find_slice(
+        &self,
+        substr: SimpleCaseless<&'s str>,
+    ) -> Option<crate::lib::std::ops::Range<usize>> {
+        crate::unicode::find_folded(self, substr.0, Folding::Simple)
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl FindSlice<SimpleCaseless<char>> for &[u8] {
+    #[inline(always)]
+    fn find_slice(
+        &self,
+        substr: SimpleCaseless<char>,
+    ) -> Option<crate::lib::std::ops::Range<usize>> {
+        let mut b = [0; 4];
+        let substr = substr.0.encode_utf8(&mut b);
+        self.find_slice(SimpleCaseless(&*substr))
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<'s> FindSlice<SimpleCaseless<&'s str>> for &str {
+    #[inline(always)]
+    fn find_slice(
+        &self,
+        substr: SimpleCaseless<&'s str>,
+    ) -> Option<crate::lib::std::ops::Range<usize>> {
+        self.as_bytes().find_slice(substr)
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl FindSlice<SimpleCaseless<char>> for &str {
+    #[inline(always)]
+    fn find_slice(
+        &self,
+        substr: SimpleCaseless<char>,
+    ) -> Option<crate::lib::std::ops::Range<usize>> {
+        self.as_bytes().find_slice(substr)
+    }
+}
+
+/// Look for the first of several slices in self
+pub trait FindSliceAny<T> {
+    /// Returns the index of the first slice found in `substrs` and its offset
+    ///
+    /// When several slices are found at the same offset, the earliest in `substrs` is returned.
+    fn find_slice_any(&self, substrs: T) -> Option<(usize, crate::lib::std::ops::Range<usize>)>;
+
+    /// Returns the first offset where one of `substrs` is cut off by the end of self
+    ///
+    /// With [`Partial`] input, a slice found after this offset might not be the first one.
+    fn find_partial_any(&self, substrs: T) -> Option<usize>;
+}
+
+impl<'s, const N: usize> FindSliceAny<[&'s [u8]; N]> for &[u8] {
+    #[inline(always)]
+    fn find_slice_any(
+        &self,
+        substrs: [&'s [u8]; N],
+    ) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+        memmem_any(self, &substrs)
+    }
+
+    #[inline(always)]
+    fn find_partial_any(&self, substrs: [&'s [u8]; N]) -> Option<usize> {
+        partial_any(self, &substrs)
+    }
+}
+
+impl<'s> FindSliceAny<&'s [&'s [u8]]> for &[u8] {
+    #[inline(always)]
+    fn find_slice_any(
+        &self,
+        substrs: &'s [&'s [u8]],
+    ) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+        memmem_any(self, substrs)
+    }
+
+    #[inline(always)]
+    fn find_partial_any(&self, substrs: &'s [&'s [u8]]) -> Option<usize> {
+        partial_any(self, substrs)
+    }
+}
+
+impl<'s, const N: usize> FindSliceAny<[&'s str; N]> for &[u8] {
+    #[inline(always)]
+    fn find_slice_any(
+        &self,
+        substrs: [&'s str; N],
+    ) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+        memmem_any(self, &substrs)
+    }
+
+    #[inline(always)]
+    fn find_partial_any(&self, substrs: [&'s str; N]) -> Option<usize> {
+        partial_any(self, &substrs)
+    }
+}
+
+impl<'s> FindSliceAny<&'s [&'s str]> for &[u8] {
+    #[inline(always)]
+    fn find_slice_any(
+        &self,
+        substrs: &'s [&'s str],
+    ) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+        memmem_any(self, substrs)
+    }
+
+    #[inline(always)]
+    fn find_partial_any(&self, substrs: &'s [&'s str]) -> Option<usize> {
+        partial_any(self, substrs)
+    }
+}
+
+impl<const N: usize> FindSliceAny<[u8; N]> for &[u8] {
+    #[inline(always)]
+    fn find_slice_any(
+        &self,
+        substrs: [u8; N],
+    ) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+        let substrs: [&[u8]; N] = core::array::from_fn(|i| core::slice::from_ref(&substrs[i]));
+        memmem_any(self, &substrs)
+    }
+
+    #[inline(always)]
+    fn find_partial_any(&self, substrs: [u8; N]) -> Option<usize> {
+        let substrs: [&[u8]; N] = core::array::from_fn(|i| core::slice::from_ref(&substrs[i]));
+        partial_any(self, &substrs)
+    }
+}
+
+impl<const N: usize> FindSliceAny<[char; N]> for &[u8] {
+    #[inline(always)]
+    fn find_slice_any(
+        &self,
+        substrs: [char; N],
+    ) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+        let mut b = [[0; 4]; N];
+        let substrs = encode_chars(&substrs, &mut b);
+        memmem_any(self, &substrs)
+    }
+
+    #[inline(always)]
+    fn find_partial_any(&self, substrs: [char; N]) -> Option<usize> {
+        let mut b = [[0; 4]; N];
+        let substrs = encode_chars(&substrs, &mut b);
+        partial_any(self, &substrs)
+    }
+}
+
+impl<'s, const N: usize> FindSliceAny<[&'s str; N]> for &str {
+    #[inline(always)]
+    fn find_slice_any(
+        &self,
+        substrs: [&'s str; N],
+    ) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+        self.as_bytes().find_slice_any(substrs)
+    }
+
+    #[inline(always)]
+    fn find_partial_any(&self, substrs: [&'s str; N]) -> Option<usize> {
+        self.as_bytes().find_partial_any(substrs)
+    }
+}
+
+impl<'s> FindSliceAny<&'s [&'s str]> for &str {
+    #[inline(always)]
+    fn find_slice_any(
+        &self,
+        substrs: &'s [&'s str],
+    ) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+        self.as_bytes().find_slice_any(substrs)
+    }
+
+    #[inline(always)]
+    fn find_partial_any(&self, substrs: &'s [&'s str]) -> Option<usize> {
+        self.as_bytes().find_partial_any(substrs)
+    }
+}
+
+impl<const N: usize> FindSliceAny<[char; N]> for &str {
+    #[inline(always)]
+    fn find_slice_any(
+        &self,
+        substrs: [char; N],
+    ) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+        self.as_bytes().find_slice_any(substrs)
+    }
+
+    #[inline(always)]
+    fn find_partial_any(&self, substrs: [char; N]) -> Option<usize> {
+        self.as_bytes().find_partial_any(substrs)
+    }
+}
+
 /// Used to integrate `str`'s `parse()` method
 pub trait ParseSlice<R> {
     /// Succeeds if `parse()` succeeded
@@ -1386,6 +1830,64 @@ impl<'i> Accumulate<&'i str> for String {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<'i, T: Clone> Accumulate<T> for Cow<'i, [T]> {
+    #[inline(always)]
+    fn initial(_capacity: Option<usize>) -> Self {
+        Cow::Borrowed(&[])
+    }
+    #[inline(always)]
+    fn accumulate(&mut self, acc: T) {
+        self.to_mut().push(acc);
+    }
+}
+
+/// Borrows the first slice, only allocating once there is more than one
+#[cfg(feature = "alloc")]
+impl<'i, 'a: 'i, T: Clone> Accumulate<&'a [T]> for Cow<'i, [T]> {
+    #[inline(always)]
+    fn initial(_capacity: Option<usize>) -> Self {
+        Cow::Borrowed(&[])
+    }
+    #[inline(always)]
+    fn accumulate(&mut self, acc: &'a [T]) {
+        if self.is_empty() {
+            *self = Cow::Borrowed(acc);
+        } else {
+            self.to_mut().extend_from_slice(acc);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'i> Accumulate<char> for Cow<'i, str> {
+    #[inline(always)]
+    fn initial(_capacity: Option<usize>) -> Self {
+        Cow::Borrowed("")
+    }
+    #[inline(always)]
+    fn accumulate(&mut self, acc: char) {
+        self.to_mut().push(acc);
+    }
+}
+
+/// Borrows the first slice, only allocating once there is more than one
+#[cfg(feature = "alloc")]
+impl<'i, 'a: 'i> Accumulate<&'a str> for Cow<'i, str> {
+    #[inline(always)]
+    fn initial(_capacity: Option<usize>) -> Self {
+        Cow::Borrowed("")
+    }
+    #[inline(always)]
+    fn accumulate(&mut self, acc: &'a str) {
+        if self.is_empty() {
+            *self = Cow::Borrowed(acc);
+        } else {
+            self.to_mut().push_str(acc);
+        }
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl<K, V> Accumulate<(K, V)> for BTreeMap<K, V>
 where
@@ -1939,37 +2441,37 @@ impl_contains_token_for_tuples!(
 
 #[cfg(feature = "simd")]
 #[inline(always)]
-fn memchr(token: u8, slice: &[u8]) -> Option<usize> {
+pub(crate) fn memchr(token: u8, slice: &[u8]) -> Option<usize> {
     memchr::memchr(token, slice)
 }
 
 #[cfg(feature = "simd")]
 #[inline(always)]
-fn memchr2(token: (u8, u8), slice: &[u8]) -> Option<usize> {
+pub(crate) fn memchr2(token: (u8, u8), slice: &[u8]) -> Option<usize> {
     memchr::memchr2(token.0, token.1, slice)
 }
 
 #[cfg(feature = "simd")]
 #[inline(always)]
-fn memchr3(token: (u8, u8, u8), slice: &[u8]) -> Option<usize> {
+pub(crate) fn memchr3(token: (u8, u8, u8), slice: &[u8]) -> Option<usize> {
     memchr::memchr3(token.0, token.1, token.2, slice)
 }
 
 #[cfg(not(feature = "simd"))]
 #[inline(always)]
-fn memchr(token: u8, slice: &[u8]) -> Option<usize> {
+pub(crate) fn memchr(token: u8, slice: &[u8]) -> Option<usize> {
     slice.iter().position(|t| *t == token)
 }
 
 #[cfg(not(feature = "simd"))]
 #[inline(always)]
-fn memchr2(token: (u8, u8), slice: &[u8]) -> Option<usize> {
+pub(crate) fn memchr2(token: (u8, u8), slice: &[u8]) -> Option<usize> {
     slice.iter().position(|t| *t == token.0 || *t == token.1)
 }
 
 #[cfg(not(feature = "simd"))]
 #[inline(always)]
-fn memchr3(token: (u8, u8, u8), slice: &[u8]) -> Option<usize> {
+pub(crate) fn memchr3(token: (u8, u8, u8), slice: &[u8]) -> Option<usize> {
     slice
         .iter()
         .position(|t| *t == token.0 || *t == token.1 || *t == token.2)
@@ -2005,6 +2507,81 @@ fn memmem3(
     }
 }
 
+fn memmem_any<T: AsRef<[u8]>>(
+    slice: &[u8],
+    literals: &[T],
+) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+    let at = |i: usize| {
+        let subslice = &slice[i..];
+        literals
+            .iter()
+            .position(|literal| subslice.starts_with(literal.as_ref()))
+            .map(|n| (n, i..i + literals[n].as_ref().len()))
+    };
+
+    let mut prefixes = [false; 256];
+    let mut prefix = [0; 3];
+    let mut prefix_count = 0;
+    for literal in literals {
+        let Some(&first) = literal.as_ref().first() else {
+            return at(0);
+        };
+        if !prefixes[usize::from(first)] {
+            prefixes[usize::from(first)] = true;
+            if prefix_count < prefix.len() {
+                prefix[prefix_count] = first;
+            }
+            prefix_count += 1;
+        }
+    }
+
+    let mut start = 0;
+    while start < slice.len() {
+        let subslice = &slice[start..];
+        let offset = match prefix_count {
+            0 => None,
+            1 => memchr(prefix[0], subslice),
+            2 => memchr2((prefix[0], prefix[1]), subslice),
+            3 => memchr3((prefix[0], prefix[1], prefix[2]), subslice),
+            _ => subslice.iter().position(|b| prefixes[usize::from(*b)]),
+        }?;
+        let i = start + offset;
+        if let Some(found) = at(i) {
+            return Some(found);
+        }
+        start = i + 1;
+    }
+    None
+}
+
+fn partial_any<T: AsRef<[u8]>>(slice: &[u8], literals: &[T]) -> Option<usize> {
+    let longest = literals
+        .iter()
+        .map(|literal| literal.as_ref().len())
+        .max()
+        .unwrap_or(0);
+    let start = slice.len().saturating_sub(longest.saturating_sub(1));
+    (start..slice.len()).find(|&i| {
+        let tail = &slice[i..];
+        literals.iter().any(|literal| {
+            let literal = literal.as_ref();
+            tail.len() < literal.len() && literal.starts_with(tail)
+        })
+    })
+}
+
+fn encode_chars<'b, const N: usize>(
+    chars: &[char; N],
+    buffers: &'b mut [[u8; 4]; N],
+) -> [&'b [u8]; N] {
+    let mut lens = [0; N];
+    for (i, c) in chars.iter().enumerate() {
+        lens[i] = c.encode_utf8(&mut buffers[i]).len();
+    }
+    let buffers = &*buffers;
+    core::array::from_fn(|i| &buffers[i][..lens[i]])
+}
+
 #[cfg(feature = "simd")]
 #[inline(always)]
 fn memmem_(slice: &[u8], literal: &[u8]) -> Option<crate::lib::std::ops::Range<usize>> {
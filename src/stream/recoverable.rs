@@ -6,8 +6,13 @@ use crate::stream::Checkpoint;
 use crate::stream::Compare;
 use crate::stream::CompareResult;
 use crate::stream::FindSlice;
+use crate::stream::FindSliceAny;
+use crate::stream::LineLocation;
 use crate::stream::Location;
+use crate::stream::Memo;
+use crate::stream::MemoCache;
 use crate::stream::Offset;
+use crate::stream::Position;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 use crate::stream::Recover;
@@ -193,6 +198,36 @@ where
     }
 }
 
+impl<I, E> LineLocation for Recoverable<I, E>
+where
+    I: LineLocation,
+    I: Stream,
+{
+    #[inline(always)]
+    fn previous_token_end_position(&self) -> Position {
+        self.input.previous_token_end_position()
+    }
+    #[inline(always)]
+    fn current_token_start_position(&self) -> Position {
+        self.input.current_token_start_position()
+    }
+}
+
+impl<I, E> Memo for Recoverable<I, E>
+where
+    I: Memo,
+    E: crate::lib::std::fmt::Debug,
+{
+    #[inline(always)]
+    fn memo_offset(&self) -> usize {
+        self.input.memo_offset()
+    }
+    #[inline(always)]
+    fn memo_cache(&self) -> &MemoCache {
+        self.input.memo_cache()
+    }
+}
+
 impl<I, E, R> Recover<E> for Recoverable<I, R>
 where
     I: Stream,
@@ -320,6 +355,22 @@ where
     }
 }
 
+impl<I, E, T> FindSliceAny<T> for Recoverable<I, E>
+where
+    I: Stream,
+    I: FindSliceAny<T>,
+{
+    #[inline(always)]
+    fn find_slice_any(&self, substrs: T) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+        self.input.find_slice_any(substrs)
+    }
+
+    #[inline(always)]
+    fn find_partial_any(&self, substrs: T) -> Option<usize> {
+        self.input.find_partial_any(substrs)
+    }
+}
+
 impl<I, E> UpdateSlice for Recoverable<I, E>
 where
     I: Stream,
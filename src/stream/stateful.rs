@@ -5,8 +5,15 @@ use crate::stream::Checkpoint;
 use crate::stream::Compare;
 use crate::stream::CompareResult;
 use crate::stream::FindSlice;
+use crate::stream::FindSliceAny;
+use crate::stream::LineLocation;
 use crate::stream::Location;
+#[cfg(feature = "alloc")]
+use crate::stream::Memo;
+#[cfg(feature = "alloc")]
+use crate::stream::MemoCache;
 use crate::stream::Offset;
+use crate::stream::Position;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 use crate::stream::Recover;
@@ -170,6 +177,36 @@ where
     }
 }
 
+impl<I, S> LineLocation for Stateful<I, S>
+where
+    I: LineLocation,
+{
+    #[inline(always)]
+    fn previous_token_end_position(&self) -> Position {
+        self.input.previous_token_end_position()
+    }
+    #[inline(always)]
+    fn current_token_start_position(&self) -> Position {
+        self.input.current_token_start_position()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, S> Memo for Stateful<I, S>
+where
+    I: Memo,
+    S: crate::lib::std::fmt::Debug,
+{
+    #[inline(always)]
+    fn memo_offset(&self) -> usize {
+        self.input.memo_offset()
+    }
+    #[inline(always)]
+    fn memo_cache(&self) -> &MemoCache {
+        self.input.memo_cache()
+    }
+}
+
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 impl<I, E, S> Recover<E> for Stateful<I, S>
@@ -284,6 +321,21 @@ where
     }
 }
 
+impl<I, S, T> FindSliceAny<T> for Stateful<I, S>
+where
+    I: FindSliceAny<T>,
+{
+    #[inline(always)]
+    fn find_slice_any(&self, substrs: T) -> Option<(usize, crate::lib::std::ops::Range<usize>)> {
+        self.input.find_slice_any(substrs)
+    }
+
+    #[inline(always)]
+    fn find_partial_any(&self, substrs: T) -> Option<usize> {
+        self.input.find_partial_any(substrs)
+    }
+}
+
 impl<I, S> UpdateSlice for Stateful<I, S>
 where
     I: UpdateSlice,
@@ -273,3 +273,92 @@ fn tokenslice_location() {
 
     assert_eq!(input.current_token_start(), 29);
 }
+
+#[test]
+fn line_locating_slice_position() {
+    let mut input = LineLocatingSlice::new("ab\nçd𝄞e\r\n\nf");
+    assert_eq!(input.position(), Position::default());
+
+    let _ = input.next_slice(2);
+    let position = input.position();
+    assert_eq!(
+        (position.line(), position.column(), position.column_chars()),
+        (0, 2, 2)
+    );
+
+    let _ = input.next_token();
+    let position = input.position();
+    assert_eq!(
+        (position.offset(), position.line(), position.line_start()),
+        (3, 1, 3)
+    );
+    assert_eq!(position.column(), 0);
+
+    let _ = input.next_token();
+    let _ = input.next_token();
+    let _ = input.next_token();
+    let position = input.position();
+    assert_eq!(position.column(), 7);
+    assert_eq!(position.column_chars(), 3);
+    assert_eq!(position.column_utf16(), 4);
+
+    let checkpoint = input.checkpoint();
+    let _ = input.finish();
+    let position = input.position();
+    assert_eq!((position.line(), position.column()), (3, 1));
+    assert_eq!(input.offset_from(&checkpoint), 5);
+
+    input.reset(&checkpoint);
+    assert_eq!(input.position().column_utf16(), 4);
+    assert_eq!(input.current_token_start(), 10);
+}
+
+#[test]
+fn line_locating_slice_bytes() {
+    let mut input = Partial::new(LineLocatingSlice::new(&b"a\xCF\x80\n\xCF\x80b"[..]));
+    let _ = input.next_slice(3);
+    let position = input.current_token_start_position();
+    assert_eq!((position.column(), position.column_chars()), (3, 2));
+    let _ = input.next_slice(4);
+    let position = input.previous_token_end_position();
+    assert_eq!(
+        (position.line(), position.column(), position.column_chars()),
+        (1, 3, 2)
+    );
+}
+
+#[test]
+#[cfg(feature = "bytes")]
+fn bytes_crate_zero_copy() {
+    use ::bytes::Bytes as SharedBytes;
+
+    use crate::ascii::dec_uint;
+    use crate::token::take_until;
+
+    fn pair(input: &mut SharedBytes) -> ModalResult<(SharedBytes, u32)> {
+        separated_pair(take_until(1.., "="), "=", dec_uint).parse_next(input)
+    }
+
+    let buffer = SharedBytes::from_static(b"key=42;rest");
+    let mut input = buffer.clone();
+    let (key, value) = pair.parse_next(&mut input).unwrap();
+    assert_eq!(&key[..], b"key");
+    assert_eq!(key.as_ptr(), buffer.as_ptr());
+    assert_eq!(value, 42);
+    assert_eq!(&input[..], b";rest");
+    assert_eq!(input.offset_from(&buffer), 6);
+
+    let mut input = buffer.clone();
+    let checkpoint = input.checkpoint();
+    assert_eq!(input.next_token(), Some(b'k'));
+    input.reset(&checkpoint);
+    assert_eq!(&input.peek_slice(3)[..], b"key");
+
+    let mut input = Partial::new(SharedBytes::from_static(b"key"));
+    assert_eq!(
+        take_until::<_, _, ErrMode<InputError<_>>>(1.., "=")
+            .parse_next(&mut input)
+            .unwrap_err(),
+        ErrMode::Incomplete(Needed::Unknown)
+    );
+}
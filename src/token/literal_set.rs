@@ -0,0 +1,185 @@
+use crate::lib::std::ops::Range;
+use crate::lib::std::vec::Vec;
+use crate::stream::FindSlice;
+use crate::stream::{memchr, memchr2, memchr3};
+
+/// A set of literals, each mapped to a value, for matching in a single pass
+///
+/// The literals are stored in a byte-wise trie, so matching walks the input once rather than
+/// trying each literal in turn.
+///
+/// Used with [`one_of_literals`][crate::token::one_of_literals] to parse the longest matching
+/// literal or with [`take_until`][crate::token::take_until] to find the first one.
+///
+/// Literals are bytes but, on a `&str`, only match on `char` boundaries.
+///
+/// # Example
+///
+/// ```rust
+/// use winnow::token::LiteralSet;
+///
+/// // Each literal is its own value
+/// let keywords = LiteralSet::new(["select", "insert", "in"]);
+/// assert_eq!(keywords.len(), 3);
+///
+/// // Literals mapped to other values
+/// #[derive(Copy, Clone, Debug, PartialEq)]
+/// enum Op { Lt, Le, Shl }
+/// let ops: LiteralSet<Op> = [("<", Op::Lt), ("<=", Op::Le), ("<<", Op::Shl)].into_iter().collect();
+/// assert_eq!(ops.len(), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct LiteralSet<O> {
+    nodes: Vec<Node>,
+    values: Vec<O>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Node {
+    /// Sorted by byte
+    edges: Vec<(u8, usize)>,
+    value: Option<usize>,
+}
+
+impl<K: AsRef<[u8]> + Clone> LiteralSet<K> {
+    /// Create a set where each literal is mapped to itself
+    pub fn new(literals: impl IntoIterator<Item = K>) -> Self {
+        literals.into_iter().map(|k| (k.clone(), k)).collect()
+    }
+}
+
+impl<O> LiteralSet<O> {
+    /// Add `literal`, mapped to `value`
+    ///
+    /// If `literal` is already in the set, its value is replaced.
+    pub fn insert(&mut self, literal: impl AsRef<[u8]>, value: O) {
+        let mut node = 0;
+        for &byte in literal.as_ref() {
+            let edges = &self.nodes[node].edges;
+            node = match edges.binary_search_by_key(&byte, |(b, _)| *b) {
+                Ok(i) => edges[i].1,
+                Err(i) => {
+                    let next = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[node].edges.insert(i, (byte, next));
+                    next
+                }
+            };
+        }
+        match self.nodes[node].value {
+            Some(i) => self.values[i] = value,
+            None => {
+                self.nodes[node].value = Some(self.values.len());
+                self.values.push(value);
+            }
+        }
+    }
+
+    /// The number of literals in the set
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether the set has no literals
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Find the longest literal at the start of `input`, ending where `is_boundary`
+    ///
+    /// Returns the length and value of the match, if any, and whether `input` ran out before a
+    /// longer literal could be ruled out.
+    pub(crate) fn longest_match(
+        &self,
+        input: &[u8],
+        mut is_boundary: impl FnMut(usize) -> bool,
+    ) -> (Option<(usize, &O)>, bool) {
+        let mut node = &self.nodes[0];
+        let mut found = node.value.map(|v| (0, v));
+        for (i, byte) in input.iter().enumerate() {
+            match node.edges.binary_search_by_key(byte, |(b, _)| *b) {
+                Ok(edge) => node = &self.nodes[node.edges[edge].1],
+                Err(_) => return (found.map(|(len, v)| (len, &self.values[v])), false),
+            }
+            if let Some(v) = node.value {
+                if is_boundary(i + 1) {
+                    found = Some((i + 1, v));
+                }
+            }
+        }
+        (
+            found.map(|(len, v)| (len, &self.values[v])),
+            !node.edges.is_empty(),
+        )
+    }
+
+    fn find(&self, input: &[u8], is_boundary: impl Fn(usize) -> bool) -> Option<Range<usize>> {
+        let root = &self.nodes[0];
+        if root.value.is_some() {
+            return Some(0..0);
+        }
+        let mut start = 0;
+        while start < input.len() {
+            let haystack = &input[start..];
+            let offset = match root.edges.as_slice() {
+                [(a, _)] => memchr(*a, haystack),
+                [(a, _), (b, _)] => memchr2((*a, *b), haystack),
+                [(a, _), (b, _), (c, _)] => memchr3((*a, *b, *c), haystack),
+                edges => haystack
+                    .iter()
+                    .position(|byte| edges.binary_search_by_key(byte, |(b, _)| *b).is_ok()),
+            }?;
+            let at = start + offset;
+            if is_boundary(at) {
+                if let (Some((len, _)), _) =
+                    self.longest_match(&input[at..], |len| is_boundary(at + len))
+                {
+                    return Some(at..at + len);
+                }
+            }
+            start = at + 1;
+        }
+        None
+    }
+}
+
+impl<O> Default for LiteralSet<O> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::from([Node::default()]),
+            values: Vec::new(),
+        }
+    }
+}
+
+impl<K: AsRef<[u8]>, O> FromIterator<(K, O)> for LiteralSet<O> {
+    fn from_iter<T: IntoIterator<Item = (K, O)>>(iter: T) -> Self {
+        let mut set = Self::default();
+        for (literal, value) in iter {
+            set.insert(literal, value);
+        }
+        set
+    }
+}
+
+impl<K: AsRef<[u8]>, O> Extend<(K, O)> for LiteralSet<O> {
+    fn extend<T: IntoIterator<Item = (K, O)>>(&mut self, iter: T) {
+        for (literal, value) in iter {
+            self.insert(literal, value);
+        }
+    }
+}
+
+impl<'s, O> FindSlice<&'s LiteralSet<O>> for &[u8] {
+    #[inline(always)]
+    fn find_slice(&self, substr: &'s LiteralSet<O>) -> Option<Range<usize>> {
+        substr.find(self, |_| true)
+    }
+}
+
+impl<'s, O> FindSlice<&'s LiteralSet<O>> for &str {
+    #[inline(always)]
+    fn find_slice(&self, substr: &'s LiteralSet<O>) -> Option<Range<usize>> {
+        substr.find(self.as_bytes(), |offset| self.is_char_boundary(offset))
+    }
+}
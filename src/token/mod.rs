@@ -1,15 +1,28 @@
 //! Parsers extracting tokens from the stream
 
+#[cfg(feature = "alloc")]
+mod literal_set;
+#[cfg(feature = "regex")]
+mod regex;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "regex")]
+pub use self::regex::{regex, regex_captures, Regex};
+#[cfg(feature = "alloc")]
+pub use literal_set::LiteralSet;
+
 use crate::combinator::trace;
 use crate::combinator::DisplayDebug;
 use crate::error::Needed;
 use crate::error::ParserError;
+#[cfg(feature = "alloc")]
+use crate::lib::std::borrow::Borrow;
 use crate::lib::std::result::Result::Ok;
+#[cfg(feature = "alloc")]
+use crate::stream::AsBStr;
 use crate::stream::Range;
-use crate::stream::{Compare, CompareResult, ContainsToken, FindSlice, Stream};
+use crate::stream::{Compare, CompareResult, ContainsToken, FindSlice, FindSliceAny, Stream};
 use crate::stream::{StreamIsPartial, ToUsize};
 use crate::Parser;
 use crate::Result;
@@ -193,6 +206,127 @@ where
     }
 }
 
+/// Recognizes the longest literal in a [`LiteralSet`], returning its value
+///
+/// Unlike trying each [`literal`] in turn with [`alt`][crate::combinator::alt], this walks the input
+/// once, no matter how many literals there are, and a literal can't shadow a longer one
+/// (like `"in"` shadowing `"insert"`).
+///
+/// Pass the set by reference to build it once rather than on every call.
+///
+/// *Complete version*: Will return an error if no literal matches.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if the
+/// input ends before a longer literal can be ruled out.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::error::ContextError;
+/// # use winnow::token::LiteralSet;
+/// # use std::borrow::Borrow;
+/// pub fn one_of_literals<'i, O: Clone>(literals: impl Borrow<LiteralSet<O>>) -> impl Parser<&'i str, O, ContextError>
+/// # {
+/// #     winnow::token::one_of_literals(literals)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::error::ContextError;
+/// use winnow::token::LiteralSet;
+/// use winnow::token::one_of_literals;
+///
+/// #[derive(Copy, Clone, Debug, PartialEq)]
+/// enum Keyword { In, Insert, Into, Select }
+///
+/// let keywords: LiteralSet<Keyword> = [
+///     ("in", Keyword::In),
+///     ("insert", Keyword::Insert),
+///     ("into", Keyword::Into),
+///     ("select", Keyword::Select),
+/// ]
+/// .into_iter()
+/// .collect();
+/// let mut keyword = one_of_literals::<_, _, _, ContextError>(&keywords);
+///
+/// assert_eq!(keyword.parse_peek("insert x"), Ok((" x", Keyword::Insert)));
+/// assert_eq!(keyword.parse_peek("in x"), Ok((" x", Keyword::In)));
+/// assert_eq!(keyword.parse_peek("inse"), Ok(("se", Keyword::In)));
+/// assert!(keyword.parse_peek("update").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::Partial;
+/// use winnow::token::LiteralSet;
+/// use winnow::token::one_of_literals;
+///
+/// fn op<'i>(input: &mut Partial<&'i str>) -> ModalResult<&'static str> {
+///     one_of_literals(LiteralSet::new(["<", "<=", "<<", "<<="])).parse_next(input)
+/// }
+///
+/// assert_eq!(op.parse_peek(Partial::new("<= 1")), Ok((Partial::new(" 1"), "<=")));
+/// assert_eq!(op.parse_peek(Partial::new("< 1")), Ok((Partial::new(" 1"), "<")));
+/// assert_eq!(op.parse_peek(Partial::new("<<")), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// assert!(op.parse_peek(Partial::new("> 1")).is_err());
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+#[doc(alias = "keyword")]
+#[doc(alias = "trie")]
+pub fn one_of_literals<Input, Literals, Output, Error>(
+    literals: Literals,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: StreamIsPartial + Stream + AsBStr,
+    Literals: Borrow<LiteralSet<Output>>,
+    Output: Clone,
+    Error: ParserError<Input>,
+{
+    trace("one_of_literals", move |i: &mut Input| {
+        let literals = literals.borrow();
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            one_of_literals_::<_, _, _, true>(i, literals)
+        } else {
+            one_of_literals_::<_, _, _, false>(i, literals)
+        }
+    })
+}
+
+#[cfg(feature = "alloc")]
+fn one_of_literals_<I, O, Error: ParserError<I>, const PARTIAL: bool>(
+    i: &mut I,
+    literals: &LiteralSet<O>,
+) -> Result<O, Error>
+where
+    I: StreamIsPartial,
+    I: Stream + AsBStr,
+    O: Clone,
+{
+    // Literals are bytes, so don't stop within a token, like a `char` of a `&str`
+    let eof = i.eof_offset();
+    let mut offsets = i.iter_offsets().map(|(offset, _)| offset).peekable();
+    let is_boundary = |len: usize| {
+        while offsets.next_if(|offset| *offset < len).is_some() {}
+        len == eof || offsets.peek() == Some(&len)
+    };
+    match literals.longest_match(i.as_bstr(), is_boundary) {
+        (_, true) if PARTIAL && i.is_partial() => Err(ParserError::incomplete(i, Needed::Unknown)),
+        (Some((len, value)), _) => {
+            let value = value.clone();
+            let _ = i.next_slice(len);
+            Ok(value)
+        }
+        (None, _) => Err(ParserError::from_input(i)),
+    }
+}
+
 /// Recognize a token that matches a [set of tokens][ContainsToken]
 ///
 /// <div class="warning">
@@ -1021,6 +1155,141 @@ where
     }
 }
 
+/// Recognize the input slice up to the first occurrence of any of several literals, returning
+/// the slice and the index of the literal that was found
+///
+/// Like [`take_until`], the literal is not consumed. When several literals are found at the
+/// same place, the earliest in `literals` wins.
+///
+/// `literals` is an array or slice of literals, see [`FindSliceAny`][crate::stream::FindSliceAny].
+///
+/// *Complete version*: It will return `Err(ErrMode::Backtrack(_))` if none of the literals are found.
+///
+/// *[Partial version][crate::_topic::partial]*: will return a `ErrMode::Incomplete(Needed::Unknown)` if none of the literals are found,
+/// or if one could still be found earlier than the one found once more input is available.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream] with `0..` or `1..` [ranges][Range]:
+/// ```rust
+/// # use std::ops::RangeFrom;
+/// # use winnow::prelude::*;;
+/// # use winnow::error::ContextError;
+/// pub fn take_until_any<'i, const N: usize>(occurrences: RangeFrom<usize>, literals: [&'static str; N]) -> impl Parser<&'i str, (&'i str, usize), ContextError>
+/// # {
+/// #     winnow::token::take_until_any(occurrences, literals)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::token::take_until_any;
+///
+/// fn text<'i>(s: &mut &'i str) -> ModalResult<(&'i str, usize)> {
+///   take_until_any(0.., ["{{", "{%", "{#"]).parse_next(s)
+/// }
+///
+/// assert_eq!(text.parse_peek("Hello {{ name }}"), Ok(("{{ name }}", ("Hello ", 0))));
+/// assert_eq!(text.parse_peek("{% if x %}"), Ok(("{% if x %}", ("", 1))));
+/// assert_eq!(text.parse_peek("a { b {# c #}"), Ok(("{# c #}", ("a { b ", 2))));
+/// assert!(text.parse_peek("Hello").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::token::take_until_any;
+///
+/// fn until_end<'i>(s: &mut Partial<&'i str>) -> ModalResult<(&'i str, usize)> {
+///   take_until_any(0.., ["-->", "-"]).parse_next(s)
+/// }
+///
+/// assert_eq!(until_end.parse_peek(Partial::new("a --> b")), Ok((Partial::new("--> b"), ("a ", 0))));
+/// assert_eq!(until_end.parse_peek(Partial::new("a -- b")), Ok((Partial::new("-- b"), ("a ", 1))));
+/// // Either could be found once more input is available
+/// assert_eq!(until_end.parse_peek(Partial::new("a --")), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// assert_eq!(until_end.parse_peek(Partial::new("a")), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// ```
+#[inline(always)]
+#[doc(alias = "take_until_one_of")]
+pub fn take_until_any<Literals, Input, Error>(
+    occurrences: impl Into<Range>,
+    literals: Literals,
+) -> impl Parser<Input, (<Input as Stream>::Slice, usize), Error>
+where
+    Input: StreamIsPartial + Stream + FindSliceAny<Literals>,
+    Literals: Clone,
+    Error: ParserError<Input>,
+{
+    let Range {
+        start_inclusive,
+        end_inclusive,
+    } = occurrences.into();
+    trace("take_until_any", move |i: &mut Input| {
+        let end = end_inclusive.unwrap_or(usize::MAX);
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            take_until_any_::<_, _, _, true>(i, start_inclusive, end, literals.clone())
+        } else {
+            take_until_any_::<_, _, _, false>(i, start_inclusive, end, literals.clone())
+        }
+    })
+}
+
+fn take_until_any_<T, I, Error: ParserError<I>, const PARTIAL: bool>(
+    i: &mut I,
+    start: usize,
+    end: usize,
+    t: T,
+) -> Result<(<I as Stream>::Slice, usize), Error>
+where
+    I: StreamIsPartial,
+    I: Stream + FindSliceAny<T>,
+    T: Clone,
+{
+    if end < start {
+        return Err(ParserError::assert(
+            i,
+            "`occurrences` should be ascending, rather than descending",
+        ));
+    }
+
+    let found = i.find_slice_any(t.clone());
+    if PARTIAL && i.is_partial() {
+        if let Some(cut) = i.find_partial_any(t) {
+            if found
+                .as_ref()
+                .map(|(_, range)| cut <= range.start)
+                .unwrap_or(true)
+            {
+                return Err(ParserError::incomplete(i, Needed::Unknown));
+            }
+        }
+    }
+    match found {
+        Some((index, range)) => {
+            let start_offset = i.offset_at(start);
+            let end_offset = i.offset_at(end).unwrap_or_else(|_err| i.eof_offset());
+            if start_offset.map(|s| range.start < s).unwrap_or(true) {
+                if PARTIAL && i.is_partial() {
+                    return Err(ParserError::incomplete(i, Needed::Unknown));
+                } else {
+                    return Err(ParserError::from_input(i));
+                }
+            }
+            if end_offset < range.start {
+                return Err(ParserError::from_input(i));
+            }
+            Ok((i.next_slice(range.start), index))
+        }
+        None if PARTIAL && i.is_partial() => Err(ParserError::incomplete(i, Needed::Unknown)),
+        None => Err(ParserError::from_input(i)),
+    }
+}
+
 /// Return the remaining input.
 ///
 /// # Effective Signature
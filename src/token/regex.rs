@@ -0,0 +1,347 @@
+use regex_automata::hybrid::dfa::{Cache, DFA};
+use regex_automata::hybrid::LazyStateID;
+use regex_automata::meta;
+use regex_automata::nfa::thompson;
+use regex_automata::util::captures::Captures;
+use regex_automata::util::syntax;
+use regex_automata::{Anchored, Input};
+
+use crate::combinator::trace;
+use crate::error::Needed;
+use crate::error::ParserError;
+use crate::lib::std::borrow::Borrow;
+use crate::stream::AsBStr;
+use crate::stream::Stream;
+use crate::stream::StreamIsPartial;
+use crate::Parser;
+use crate::Result;
+
+/// A regular expression for [`regex`] and [`regex_captures`]
+///
+/// The pattern is compiled once into an anchored lazy DFA, to tell where a match ends without
+/// scanning past it and whether more input could change it, and into a
+/// [`meta::Regex`][regex_automata::meta::Regex] for capture groups.  Both share the same
+/// [`syntax::Config`][regex_automata::util::syntax::Config], so flags can be set inline, like
+/// `(?i)`, or through [`Regex::with_syntax`].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::error::ContextError;
+/// use regex_automata::util::syntax;
+/// use winnow::token::regex;
+/// use winnow::token::Regex;
+///
+/// let keyword = Regex::with_syntax(r"\Aselect", syntax::Config::new().case_insensitive(true)).unwrap();
+/// let mut parser = regex::<_, _, ContextError>(&keyword);
+///
+/// assert_eq!(parser.parse_peek("SELECT *"), Ok((" *", "SELECT")));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Regex {
+    meta: meta::Regex,
+    /// `None` if the pattern can't be compiled to a lazy DFA
+    dfa: Option<DFA>,
+    /// Whether matches are always valid UTF-8
+    utf8: bool,
+}
+
+impl Regex {
+    /// Compile `pattern` with the default syntax
+    pub fn new(pattern: &str) -> Result<Self, meta::BuildError> {
+        Self::with_syntax(pattern, syntax::Config::new())
+    }
+
+    /// Compile `pattern` with the flags, like case insensitivity, of `syntax`
+    ///
+    /// Turning off [`syntax::Config::utf8`][regex_automata::util::syntax::Config::utf8] allows
+    /// matching invalid UTF-8 in byte streams.  On a `&str`, such a regex only matches whole
+    /// `char`s.
+    pub fn with_syntax(pattern: &str, syntax: syntax::Config) -> Result<Self, meta::BuildError> {
+        let utf8 = syntax.get_utf8();
+        let meta = meta::Regex::builder()
+            .configure(meta::Config::new().utf8_empty(utf8))
+            .syntax(syntax)
+            .build(pattern)?;
+        let dfa = DFA::builder()
+            .configure(DFA::config().unicode_word_boundary(true))
+            .syntax(syntax)
+            .thompson(thompson::Config::new().utf8(utf8))
+            .build(pattern)
+            .ok();
+        Ok(Self { meta, dfa, utf8 })
+    }
+}
+
+/// Recognizes the input slice matched by a regular expression, starting at the current position
+///
+/// The match must start at the current position, whether or not the pattern is anchored with `\A`.
+///
+/// This works with `&str`, `&[u8]` and `&BStr` streams.
+/// Pass the [`Regex`] by reference to compile it once rather than on every call.
+///
+/// *Complete version*: Will return an error if the regex doesn't match.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if
+/// more input could change the result, whether by extending a match, like `"abc;"` for
+/// `[a-z]+(;[0-9]+)?`, or by completing one, like `"0x"` for `0x[0-9a-f]+`.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::error::ContextError;
+/// # use winnow::token::Regex;
+/// # use std::borrow::Borrow;
+/// pub fn regex<'i>(re: impl Borrow<Regex>) -> impl Parser<&'i str, &'i str, ContextError>
+/// # {
+/// #     winnow::token::regex(re)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::error::ContextError;
+/// use winnow::token::regex;
+/// use winnow::token::Regex;
+///
+/// let number = Regex::new(r"\A[+-]?[0-9][0-9_]*(\.[0-9_]+)?").unwrap();
+/// let mut parser = regex::<_, _, ContextError>(&number);
+///
+/// assert_eq!(parser.parse_peek("1_000.5 apples"), Ok((" apples", "1_000.5")));
+/// assert_eq!(parser.parse_peek("-7"), Ok(("", "-7")));
+/// assert!(parser.parse_peek("apples").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::Partial;
+/// use winnow::token::regex;
+/// use winnow::token::Regex;
+///
+/// let number = Regex::new(r"\A[0-9]+").unwrap();
+/// let mut parser = regex::<_, _, ErrMode<ContextError>>(&number);
+///
+/// assert_eq!(parser.parse_peek(Partial::new(&b"123;"[..])), Ok((Partial::new(&b";"[..]), &b"123"[..])));
+/// assert_eq!(parser.parse_peek(Partial::new(&b"123"[..])), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// assert_eq!(parser.parse_peek(Partial::new(&b""[..])), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// assert!(parser.parse_peek(Partial::new(&b";"[..])).is_err());
+/// ```
+#[inline(always)]
+pub fn regex<Input, Re, Error>(re: Re) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Slice: AsBStr,
+    Re: Borrow<Regex>,
+    Error: ParserError<Input>,
+{
+    let mut cache = None;
+    trace("regex", move |input: &mut Input| {
+        let len = find(re.borrow(), &mut cache, input)?;
+        Ok(input.next_slice(len))
+    })
+}
+
+/// Recognizes the input slice matched by a regular expression, starting at the current position,
+/// along with its capture groups
+///
+/// Capture group offsets are relative to the start of the match.
+/// See [`regex`][crate::token::regex] for more details.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::error::ContextError;
+/// # use winnow::token::Regex;
+/// # use regex_automata::util::captures::Captures;
+/// # use std::borrow::Borrow;
+/// pub fn regex_captures<'i>(re: impl Borrow<Regex>) -> impl Parser<&'i str, (&'i str, Captures), ContextError>
+/// # {
+/// #     winnow::token::regex_captures(re)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::error::ContextError;
+/// use winnow::token::regex_captures;
+/// use winnow::token::Regex;
+///
+/// let version = Regex::new(r"\Av(?<major>[0-9]+)\.(?<minor>[0-9]+)").unwrap();
+/// let mut parser = regex_captures::<&str, _, ContextError>(&version);
+///
+/// let (rest, (matched, caps)) = parser.parse_peek("v1.65 is the MSRV").unwrap();
+/// assert_eq!(rest, " is the MSRV");
+/// assert_eq!(matched, "v1.65");
+/// let group = |name| caps.get_group_by_name(name).map(|span| &matched[span]);
+/// assert_eq!((group("major"), group("minor")), (Some("1"), Some("65")));
+/// assert!(parser.parse_peek("1.65").is_err());
+/// ```
+#[inline(always)]
+pub fn regex_captures<Input, Re, Error>(
+    re: Re,
+) -> impl Parser<Input, (<Input as Stream>::Slice, Captures), Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Slice: AsBStr,
+    Re: Borrow<Regex>,
+    Error: ParserError<Input>,
+{
+    let mut cache = None;
+    trace("regex_captures", move |input: &mut Input| {
+        let re = re.borrow();
+        let len = find(re, &mut cache, input)?;
+        // Searching what follows too, for look-around like `$`, stops at the same match
+        let haystack = input.peek_slice(input.eof_offset());
+        let mut captures = re.meta.create_captures();
+        re.meta.search_captures(
+            &regex_automata::Input::new(haystack.as_bstr()).anchored(Anchored::Yes),
+            &mut captures,
+        );
+        if !captures.is_match() {
+            return Err(ParserError::from_input(input));
+        }
+        Ok((input.next_slice(len), captures))
+    })
+}
+
+/// The length of the match starting at the current position
+fn find<I, Error>(re: &Regex, cache: &mut Option<Cache>, input: &I) -> Result<usize, Error>
+where
+    I: StreamIsPartial + Stream,
+    <I as Stream>::Slice: AsBStr,
+    Error: ParserError<I>,
+{
+    let partial = <I as StreamIsPartial>::is_partial_supported() && input.is_partial();
+    let haystack = input.peek_slice(input.eof_offset());
+    let haystack = haystack.as_bstr();
+    let search = re.dfa.as_ref().and_then(|dfa| {
+        let cache = cache.get_or_insert_with(|| dfa.create_cache());
+        search(dfa, cache, haystack, partial)
+    });
+    let len = match search {
+        Some(Search::Match(len)) => len,
+        Some(Search::NoMatch) => return Err(ParserError::from_input(input)),
+        Some(Search::Incomplete) => return Err(ParserError::incomplete(input, Needed::Unknown)),
+        None => {
+            // The DFA gave up, so fall back to a search that can't tell whether more input would
+            // change the result, other than a match reaching the end of the input
+            let Some(m) = re
+                .meta
+                .search(&Input::new(haystack).anchored(Anchored::Yes))
+            else {
+                return Err(ParserError::from_input(input));
+            };
+            if partial && m.end() == haystack.len() {
+                return Err(ParserError::incomplete(input, Needed::Unknown));
+            }
+            m.end()
+        }
+    };
+    if !re.utf8 && !is_token_boundary(input, len) {
+        return Err(ParserError::from_input(input));
+    }
+    Ok(len)
+}
+
+/// Whether `len` bytes of `input` end on a token, like a `char` of a `&str`
+fn is_token_boundary<I: Stream>(input: &I, len: usize) -> bool {
+    len == input.eof_offset()
+        || input
+            .iter_offsets()
+            .map(|(offset, _)| offset)
+            .find(|offset| len <= *offset)
+            == Some(len)
+}
+
+enum Search {
+    Match(usize),
+    NoMatch,
+    Incomplete,
+}
+
+/// Run the anchored `dfa` over `haystack`, or `None` if it gave up and the regex must be searched
+/// instead
+fn search(dfa: &DFA, cache: &mut Cache, haystack: &[u8], partial: bool) -> Option<Search> {
+    let search = Input::new(haystack).anchored(Anchored::Yes);
+    let mut state = dfa.start_state_forward(cache, &search).ok()?;
+    // Matches are reported one byte late, once the DFA has seen what follows them
+    let mut end = None;
+    for (offset, &byte) in haystack.iter().enumerate() {
+        if state.is_dead() {
+            break;
+        }
+        state = dfa.next_state(cache, state, byte).ok()?;
+        if state.is_quit() {
+            return None;
+        } else if state.is_match() {
+            end = Some(offset);
+        }
+    }
+    let end = if state.is_dead() {
+        end
+    } else if partial {
+        return settle(dfa, cache, state, end, haystack.len());
+    } else if dfa.next_eoi_state(cache, state).ok()?.is_match() {
+        Some(haystack.len())
+    } else {
+        end
+    };
+    Some(end.map(Search::Match).unwrap_or(Search::NoMatch))
+}
+
+/// The result of a search that reached the end of partial input, where `state` follows all `len`
+/// bytes with a match ending at `end` so far
+///
+/// This is only a match or not when it would be the same whatever comes next, including nothing.
+fn settle(
+    dfa: &DFA,
+    cache: &mut Cache,
+    state: LazyStateID,
+    end: Option<usize>,
+    len: usize,
+) -> Option<Search> {
+    let eoi_end = if dfa.next_eoi_state(cache, state).ok()?.is_match() {
+        Some(len)
+    } else {
+        end
+    };
+    for unit in dfa.byte_classes().representatives(0..=u8::MAX) {
+        let byte = unit.as_u8().expect("only bytes were requested");
+        let next = dfa.next_state(cache, state, byte).ok()?;
+        let next_end = if next.is_dead() {
+            end
+        } else if next.is_match() && ends(dfa, cache, next)? {
+            Some(len)
+        } else {
+            return Some(Search::Incomplete);
+        };
+        if next_end != eoi_end {
+            return Some(Search::Incomplete);
+        }
+    }
+    Some(eoi_end.map(Search::Match).unwrap_or(Search::NoMatch))
+}
+
+/// Whether `state` can't lead to any other match
+fn ends(dfa: &DFA, cache: &mut Cache, state: LazyStateID) -> Option<bool> {
+    if dfa.next_eoi_state(cache, state).ok()?.is_match() {
+        return Some(false);
+    }
+    for unit in dfa.byte_classes().representatives(0..=u8::MAX) {
+        let byte = unit.as_u8().expect("only bytes were requested");
+        if !dfa.next_state(cache, state, byte).ok()?.is_dead() {
+            return Some(false);
+        }
+    }
+    Some(true)
+}
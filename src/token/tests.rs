@@ -11,6 +11,7 @@ use crate::error::ErrMode;
 use crate::error::InputError;
 use crate::prelude::*;
 use crate::stream::AsChar;
+use crate::stream::BStr;
 use crate::token::literal;
 use crate::Partial;
 
@@ -2893,3 +2894,433 @@ Ok(
         .raw()
     );
 }
+
+#[test]
+#[cfg(feature = "alloc")]
+fn one_of_literals_longest() {
+    let set = LiteralSet::new(["in", "insert", "into", "int", "i"]);
+    let mut parser = one_of_literals::<_, _, _, ErrMode<InputError<_>>>(&set);
+    assert_eq!(parser.parse_peek("insert x"), Ok((" x", "insert")));
+    assert_eq!(parser.parse_peek("intox"), Ok(("x", "into")));
+    assert_eq!(parser.parse_peek("inse"), Ok(("se", "in")));
+    assert_eq!(parser.parse_peek("ix"), Ok(("x", "i")));
+    assert_eq!(
+        parser.parse_peek("x"),
+        Err(ErrMode::Backtrack(InputError::at("x")))
+    );
+    assert_eq!(
+        parser.parse_peek(""),
+        Err(ErrMode::Backtrack(InputError::at("")))
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn one_of_literals_values() {
+    let mut set: LiteralSet<u8> = [(&b"\x00"[..], 0), (b"\x00\xff", 1), (b"\xff", 2)]
+        .into_iter()
+        .collect();
+    // Re-inserting replaces the value
+    set.insert(b"\xff", 3);
+    assert_eq!(set.len(), 3);
+
+    let mut parser = one_of_literals::<_, _, _, ErrMode<InputError<_>>>(&set);
+    assert_eq!(
+        parser.parse_peek(&b"\x00\xff\x00"[..]),
+        Ok((&b"\x00"[..], 1))
+    );
+    assert_eq!(parser.parse_peek(&b"\xff"[..]), Ok((&b""[..], 3)));
+
+    let empty = LiteralSet::<()>::default();
+    assert!(empty.is_empty());
+    assert!(one_of_literals::<_, _, _, ErrMode<InputError<_>>>(&empty)
+        .parse_peek("a")
+        .is_err());
+    let set = LiteralSet::new([""]);
+    assert_eq!(
+        one_of_literals::<_, _, _, ErrMode<InputError<_>>>(&set).parse_peek("a"),
+        Ok(("a", ""))
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn one_of_literals_char_boundary() {
+    let set: LiteralSet<u8> = [(&b"a"[..], 0), (b"a\xc3", 1), (b"\xc3", 2)]
+        .into_iter()
+        .collect();
+    let mut parser = one_of_literals::<_, _, _, ErrMode<InputError<_>>>(&set);
+    assert_eq!(parser.parse_peek("aé"), Ok(("é", 0)));
+    assert_eq!(
+        parser.parse_peek("é"),
+        Err(ErrMode::Backtrack(InputError::at("é")))
+    );
+    assert_eq!(
+        one_of_literals::<_, _, _, ErrMode<InputError<_>>>(&set).parse_peek(&b"\xc3\xa9"[..]),
+        Ok((&b"\xa9"[..], 2))
+    );
+
+    let set = LiteralSet::new([&b"\xa9"[..], b"b"]);
+    let mut parser = take_until::<_, _, ErrMode<InputError<_>>>(0.., &set);
+    assert_eq!(parser.parse_peek("éb"), Ok(("b", "é")));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn one_of_literals_partial() {
+    let set = LiteralSet::new(["<", "<=", "<<", "<<="]);
+    let mut parser = one_of_literals::<_, _, _, ErrMode<InputError<_>>>(&set);
+    assert_eq!(
+        parser.parse_peek(Partial::new("<<= 1")),
+        Ok((Partial::new(" 1"), "<<="))
+    );
+    assert_eq!(
+        parser.parse_peek(Partial::new("<<=")),
+        Ok((Partial::new(""), "<<="))
+    );
+    assert_eq!(
+        parser.parse_peek(Partial::new("<<")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert_eq!(
+        parser.parse_peek(Partial::new("")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert!(parser.parse_peek(Partial::new(">")).is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn take_until_literal_set() {
+    // Exercise the `memchr`, `memchr2`, `memchr3`, and general first-byte searches
+    for literals in [
+        &["end"][..],
+        &["end", "stop"],
+        &["end", "stop", "halt"],
+        &["end", "stop", "halt", "quit"],
+    ] {
+        let set = LiteralSet::new(literals.iter().copied());
+        let mut parser = take_until::<_, _, ErrMode<InputError<_>>>(0.., &set);
+        assert_eq!(parser.parse_peek("e en ending"), Ok(("ending", "e en ")));
+        assert_eq!(parser.parse_peek("ends"), Ok(("ends", "")));
+        assert!(parser.parse_peek("en").is_err());
+        assert_eq!(
+            take_until::<_, _, ErrMode<InputError<_>>>(0.., &set).parse_peek(Partial::new("en")),
+            Err(ErrMode::Incomplete(Needed::Unknown))
+        );
+    }
+
+    let set = LiteralSet::new(["ab", "b"]);
+    let mut parser = take_until::<_, _, ErrMode<InputError<_>>>(1.., &set);
+    assert_eq!(parser.parse_peek("xab"), Ok(("ab", "x")));
+    assert_eq!(parser.parse_peek("xxb"), Ok(("b", "xx")));
+    assert_eq!(
+        take_until::<_, _, ErrMode<InputError<_>>>(1.., &set).parse_peek(BStr::new("é-b")),
+        Ok((BStr::new("b"), "é-".as_bytes()))
+    );
+}
+
+#[test]
+fn take_until_any_needles() {
+    // Exercise the `memchr`, `memchr2`, `memchr3`, and general first-byte searches
+    let mut parser = take_until_any::<_, _, ErrMode<InputError<_>>>(0.., ["{{"]);
+    assert_eq!(parser.parse_peek("a{b{{"), Ok(("{{", ("a{b", 0))));
+    let mut parser = take_until_any::<_, _, ErrMode<InputError<_>>>(0.., ["{{", "}}"]);
+    assert_eq!(parser.parse_peek("a}b}}{{"), Ok(("}}{{", ("a}b", 1))));
+    let mut parser = take_until_any::<_, _, ErrMode<InputError<_>>>(0.., ["{{", "{%", "<%", "%%"]);
+    assert_eq!(parser.parse_peek("a%<%{{"), Ok(("<%{{", ("a%", 2))));
+    let mut parser =
+        take_until_any::<_, _, ErrMode<InputError<_>>>(0.., ["{{", "[[", "<%", "%%", "$$"]);
+    assert_eq!(parser.parse_peek("a$[%%"), Ok(("%%", ("a$[", 3))));
+    assert!(parser.parse_peek("a$[%").is_err());
+
+    // Earliest in the list wins at the same offset
+    let mut parser = take_until_any::<_, _, ErrMode<InputError<_>>>(0.., ["ab", "a", "abc"]);
+    assert_eq!(parser.parse_peek("xabc"), Ok(("abc", ("x", 0))));
+    assert_eq!(parser.parse_peek("xac"), Ok(("ac", ("x", 1))));
+    let mut parser = take_until_any::<_, _, ErrMode<InputError<_>>>(0.., ["b", ""]);
+    assert_eq!(parser.parse_peek("ab"), Ok(("ab", ("", 1))));
+
+    let needles: &[&str] = &["\r\n", "\n"];
+    let mut parser = take_until_any::<_, _, ErrMode<InputError<_>>>(1.., needles);
+    assert_eq!(parser.parse_peek("a\r\nb"), Ok(("\r\nb", ("a", 0))));
+    assert_eq!(parser.parse_peek("a\rb\n"), Ok(("\n", ("a\rb", 1))));
+    assert!(parser.parse_peek("\n").is_err());
+
+    let mut parser = take_until_any::<_, _, ErrMode<InputError<_>>>(0.., ['é', '\n']);
+    assert_eq!(parser.parse_peek("caféx"), Ok(("éx", ("caf", 0))));
+    let mut parser = take_until_any::<_, _, ErrMode<InputError<_>>>(0.., [b'\r', b'\n']);
+    assert_eq!(
+        parser.parse_peek(&b"ab\nc"[..]),
+        Ok((&b"\nc"[..], (&b"ab"[..], 1)))
+    );
+    let mut parser = take_until_any::<_, _, ErrMode<InputError<_>>>(0..=2, ["c"]);
+    assert!(parser.parse_peek(BStr::new("abbc")).is_err());
+    assert_eq!(
+        parser.parse_peek(BStr::new("abc")),
+        Ok((BStr::new("c"), (&b"ab"[..], 0)))
+    );
+}
+
+#[test]
+fn take_until_any_partial() {
+    let mut parser = take_until_any::<_, _, ErrMode<InputError<_>>>(0.., ["abcd", "c"]);
+    // `abcd` could still be found first
+    assert_eq!(
+        parser.parse_peek(Partial::new("xabc")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert_eq!(
+        parser.parse_peek(Partial::new("xabcd")),
+        Ok((Partial::new("abcd"), ("x", 0)))
+    );
+    assert_eq!(
+        parser.parse_peek(Partial::new("xabce")),
+        Ok((Partial::new("ce"), ("xab", 1)))
+    );
+    assert_eq!(
+        parser.parse_peek(Partial::new("xcab")),
+        Ok((Partial::new("cab"), ("x", 1)))
+    );
+    assert_eq!(
+        parser.parse_peek(Partial::new("xab")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert_eq!(
+        parser.parse_peek(Partial::new("")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+
+    let mut parser = take_until_any::<_, _, ErrMode<InputError<_>>>(2.., ["c"]);
+    assert_eq!(
+        parser.parse_peek(Partial::new("ac")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn regex_str() {
+    let re = Regex::new(r"\A[a-z]+[0-9]*").unwrap();
+    let mut parser = regex::<_, _, ErrMode<InputError<_>>>(&re);
+    assert_eq!(parser.parse_peek("abc12 rest"), Ok((" rest", "abc12")));
+    assert_eq!(parser.parse_peek("abc"), Ok(("", "abc")));
+    assert!(parser.parse_peek("12abc").is_err());
+    assert!(parser.parse_peek("").is_err());
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn regex_unanchored() {
+    let re = Regex::new(r"[0-9]+").unwrap();
+    let mut parser = regex::<_, _, ErrMode<InputError<_>>>(&re);
+    assert_eq!(parser.parse_peek("12ab"), Ok(("ab", "12")));
+    assert!(parser.parse_peek("ab12").is_err());
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn regex_bytes() {
+    let re = Regex::new(r"\A[0-9]+").unwrap();
+    let mut parser = regex::<_, _, ErrMode<InputError<_>>>(&re);
+    assert_eq!(
+        parser.parse_peek(&b"123\xff"[..]),
+        Ok((&b"\xff"[..], &b"123"[..]))
+    );
+    assert!(parser.parse_peek(&b"\xff"[..]).is_err());
+
+    let mut parser = regex::<_, _, ErrMode<InputError<_>>>(&re);
+    assert_eq!(
+        parser.parse_peek(BStr::new(b"42;")),
+        Ok((BStr::new(b";"), &b"42"[..]))
+    );
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn regex_locating() {
+    use crate::stream::LocatingSlice;
+
+    let re = Regex::new(r"\A[a-z]+").unwrap();
+    let mut parser = (
+        literal::<_, _, ErrMode<InputError<_>>>("("),
+        regex(&re).span(),
+    );
+    let input = LocatingSlice::new("(abc)");
+    let (rest, (_, span)) = parser.parse_peek(input).unwrap();
+    assert_eq!(span, 1..4);
+    assert_eq!(rest.peek_slice(rest.eof_offset()), ")");
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn regex_partial() {
+    let re = Regex::new(r"\A[0-9]+").unwrap();
+    let mut parser = regex::<_, _, ErrMode<InputError<_>>>(&re);
+    assert_eq!(
+        parser.parse_peek(Partial::new("12;")),
+        Ok((Partial::new(";"), "12"))
+    );
+    assert_eq!(
+        parser.parse_peek(Partial::new("12")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert!(parser.parse_peek(Partial::new(";")).is_err());
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn regex_partial_extendable() {
+    let re = Regex::new(r"\A[a-z]+(;[0-9]+)?").unwrap();
+    let mut parser = regex::<_, _, ErrMode<InputError<_>>>(&re);
+    assert_eq!(
+        parser.parse_peek(Partial::new("abc;")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert_eq!(
+        parser.parse_peek(Partial::new("abc;1 ")),
+        Ok((Partial::new(" "), "abc;1"))
+    );
+    assert_eq!(
+        parser.parse_peek(Partial::new("abc;x")),
+        Ok((Partial::new(";x"), "abc"))
+    );
+    assert_eq!(
+        regex::<_, _, ErrMode<InputError<_>>>(&re).parse_peek("abc;"),
+        Ok((";", "abc"))
+    );
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn regex_partial_unmatched_prefix() {
+    let re = Regex::new(r"\A[0-9]+;").unwrap();
+    let mut parser = regex::<_, _, ErrMode<InputError<_>>>(&re);
+    assert_eq!(
+        parser.parse_peek(Partial::new("123")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert_eq!(
+        parser.parse_peek(Partial::new("123;")),
+        Ok((Partial::new(""), "123;"))
+    );
+    assert!(matches!(
+        parser.parse_peek(Partial::new("123a")),
+        Err(ErrMode::Backtrack(_))
+    ));
+    assert!(matches!(
+        regex::<_, _, ErrMode<InputError<_>>>(&re).parse_peek("123"),
+        Err(ErrMode::Backtrack(_))
+    ));
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn regex_partial_settled() {
+    // Nothing can follow a two digit match, so it doesn't need more input
+    let re = Regex::new(r"\A[0-9]{2}").unwrap();
+    let mut parser = regex::<_, _, ErrMode<InputError<_>>>(&re);
+    assert_eq!(
+        parser.parse_peek(Partial::new("12")),
+        Ok((Partial::new(""), "12"))
+    );
+
+    // Whether the match ends here depends on whether the input does
+    let re = Regex::new(r"\A[0-9]+$").unwrap();
+    let mut parser = regex::<_, _, ErrMode<InputError<_>>>(&re);
+    assert_eq!(
+        parser.parse_peek(Partial::new("12")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert_eq!(
+        regex::<_, _, ErrMode<InputError<_>>>(&re).parse_peek("12"),
+        Ok(("", "12"))
+    );
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn regex_captures_str() {
+    let re = Regex::new(r"\A(?<key>[a-z]+)=(?<value>[0-9]+)").unwrap();
+    let mut parser = regex_captures::<_, _, ErrMode<InputError<_>>>(&re);
+    let (rest, (matched, caps)) = parser.parse_peek("ab=12,cd=3").unwrap();
+    assert_eq!(rest, ",cd=3");
+    assert_eq!(matched, "ab=12");
+    assert_eq!(
+        caps.get_group_by_name("key").map(|s| &matched[s]),
+        Some("ab")
+    );
+    assert_eq!(
+        caps.get_group_by_name("value").map(|s| &matched[s]),
+        Some("12")
+    );
+    assert!(parser.parse_peek(",ab=12").is_err());
+
+    let mut parser = regex_captures::<_, _, ErrMode<InputError<_>>>(&re);
+    assert_eq!(
+        parser
+            .parse_peek(Partial::new("ab=12"))
+            .map(|(rest, (matched, _))| (rest, matched)),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert_eq!(
+        parser
+            .parse_peek(Partial::new("ab="))
+            .map(|(rest, (matched, _))| (rest, matched)),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert_eq!(
+        parser
+            .parse_peek(Partial::new("ab=12,"))
+            .map(|(rest, (matched, _))| (rest, matched)),
+        Ok((Partial::new(","), "ab=12"))
+    );
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn regex_syntax() {
+    use regex_automata::util::syntax;
+
+    let re = Regex::with_syntax(
+        "abc",
+        syntax::Config::new().unicode(false).case_insensitive(true),
+    )
+    .unwrap();
+    let mut parser = regex::<_, _, ErrMode<InputError<_>>>(&re);
+    assert_eq!(parser.parse_peek("ABC;"), Ok((";", "ABC")));
+    let mut parser = regex::<_, _, ErrMode<InputError<_>>>(&re);
+    assert_eq!(
+        parser.parse_peek(Partial::new("AB")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+
+    let re = Regex::with_syntax(r"(?-u:\xc3)", syntax::Config::new().utf8(false)).unwrap();
+    let mut parser = regex::<_, _, ErrMode<InputError<_>>>(&re);
+    assert_eq!(
+        parser.parse_peek(&b"\xc3\xa9"[..]),
+        Ok((&b"\xa9"[..], &b"\xc3"[..]))
+    );
+    let mut parser = regex::<_, _, ErrMode<InputError<_>>>(&re);
+    assert!(parser.parse_peek("é").is_err());
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn regex_unicode_word_boundary() {
+    // The DFA gives up on non-ASCII input around a Unicode `\b`
+    let re = Regex::new(r"\w+\b").unwrap();
+    let mut parser = regex::<_, _, ErrMode<InputError<_>>>(&re);
+    assert_eq!(parser.parse_peek("héllo wörld"), Ok((" wörld", "héllo")));
+    assert!(parser.parse_peek(" héllo").is_err());
+    let mut parser = regex::<_, _, ErrMode<InputError<_>>>(&re);
+    assert_eq!(
+        parser.parse_peek(Partial::new("héllo")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+
+    let mut parser = regex_captures::<_, _, ErrMode<InputError<_>>>(&re);
+    let (rest, (matched, caps)) = parser.parse_peek("héllo wörld").unwrap();
+    assert_eq!((rest, matched), (" wörld", "héllo"));
+    assert_eq!(caps.get_match().map(|m| m.range()), Some(0..6));
+}
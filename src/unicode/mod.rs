@@ -0,0 +1,1088 @@
+//! Unicode-aware character parsers
+//!
+//! Unlike the [`ascii`][crate::ascii] parsers, these classify characters by their Unicode
+//! properties, so they accept non-ASCII letters, digits, and whitespace.
+//!
+//! The character classes are available as [sets of tokens][ContainsToken] for use with
+//! [`one_of`], [`take_while`], and friends:
+//! - [`XidStart`] and [`XidContinue`] for identifiers, see [`identifier`]
+//! - [`WhiteSpace`] and [`HorizontalSpace`]
+//! - [`GeneralCategory`] and [`GeneralCategoryGroup`]
+//!
+//! [`grapheme`] and [`take_graphemes`] take user-perceived characters (extended grapheme clusters,
+//! [UAX #29](https://www.unicode.org/reports/tr29/)) rather than `char`s.
+
+mod case_folding;
+
+#[cfg(test)]
+mod tests;
+
+use unicode_properties::UnicodeGeneralCategory as _;
+use unicode_segmentation::GraphemeCursor;
+
+use crate::combinator::trace;
+use crate::error::Needed;
+use crate::error::ParserError;
+use crate::lib::std::str::from_utf8;
+use crate::stream::AsChar;
+use crate::stream::CompareResult;
+use crate::stream::ContainsToken;
+use crate::stream::Range;
+use crate::stream::Stream;
+use crate::stream::StreamIsPartial;
+use crate::token::one_of;
+use crate::token::take_while;
+use crate::Parser;
+use crate::Result;
+
+/// Characters that can start an identifier: the `XID_Start` property
+///
+/// See [UAX #31](https://www.unicode.org/reports/tr31/) and [`identifier`].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::token::one_of;
+/// use winnow::unicode::XidStart;
+///
+/// fn parser(input: &mut &str) -> ModalResult<char> {
+///     one_of(XidStart).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("éa"), Ok(("a", 'é')));
+/// assert!(parser.parse_peek("1a").is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct XidStart;
+
+impl<C: AsChar> ContainsToken<C> for XidStart {
+    #[inline]
+    fn contains_token(&self, token: C) -> bool {
+        unicode_ident::is_xid_start(token.as_char())
+    }
+}
+
+/// Characters that can continue an identifier: the `XID_Continue` property
+///
+/// See [UAX #31](https://www.unicode.org/reports/tr31/) and [`identifier`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct XidContinue;
+
+impl<C: AsChar> ContainsToken<C> for XidContinue {
+    #[inline]
+    fn contains_token(&self, token: C) -> bool {
+        unicode_ident::is_xid_continue(token.as_char())
+    }
+}
+
+/// Whitespace, including line breaks: the `White_Space` property
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WhiteSpace;
+
+impl<C: AsChar> ContainsToken<C> for WhiteSpace {
+    #[inline]
+    fn contains_token(&self, token: C) -> bool {
+        token.as_char().is_whitespace()
+    }
+}
+
+/// Whitespace within a line: [`WhiteSpace`] other than line breaks
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct HorizontalSpace;
+
+impl<C: AsChar> ContainsToken<C> for HorizontalSpace {
+    #[inline]
+    fn contains_token(&self, token: C) -> bool {
+        let c = token.as_char();
+        c.is_whitespace()
+            && !matches!(
+                c,
+                '\n' | '\u{b}' | '\u{c}' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}'
+            )
+    }
+}
+
+/// The general category of a character
+///
+/// As a [set of tokens][ContainsToken], this matches characters in the category.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::token::take_while;
+/// use winnow::unicode::GeneralCategory;
+///
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     take_while(1.., GeneralCategory::DecimalNumber).parse_next(input)
+/// }
+///
+/// assert_eq!(GeneralCategory::of('Ä'), GeneralCategory::UppercaseLetter);
+/// assert_eq!(parser.parse_peek("١٢٣ "), Ok((" ", "١٢٣")));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum GeneralCategory {
+    UppercaseLetter,
+    LowercaseLetter,
+    TitlecaseLetter,
+    ModifierLetter,
+    OtherLetter,
+    NonspacingMark,
+    SpacingMark,
+    EnclosingMark,
+    DecimalNumber,
+    LetterNumber,
+    OtherNumber,
+    ConnectorPunctuation,
+    DashPunctuation,
+    OpenPunctuation,
+    ClosePunctuation,
+    InitialPunctuation,
+    FinalPunctuation,
+    OtherPunctuation,
+    MathSymbol,
+    CurrencySymbol,
+    ModifierSymbol,
+    OtherSymbol,
+    SpaceSeparator,
+    LineSeparator,
+    ParagraphSeparator,
+    Control,
+    Format,
+    Surrogate,
+    PrivateUse,
+    Unassigned,
+}
+
+impl GeneralCategory {
+    /// The general category of `c`
+    pub fn of(c: char) -> Self {
+        use unicode_properties::GeneralCategory as Category;
+
+        match c.general_category() {
+            Category::UppercaseLetter => Self::UppercaseLetter,
+            Category::LowercaseLetter => Self::LowercaseLetter,
+            Category::TitlecaseLetter => Self::TitlecaseLetter,
+            Category::ModifierLetter => Self::ModifierLetter,
+            Category::OtherLetter => Self::OtherLetter,
+            Category::NonspacingMark => Self::NonspacingMark,
+            Category::SpacingMark => Self::SpacingMark,
+            Category::EnclosingMark => Self::EnclosingMark,
+            Category::DecimalNumber => Self::DecimalNumber,
+            Category::LetterNumber => Self::LetterNumber,
+            Category::OtherNumber => Self::OtherNumber,
+            Category::ConnectorPunctuation => Self::ConnectorPunctuation,
+            Category::DashPunctuation => Self::DashPunctuation,
+            Category::OpenPunctuation => Self::OpenPunctuation,
+            Category::ClosePunctuation => Self::ClosePunctuation,
+            Category::InitialPunctuation => Self::InitialPunctuation,
+            Category::FinalPunctuation => Self::FinalPunctuation,
+            Category::OtherPunctuation => Self::OtherPunctuation,
+            Category::MathSymbol => Self::MathSymbol,
+            Category::CurrencySymbol => Self::CurrencySymbol,
+            Category::ModifierSymbol => Self::ModifierSymbol,
+            Category::OtherSymbol => Self::OtherSymbol,
+            Category::SpaceSeparator => Self::SpaceSeparator,
+            Category::LineSeparator => Self::LineSeparator,
+            Category::ParagraphSeparator => Self::ParagraphSeparator,
+            Category::Control => Self::Control,
+            Category::Format => Self::Format,
+            Category::Surrogate => Self::Surrogate,
+            Category::PrivateUse => Self::PrivateUse,
+            Category::Unassigned => Self::Unassigned,
+        }
+    }
+
+    /// The group this category belongs to
+    pub fn group(self) -> GeneralCategoryGroup {
+        match self {
+            Self::UppercaseLetter
+            | Self::LowercaseLetter
+            | Self::TitlecaseLetter
+            | Self::ModifierLetter
+            | Self::OtherLetter => GeneralCategoryGroup::Letter,
+            Self::NonspacingMark | Self::SpacingMark | Self::EnclosingMark => {
+                GeneralCategoryGroup::Mark
+            }
+            Self::DecimalNumber | Self::LetterNumber | Self::OtherNumber => {
+                GeneralCategoryGroup::Number
+            }
+            Self::ConnectorPunctuation
+            | Self::DashPunctuation
+            | Self::OpenPunctuation
+            | Self::ClosePunctuation
+            | Self::InitialPunctuation
+            | Self::FinalPunctuation
+            | Self::OtherPunctuation => GeneralCategoryGroup::Punctuation,
+            Self::MathSymbol | Self::CurrencySymbol | Self::ModifierSymbol | Self::OtherSymbol => {
+                GeneralCategoryGroup::Symbol
+            }
+            Self::SpaceSeparator | Self::LineSeparator | Self::ParagraphSeparator => {
+                GeneralCategoryGroup::Separator
+            }
+            Self::Control
+            | Self::Format
+            | Self::Surrogate
+            | Self::PrivateUse
+            | Self::Unassigned => GeneralCategoryGroup::Other,
+        }
+    }
+}
+
+impl<C: AsChar> ContainsToken<C> for GeneralCategory {
+    #[inline]
+    fn contains_token(&self, token: C) -> bool {
+        Self::of(token.as_char()) == *self
+    }
+}
+
+/// The major class of a [`GeneralCategory`]
+///
+/// As a [set of tokens][ContainsToken], this matches characters in any category of the group.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::token::take_while;
+/// use winnow::unicode::GeneralCategoryGroup;
+///
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     take_while(1.., GeneralCategoryGroup::Letter).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("Straße1"), Ok(("1", "Straße")));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum GeneralCategoryGroup {
+    Letter,
+    Mark,
+    Number,
+    Punctuation,
+    Symbol,
+    Separator,
+    Other,
+}
+
+impl GeneralCategoryGroup {
+    /// The general category group of `c`
+    pub fn of(c: char) -> Self {
+        GeneralCategory::of(c).group()
+    }
+}
+
+impl<C: AsChar> ContainsToken<C> for GeneralCategoryGroup {
+    #[inline]
+    fn contains_token(&self, token: C) -> bool {
+        Self::of(token.as_char()) == *self
+    }
+}
+
+/// Mark a value as case-insensitive using Unicode full case folding
+///
+/// Unlike [`ascii::Caseless`][crate::ascii::Caseless], this matches letters in any script,
+/// including matches of different lengths, like `"straße"` and `"STRASSE"`.
+/// See [`SimpleCaseless`] for only matching one character against another.
+///
+/// This works with [`literal`][crate::token::literal], [`take_until`][crate::token::take_until],
+/// and other users of [`Compare`][crate::stream::Compare] and
+/// [`FindSlice`][crate::stream::FindSlice] on UTF-8 streams, like `&str` and `&BStr`.
+/// Folding is not locale-specific, so the Turkish dotless `ı` does not match `I`.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::unicode::Caseless;
+///
+/// fn parser<'s>(s: &mut &'s str) -> ModalResult<&'s str> {
+///     Caseless("straße").parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek("STRASSE 1"), Ok((" 1", "STRASSE")));
+/// assert_eq!(parser.parse_peek("Straße 1"), Ok((" 1", "Straße")));
+/// assert!(parser.parse_peek("Strasze").is_err());
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Caseless<T>(pub T);
+
+/// Mark a value as case-insensitive using Unicode simple case folding
+///
+/// Each character only matches a single character, so `"ß"` does not match `"SS"` like it does
+/// with [`Caseless`], but `"ẞ"` does.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::unicode::SimpleCaseless;
+///
+/// fn parser<'s>(s: &mut &'s str) -> ModalResult<&'s str> {
+///     SimpleCaseless("ΣΕΛΕΚΤ").parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek("σελεκτ *"), Ok((" *", "σελεκτ")));
+/// assert_eq!(parser.parse_peek("Σελεκτ *"), Ok((" *", "Σελεκτ")));
+/// assert!(parser.parse_peek("σελ").is_err());
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct SimpleCaseless<T>(pub T);
+
+/// Recognizes zero or more alphabetic characters: the `Alphabetic` property
+///
+/// *Complete version*: Will return the whole input if no terminating token is found (a non
+/// alphabetic character).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a non alphabetic character).
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn alpha0<'i>(input: &mut &'i str) -> ModalResult<&'i str>
+/// # {
+/// #     winnow::unicode::alpha0.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::unicode::alpha0;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     alpha0.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("Ωμέγα1"), Ok(("1", "Ωμέγα")));
+/// assert_eq!(parser.parse_peek("1c"), Ok(("1c", "")));
+/// assert_eq!(parser.parse_peek(""), Ok(("", "")));
+/// ```
+#[inline(always)]
+pub fn alpha0<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    trace("alpha0", take_while(0.., is_alphabetic)).parse_next(input)
+}
+
+/// Recognizes one or more alphabetic characters: the `Alphabetic` property
+///
+/// *Complete version*: Will return an error if there's not enough input data,
+/// or the whole input if no terminating token is found (a non alphabetic character).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a non alphabetic character).
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn alpha1<'i>(input: &mut &'i str) -> ModalResult<&'i str>
+/// # {
+/// #     winnow::unicode::alpha1.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::unicode::alpha1;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     alpha1.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("日本語 "), Ok((" ", "日本語")));
+/// assert!(parser.parse_peek("1c").is_err());
+/// assert!(parser.parse_peek("").is_err());
+/// ```
+#[inline(always)]
+pub fn alpha1<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    trace("alpha1", take_while(1.., is_alphabetic)).parse_next(input)
+}
+
+/// Recognizes zero or more alphabetic or numeric characters: the `Alphabetic` and `Numeric`
+/// properties
+///
+/// *Complete version*: Will return the whole input if no terminating token is found (a non
+/// alphanumeric character).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a non alphanumeric character).
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn alphanumeric0<'i>(input: &mut &'i str) -> ModalResult<&'i str>
+/// # {
+/// #     winnow::unicode::alphanumeric0.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::unicode::alphanumeric0;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     alphanumeric0.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("ab٣½-"), Ok(("-", "ab٣½")));
+/// assert_eq!(parser.parse_peek("-"), Ok(("-", "")));
+/// assert_eq!(parser.parse_peek(""), Ok(("", "")));
+/// ```
+#[inline(always)]
+pub fn alphanumeric0<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    trace("alphanumeric0", take_while(0.., is_alphanumeric)).parse_next(input)
+}
+
+/// Recognizes one or more alphabetic or numeric characters: the `Alphabetic` and `Numeric`
+/// properties
+///
+/// *Complete version*: Will return an error if there's not enough input data,
+/// or the whole input if no terminating token is found (a non alphanumeric character).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a non alphanumeric character).
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn alphanumeric1<'i>(input: &mut &'i str) -> ModalResult<&'i str>
+/// # {
+/// #     winnow::unicode::alphanumeric1.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::unicode::alphanumeric1;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     alphanumeric1.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("ab٣½-"), Ok(("-", "ab٣½")));
+/// assert!(parser.parse_peek("-").is_err());
+/// assert!(parser.parse_peek("").is_err());
+/// ```
+#[inline(always)]
+pub fn alphanumeric1<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    trace("alphanumeric1", take_while(1.., is_alphanumeric)).parse_next(input)
+}
+
+/// Recognizes zero or more decimal digits in any script: [`GeneralCategory::DecimalNumber`]
+///
+/// *Complete version*: Will return the whole input if no terminating token is found (a non digit
+/// character).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a non digit character).
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn digit0<'i>(input: &mut &'i str) -> ModalResult<&'i str>
+/// # {
+/// #     winnow::unicode::digit0.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::unicode::digit0;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     digit0.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("12٣४c"), Ok(("c", "12٣४")));
+/// assert_eq!(parser.parse_peek("½"), Ok(("½", "")));
+/// assert_eq!(parser.parse_peek(""), Ok(("", "")));
+/// ```
+#[inline(always)]
+pub fn digit0<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    trace("digit0", take_while(0.., GeneralCategory::DecimalNumber)).parse_next(input)
+}
+
+/// Recognizes one or more decimal digits in any script: [`GeneralCategory::DecimalNumber`]
+///
+/// *Complete version*: Will return an error if there's not enough input data,
+/// or the whole input if no terminating token is found (a non digit character).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a non digit character).
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn digit1<'i>(input: &mut &'i str) -> ModalResult<&'i str>
+/// # {
+/// #     winnow::unicode::digit1.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::unicode::digit1;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     digit1.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("12٣४c"), Ok(("c", "12٣४")));
+/// assert!(parser.parse_peek("½").is_err());
+/// assert!(parser.parse_peek("").is_err());
+/// ```
+#[inline(always)]
+pub fn digit1<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    trace("digit1", take_while(1.., GeneralCategory::DecimalNumber)).parse_next(input)
+}
+
+/// Recognizes zero or more whitespace characters within a line, see [`HorizontalSpace`]
+///
+/// *Complete version*: Will return the whole input if no terminating token is found (a non space
+/// character).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a non space character).
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn space0<'i>(input: &mut &'i str) -> ModalResult<&'i str>
+/// # {
+/// #     winnow::unicode::space0.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::unicode::space0;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     space0.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek(" \t\u{3000}\nZ"), Ok(("\nZ", " \t\u{3000}")));
+/// assert_eq!(parser.parse_peek("Z"), Ok(("Z", "")));
+/// assert_eq!(parser.parse_peek(""), Ok(("", "")));
+/// ```
+#[inline(always)]
+pub fn space0<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    trace("space0", take_while(0.., HorizontalSpace)).parse_next(input)
+}
+
+/// Recognizes one or more whitespace characters within a line, see [`HorizontalSpace`]
+///
+/// *Complete version*: Will return an error if there's not enough input data,
+/// or the whole input if no terminating token is found (a non space character).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a non space character).
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn space1<'i>(input: &mut &'i str) -> ModalResult<&'i str>
+/// # {
+/// #     winnow::unicode::space1.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::unicode::space1;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     space1.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("\u{a0}\u{2003}Z"), Ok(("Z", "\u{a0}\u{2003}")));
+/// assert!(parser.parse_peek("\nZ").is_err());
+/// assert!(parser.parse_peek("").is_err());
+/// ```
+#[inline(always)]
+pub fn space1<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    trace("space1", take_while(1.., HorizontalSpace)).parse_next(input)
+}
+
+/// Recognizes zero or more whitespace characters, including line breaks, see [`WhiteSpace`]
+///
+/// *Complete version*: Will return the whole input if no terminating token is found (a non space
+/// character).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a non space character).
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn multispace0<'i>(input: &mut &'i str) -> ModalResult<&'i str>
+/// # {
+/// #     winnow::unicode::multispace0.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::unicode::multispace0;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     multispace0.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek(" \u{2028}\r\nZ"), Ok(("Z", " \u{2028}\r\n")));
+/// assert_eq!(parser.parse_peek("Z"), Ok(("Z", "")));
+/// assert_eq!(parser.parse_peek(""), Ok(("", "")));
+/// ```
+#[inline(always)]
+pub fn multispace0<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    trace("multispace0", take_while(0.., WhiteSpace)).parse_next(input)
+}
+
+/// Recognizes one or more whitespace characters, including line breaks, see [`WhiteSpace`]
+///
+/// *Complete version*: Will return an error if there's not enough input data,
+/// or the whole input if no terminating token is found (a non space character).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a non space character).
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn multispace1<'i>(input: &mut &'i str) -> ModalResult<&'i str>
+/// # {
+/// #     winnow::unicode::multispace1.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::unicode::multispace1;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     multispace1.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("\u{85}\u{3000}Z"), Ok(("Z", "\u{85}\u{3000}")));
+/// assert!(parser.parse_peek("Z").is_err());
+/// assert!(parser.parse_peek("").is_err());
+/// ```
+#[inline(always)]
+pub fn multispace1<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    trace("multispace1", take_while(1.., WhiteSpace)).parse_next(input)
+}
+
+/// Recognizes an identifier: an [`XidStart`] character followed by zero or more [`XidContinue`]
+/// characters
+///
+/// This is the default identifier syntax of [UAX #31](https://www.unicode.org/reports/tr31/).
+/// Many languages also allow identifiers to start with `_`, see the example.
+///
+/// *Complete version*: Will return an error if there's not enough input data,
+/// or the whole input if no terminating token is found (a non identifier character).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a non identifier character).
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn identifier<'i>(input: &mut &'i str) -> ModalResult<&'i str>
+/// # {
+/// #     winnow::unicode::identifier.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::unicode::identifier;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     identifier.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("größe_2 = 1"), Ok((" = 1", "größe_2")));
+/// assert_eq!(parser.parse_peek("переменная;"), Ok((";", "переменная")));
+/// assert!(parser.parse_peek("_x").is_err());
+/// assert!(parser.parse_peek("2x").is_err());
+/// ```
+///
+/// Allowing a leading `_`:
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::token::one_of;
+/// use winnow::token::take_while;
+/// use winnow::unicode::{XidContinue, XidStart};
+///
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     (one_of((XidStart, '_')), take_while(0.., XidContinue))
+///         .take()
+///         .parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("_x1 "), Ok((" ", "_x1")));
+/// ```
+#[inline(always)]
+pub fn identifier<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input>,
+{
+    trace(
+        "identifier",
+        (one_of(XidStart), take_while(0.., XidContinue)).take(),
+    )
+    .parse_next(input)
+}
+
+/// Recognizes one user-perceived character: an extended grapheme cluster
+///
+/// Offsets into the [Stream] must be bytes of a `str`, as with `&str`.
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if the
+/// cluster runs to the end of the input, as more input could extend it.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn grapheme<'i>(input: &mut &'i str) -> ModalResult<&'i str>
+/// # {
+/// #     winnow::unicode::grapheme.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::unicode::grapheme;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     grapheme.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("e\u{301}x"), Ok(("x", "e\u{301}")));
+/// assert_eq!(parser.parse_peek("👨‍👩‍👧!"), Ok(("!", "👨‍👩‍👧")));
+/// assert_eq!(parser.parse_peek("\r\n"), Ok(("", "\r\n")));
+/// assert!(parser.parse_peek("").is_err());
+/// ```
+#[inline(always)]
+pub fn grapheme<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Slice: AsRef<str>,
+    Error: ParserError<Input>,
+{
+    trace("grapheme", take_graphemes(1)).parse_next(input)
+}
+
+/// Recognizes user-perceived characters (extended grapheme clusters), greedily taking as many as
+/// `occurrences` allows
+///
+/// This is the grapheme-aware counterpart to [`take`][crate::token::take], which counts `char`s.
+/// Offsets into the [Stream] must be bytes of a `str`, as with `&str`.
+///
+/// *Complete version*: Will return an error if there are fewer clusters than the minimum.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if the
+/// last cluster runs to the end of the input, as more input could extend it.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream] with `0..` or `1..` [ranges][Range]:
+/// ```rust
+/// # use std::ops::RangeFrom;
+/// # use winnow::prelude::*;;
+/// # use winnow::error::ContextError;
+/// pub fn take_graphemes<'i>(occurrences: RangeFrom<usize>) -> impl Parser<&'i str, &'i str, ContextError>
+/// # {
+/// #     winnow::unicode::take_graphemes(occurrences)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::unicode::take_graphemes;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     take_graphemes(3).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("🇫🇷🇩🇪🇯🇵🇺🇸"), Ok(("🇺🇸", "🇫🇷🇩🇪🇯🇵")));
+/// assert_eq!(parser.parse_peek("ne\u{301}e"), Ok(("", "ne\u{301}e")));
+/// assert!(parser.parse_peek("e\u{301}e").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::Partial;
+/// # use winnow::unicode::take_graphemes;
+/// fn parser<'s>(input: &mut Partial<&'s str>) -> ModalResult<&'s str> {
+///     take_graphemes(2).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new("abc")), Ok((Partial::new("c"), "ab")));
+/// // A combining mark could follow `b`
+/// assert_eq!(parser.parse_peek(Partial::new("ab")), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// ```
+#[inline(always)]
+pub fn take_graphemes<Input, Error>(
+    occurrences: impl Into<Range>,
+) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Slice: AsRef<str>,
+    Error: ParserError<Input>,
+{
+    let Range {
+        start_inclusive,
+        end_inclusive,
+    } = occurrences.into();
+    trace("take_graphemes", move |i: &mut Input| {
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            take_graphemes_::<_, _, true>(i, start_inclusive, end_inclusive)
+        } else {
+            take_graphemes_::<_, _, false>(i, start_inclusive, end_inclusive)
+        }
+    })
+}
+
+fn take_graphemes_<I, Error: ParserError<I>, const PARTIAL: bool>(
+    i: &mut I,
+    min: usize,
+    max: Option<usize>,
+) -> Result<<I as Stream>::Slice, Error>
+where
+    I: StreamIsPartial,
+    I: Stream,
+    <I as Stream>::Slice: AsRef<str>,
+{
+    let remaining = i.peek_slice(i.eof_offset());
+    let remaining = remaining.as_ref();
+    let mut cursor = GraphemeCursor::new(0, remaining.len(), true);
+    let mut count = 0;
+    let mut offset = 0;
+    while max.map(|max| count < max).unwrap_or(true) {
+        match cursor.next_boundary(remaining, 0) {
+            Ok(Some(boundary)) => {
+                offset = boundary;
+                count += 1;
+            }
+            Ok(None) | Err(_) => break,
+        }
+    }
+
+    let more = max.map(|max| count < max).unwrap_or(true);
+    if PARTIAL && i.is_partial() && offset == remaining.len() && (more || 0 < count) {
+        return Err(ParserError::incomplete(i, Needed::Unknown));
+    }
+    if count < min {
+        return Err(ParserError::from_input(i));
+    }
+    Ok(i.next_slice(offset))
+}
+
+fn is_alphabetic<C: AsChar>(c: C) -> bool {
+    c.as_char().is_alphabetic()
+}
+
+fn is_alphanumeric<C: AsChar>(c: C) -> bool {
+    c.as_char().is_alphanumeric()
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Folding {
+    Full,
+    Simple,
+}
+
+/// Case-insensitive [`Compare`][crate::stream::Compare] of UTF-8 `input` against `literal`
+pub(crate) fn compare_folded(input: &[u8], literal: &str, folding: Folding) -> CompareResult {
+    let mut expected = literal.chars().flat_map(|c| fold(c, folding)).peekable();
+    let mut offset = 0;
+    while expected.peek().is_some() {
+        let (c, len) = match next_char(&input[offset..]) {
+            Ok(next) => next,
+            Err(result) => return result,
+        };
+        if fold(c, folding).any(|c| expected.next() != Some(c)) {
+            return CompareResult::Error;
+        }
+        offset += len;
+    }
+    CompareResult::Ok(offset)
+}
+
+/// Case-insensitive [`FindSlice`][crate::stream::FindSlice] of `literal` in UTF-8 `input`
+pub(crate) fn find_folded(
+    input: &[u8],
+    literal: &str,
+    folding: Folding,
+) -> Option<crate::lib::std::ops::Range<usize>> {
+    (0..=input.len())
+        .filter(|&start| {
+            input
+                .get(start)
+                .map(|b| !(0x80..0xc0).contains(b))
+                .unwrap_or(true)
+        })
+        .find_map(
+            |start| match compare_folded(&input[start..], literal, folding) {
+                CompareResult::Ok(len) => Some(start..start + len),
+                CompareResult::Incomplete | CompareResult::Error => None,
+            },
+        )
+}
+
+fn next_char(input: &[u8]) -> Result<(char, usize), CompareResult> {
+    let len = match input.first() {
+        Some(0x00..=0x7f) => 1,
+        Some(0xc2..=0xdf) => 2,
+        Some(0xe0..=0xef) => 3,
+        Some(0xf0..=0xf4) => 4,
+        Some(_) => return Err(CompareResult::Error),
+        None => return Err(CompareResult::Incomplete),
+    };
+    match input.get(..len) {
+        Some(bytes) => from_utf8(bytes)
+            .ok()
+            .and_then(|s| s.chars().next())
+            .map(|c| (c, len))
+            .ok_or(CompareResult::Error),
+        None => match from_utf8(input) {
+            Err(err) if err.error_len().is_some() => Err(CompareResult::Error),
+            _ => Err(CompareResult::Incomplete),
+        },
+    }
+}
+
+fn fold(c: char, folding: Folding) -> Folded {
+    let mut folded = Folded {
+        chars: [c, '\0', '\0'],
+        next: 0,
+        len: 1,
+    };
+    if folding == Folding::Simple {
+        if let Ok(i) = case_folding::SIMPLE.binary_search_by_key(&c, |(c, _)| *c) {
+            folded.chars[0] = case_folding::SIMPLE[i].1;
+            return folded;
+        }
+    }
+    if let Ok(i) = case_folding::FULL.binary_search_by_key(&c, |(c, _)| *c) {
+        let chars = case_folding::FULL[i].1;
+        let len = chars.iter().take_while(|c| **c != '\0').count();
+        // Without a simple folding, characters with a multi-character full folding fold to
+        // themselves
+        if folding == Folding::Full || len == 1 {
+            folded.chars = chars;
+            folded.len = len;
+        }
+    }
+    folded
+}
+
+struct Folded {
+    chars: [char; 3],
+    next: usize,
+    len: usize,
+}
+
+impl Iterator for Folded {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars[..self.len].get(self.next).copied();
+        self.next += 1;
+        c
+    }
+}
@@ -0,0 +1,407 @@
+use super::*;
+
+use crate::error::ErrMode;
+use crate::error::InputError;
+use crate::stream::BStr;
+use crate::stream::LocatingSlice;
+use crate::token::literal;
+use crate::token::take_until;
+use crate::Partial;
+
+#[test]
+fn xid() {
+    for c in ['a', 'Z', 'é', 'λ', '日', 'ǅ'] {
+        assert!(XidStart.contains_token(c), "{c:?}");
+        assert!(XidContinue.contains_token(c), "{c:?}");
+    }
+    for c in ['1', '_', '\u{301}', '٣'] {
+        assert!(!XidStart.contains_token(c), "{c:?}");
+        assert!(XidContinue.contains_token(c), "{c:?}");
+    }
+    for c in [' ', '-', '$', '😀'] {
+        assert!(!XidStart.contains_token(c), "{c:?}");
+        assert!(!XidContinue.contains_token(c), "{c:?}");
+    }
+    assert!(XidStart.contains_token(b'a'));
+    assert!(!XidStart.contains_token(b'1'));
+}
+
+#[test]
+fn whitespace() {
+    for c in [
+        ' ', '\t', '\u{a0}', '\u{1680}', '\u{2003}', '\u{202f}', '\u{3000}',
+    ] {
+        assert!(WhiteSpace.contains_token(c), "{c:?}");
+        assert!(HorizontalSpace.contains_token(c), "{c:?}");
+    }
+    for c in [
+        '\n', '\u{b}', '\u{c}', '\r', '\u{85}', '\u{2028}', '\u{2029}',
+    ] {
+        assert!(WhiteSpace.contains_token(c), "{c:?}");
+        assert!(!HorizontalSpace.contains_token(c), "{c:?}");
+    }
+    for c in ['a', '\u{200b}', '\u{feff}'] {
+        assert!(!WhiteSpace.contains_token(c), "{c:?}");
+    }
+}
+
+#[test]
+fn general_category() {
+    assert_eq!(GeneralCategory::of('a'), GeneralCategory::LowercaseLetter);
+    assert_eq!(GeneralCategory::of('ǅ'), GeneralCategory::TitlecaseLetter);
+    assert_eq!(
+        GeneralCategory::of('\u{301}'),
+        GeneralCategory::NonspacingMark
+    );
+    assert_eq!(GeneralCategory::of('Ⅻ'), GeneralCategory::LetterNumber);
+    assert_eq!(
+        GeneralCategory::of('«'),
+        GeneralCategory::InitialPunctuation
+    );
+    assert_eq!(GeneralCategory::of('€'), GeneralCategory::CurrencySymbol);
+    assert_eq!(
+        GeneralCategory::of('\u{2028}'),
+        GeneralCategory::LineSeparator
+    );
+    assert_eq!(GeneralCategory::of('\u{e000}'), GeneralCategory::PrivateUse);
+    assert_eq!(GeneralCategory::of('\u{378}'), GeneralCategory::Unassigned);
+
+    assert_eq!(GeneralCategoryGroup::of('ǅ'), GeneralCategoryGroup::Letter);
+    assert_eq!(GeneralCategoryGroup::of('½'), GeneralCategoryGroup::Number);
+    assert_eq!(
+        GeneralCategoryGroup::of('-'),
+        GeneralCategoryGroup::Punctuation
+    );
+    assert_eq!(GeneralCategoryGroup::of('+'), GeneralCategoryGroup::Symbol);
+    assert_eq!(
+        GeneralCategoryGroup::of(' '),
+        GeneralCategoryGroup::Separator
+    );
+    assert_eq!(GeneralCategoryGroup::of('\0'), GeneralCategoryGroup::Other);
+
+    let mut parser = take_while::<_, _, ErrMode<InputError<_>>>(
+        1..,
+        (GeneralCategoryGroup::Letter, GeneralCategory::DecimalNumber),
+    );
+    assert_eq!(parser.parse_peek("x٣y½"), Ok(("½", "x٣y")));
+}
+
+#[test]
+fn identifier_str() {
+    assert_eq!(
+        identifier::<_, ErrMode<InputError<_>>>.parse_peek("größe_2 = 1"),
+        Ok((" = 1", "größe_2"))
+    );
+    assert_eq!(
+        identifier::<_, ErrMode<InputError<_>>>.parse_peek("e\u{301}t\u{e9}"),
+        Ok(("", "e\u{301}t\u{e9}"))
+    );
+    assert!(identifier::<_, ErrMode<InputError<_>>>
+        .parse_peek("_a")
+        .is_err());
+    assert!(identifier::<_, ErrMode<InputError<_>>>
+        .parse_peek("")
+        .is_err());
+}
+
+#[test]
+fn identifier_partial() {
+    assert_eq!(
+        identifier::<_, ErrMode<InputError<_>>>.parse_peek(Partial::new("ab;")),
+        Ok((Partial::new(";"), "ab"))
+    );
+    assert_eq!(
+        identifier::<_, ErrMode<InputError<_>>>.parse_peek(Partial::new("ab")),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+}
+
+#[test]
+fn character_classes() {
+    assert_eq!(
+        alpha1::<_, ErrMode<InputError<_>>>.parse_peek("Straße1"),
+        Ok(("1", "Straße"))
+    );
+    assert_eq!(
+        alphanumeric1::<_, ErrMode<InputError<_>>>.parse_peek("x²٣-"),
+        Ok(("-", "x²٣"))
+    );
+    assert_eq!(
+        digit1::<_, ErrMode<InputError<_>>>.parse_peek("٣٤²"),
+        Ok(("²", "٣٤"))
+    );
+    assert_eq!(
+        space1::<_, ErrMode<InputError<_>>>.parse_peek("\u{3000} \u{2028}"),
+        Ok(("\u{2028}", "\u{3000} "))
+    );
+    assert_eq!(
+        multispace0::<_, ErrMode<InputError<_>>>.parse_peek("\u{2028}\r\n x"),
+        Ok(("x", "\u{2028}\r\n "))
+    );
+    assert_eq!(
+        digit0::<_, ErrMode<InputError<_>>>.parse_peek(Partial::new("٣٤")),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+}
+
+#[test]
+fn graphemes_complete() {
+    assert_eq!(
+        grapheme::<_, ErrMode<InputError<_>>>.parse_peek("🇫🇷x"),
+        Ok(("x", "🇫🇷"))
+    );
+    assert_eq!(
+        grapheme::<_, ErrMode<InputError<_>>>.parse_peek("\r\n\n"),
+        Ok(("\n", "\r\n"))
+    );
+    assert!(grapheme::<_, ErrMode<InputError<_>>>
+        .parse_peek("")
+        .is_err());
+
+    assert_eq!(
+        take_graphemes::<_, ErrMode<InputError<_>>>(0..).parse_peek("ne\u{301}e"),
+        Ok(("", "ne\u{301}e"))
+    );
+    assert_eq!(
+        take_graphemes::<_, ErrMode<InputError<_>>>(1..=2).parse_peek("ne\u{301}e"),
+        Ok(("e", "ne\u{301}"))
+    );
+    assert!(take_graphemes::<_, ErrMode<InputError<_>>>(4..)
+        .parse_peek("ne\u{301}e")
+        .is_err());
+    assert_eq!(
+        take_graphemes::<_, ErrMode<InputError<_>>>(0).parse_peek("abc"),
+        Ok(("abc", ""))
+    );
+}
+
+#[test]
+fn graphemes_partial() {
+    assert_eq!(
+        grapheme::<_, ErrMode<InputError<_>>>.parse_peek(Partial::new("e\u{301}x")),
+        Ok((Partial::new("x"), "e\u{301}"))
+    );
+    assert_eq!(
+        grapheme::<_, ErrMode<InputError<_>>>.parse_peek(Partial::new("e")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert_eq!(
+        grapheme::<_, ErrMode<InputError<_>>>.parse_peek(Partial::new("")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert_eq!(
+        take_graphemes::<_, ErrMode<InputError<_>>>(0..).parse_peek(Partial::new("ab")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+}
+
+#[test]
+fn caseless_literal_str() {
+    let mut parser = literal::<_, _, ErrMode<InputError<_>>>(Caseless("SELECT"));
+    assert_eq!(parser.parse_peek("select *"), Ok((" *", "select")));
+    assert!(parser.parse_peek("selec").is_err());
+
+    let mut parser = literal::<_, _, ErrMode<InputError<_>>>(Caseless("ΟΔΟΣ"));
+    assert_eq!(parser.parse_peek("οδος 1"), Ok((" 1", "οδος")));
+    assert!(parser.parse_peek("οδoς 1").is_err());
+    // Final sigma
+    assert!(parser.parse_peek("οδός").is_err());
+    assert_eq!(
+        literal::<_, _, ErrMode<InputError<_>>>(Caseless("σ")).parse_peek("ς"),
+        Ok(("", "ς"))
+    );
+    // Kelvin sign
+    assert_eq!(
+        literal::<_, _, ErrMode<InputError<_>>>(Caseless("k")).parse_peek("\u{212a}"),
+        Ok(("", "\u{212a}"))
+    );
+    assert_eq!(
+        literal::<_, _, ErrMode<InputError<_>>>(Caseless('ǆ')).parse_peek("ǅx"),
+        Ok(("x", "ǅ"))
+    );
+}
+
+#[test]
+fn caseless_full_folding() {
+    let mut parser = literal::<_, _, ErrMode<InputError<_>>>(Caseless("straße"));
+    assert_eq!(parser.parse_peek("STRASSE"), Ok(("", "STRASSE")));
+    assert_eq!(parser.parse_peek("strasse"), Ok(("", "strasse")));
+    assert_eq!(parser.parse_peek("STRAẞE"), Ok(("", "STRAẞE")));
+    assert!(parser.parse_peek("STRASE").is_err());
+
+    // A folded character must match as a whole
+    let mut parser = literal::<_, _, ErrMode<InputError<_>>>(Caseless("s"));
+    assert!(parser.parse_peek("ß").is_err());
+    let mut parser = literal::<_, _, ErrMode<InputError<_>>>(Caseless("ﬁ"));
+    assert_eq!(parser.parse_peek("Fin"), Ok(("n", "Fi")));
+    assert!(parser.parse_peek("Fn").is_err());
+
+    // Not locale-specific
+    let mut parser = literal::<_, _, ErrMode<InputError<_>>>(Caseless("I"));
+    assert_eq!(parser.parse_peek("i"), Ok(("", "i")));
+    assert!(parser.parse_peek("ı").is_err());
+}
+
+#[test]
+fn caseless_simple_folding() {
+    let mut parser = literal::<_, _, ErrMode<InputError<_>>>(SimpleCaseless("straße"));
+    assert_eq!(parser.parse_peek("STRAẞE"), Ok(("", "STRAẞE")));
+    assert!(parser.parse_peek("STRASSE").is_err());
+    let mut parser = literal::<_, _, ErrMode<InputError<_>>>(SimpleCaseless("ﬁ"));
+    assert_eq!(parser.parse_peek("ﬁ"), Ok(("", "ﬁ")));
+    assert!(parser.parse_peek("fi").is_err());
+    assert_eq!(
+        literal::<_, _, ErrMode<InputError<_>>>(SimpleCaseless("Ω")).parse_peek("ωΩ"),
+        Ok(("Ω", "ω"))
+    );
+}
+
+#[test]
+fn caseless_literal_bstr() {
+    let mut parser = literal::<_, _, ErrMode<InputError<_>>>(Caseless("ÜBER"));
+    assert_eq!(
+        parser.parse_peek(BStr::new("über-")),
+        Ok((BStr::new("-"), "über".as_bytes()))
+    );
+    assert!(parser.parse_peek(BStr::new(b"\xfcber")).is_err());
+    assert!(parser.parse_peek(BStr::new(b"\xc3")).is_err());
+}
+
+#[test]
+fn caseless_literal_partial() {
+    let mut parser = literal::<_, _, ErrMode<InputError<_>>>(Caseless("STRASSE"));
+    assert_eq!(
+        parser.parse_peek(Partial::new("stra")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert_eq!(
+        parser.parse_peek(Partial::new("straße")),
+        Ok((Partial::new(""), "straße"))
+    );
+    assert!(parser.parse_peek(Partial::new("strb")).is_err());
+
+    let mut parser = literal::<_, _, ErrMode<InputError<_>>>(Caseless("Ü"));
+    // Truncated in the middle of a character
+    assert_eq!(
+        parser.parse_peek(Partial::new(BStr::new(b"\xc3"))),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert!(parser
+        .parse_peek(Partial::new(BStr::new(b"\xc3(")))
+        .is_err());
+}
+
+#[test]
+fn caseless_take_until() {
+    let mut parser = take_until::<_, _, ErrMode<InputError<_>>>(0.., Caseless("ẞ"));
+    assert_eq!(parser.parse_peek("Maße"), Ok(("ße", "Ma")));
+    assert_eq!(parser.parse_peek("MASSE"), Ok(("SSE", "MA")));
+    assert!(parser.parse_peek("Mase").is_err());
+
+    let mut parser = take_until::<_, _, ErrMode<InputError<_>>>(0.., SimpleCaseless('ẞ'));
+    assert_eq!(parser.parse_peek("MASSEß"), Ok(("ß", "MASSE")));
+
+    let input = LocatingSlice::new("Ärger: ÜBER über");
+    let mut parser = (
+        take_until::<_, _, ErrMode<InputError<_>>>(0.., Caseless("über")),
+        literal(Caseless("über")).span(),
+    );
+    assert_eq!(
+        parser.parse_peek(input).map(|(_, o)| o),
+        Ok(("Ärger: ", 8..13))
+    );
+
+    let mut parser = take_until::<_, _, ErrMode<InputError<_>>>(0.., Caseless("ok"));
+    assert_eq!(
+        parser.parse_peek(BStr::new(b"\xff\xc3\x96K!")),
+        Err(ErrMode::Backtrack(InputError::at(BStr::new(
+            b"\xff\xc3\x96K!"
+        ))))
+    );
+    assert_eq!(
+        parser.parse_peek(BStr::new(b"\xff\xc3\x96OK!")),
+        Ok((BStr::new("OK!"), &b"\xff\xc3\x96"[..]))
+    );
+    assert_eq!(
+        take_until::<_, _, ErrMode<InputError<_>>>(0.., Caseless("ok"))
+            .parse_peek(Partial::new("bo")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+}
+
+/// Regenerate `case_folding.rs`, run with `CASE_FOLDING_TXT` set to the path of the Unicode
+/// Character Database's `CaseFolding.txt`
+#[test]
+#[ignore = "writes `case_folding.rs` from `CaseFolding.txt`"]
+#[cfg(feature = "std")]
+fn generate_case_folding() {
+    use crate::lib::std::fmt::Write as _;
+
+    fn parse_char(code: &str) -> char {
+        u32::from_str_radix(code, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .unwrap_or_else(|| panic!("invalid code point `{code}`"))
+    }
+
+    fn escape(c: char) -> String {
+        match c {
+            '\0' => "\\0".to_owned(),
+            c => c.escape_default().to_string(),
+        }
+    }
+
+    let path = std::env::var("CASE_FOLDING_TXT")
+        .expect("`CASE_FOLDING_TXT` should be the path to `CaseFolding.txt`");
+    let data = std::fs::read_to_string(path).unwrap();
+    let version = data
+        .lines()
+        .next()
+        .and_then(|line| line.strip_prefix("# CaseFolding-"))
+        .and_then(|line| line.strip_suffix(".txt"))
+        .expect("`CaseFolding.txt` should start with its version");
+
+    let mut full = String::new();
+    let mut simple = String::new();
+    for line in data.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        // <code>; <status>; <mapping>;
+        let fields = line.split(';').map(str::trim).collect::<Vec<_>>();
+        let code = escape(parse_char(fields[0]));
+        let mut mapping = fields[2].split(' ').map(parse_char);
+        match fields[1] {
+            "C" | "F" => {
+                let padded = [0; 3].map(|_| escape(mapping.next().unwrap_or('\0')));
+                assert!(mapping.next().is_none(), "`{line}` folds to over 3 chars");
+                let padded = padded.map(|c| format!("'{c}'")).join(", ");
+                writeln!(full, "    ('{code}', [{padded}]),").unwrap();
+            }
+            "S" => {
+                let folded = escape(mapping.next().unwrap());
+                writeln!(simple, "    ('{code}', '{folded}'),").unwrap();
+            }
+            _ => {}
+        }
+    }
+
+    let generated = format!(
+        "//! Case folding data from `CaseFolding.txt` (Unicode {version})
+//!
+//! Generated from the `C`, `F` and `S` entries by `generate_case_folding` in `tests.rs`; do not
+//! edit by hand.
+
+/// Full case folding: entries with status `C` or `F`, padded with `'\\0'`
+pub(crate) static FULL: &[(char, [char; 3])] = &[
+{full}];
+
+/// Simple case folding where it differs from [`FULL`]: entries with status `S`
+pub(crate) static SIMPLE: &[(char, char)] = &[
+{simple}];
+"
+    );
+    let out = concat!(env!("CARGO_MANIFEST_DIR"), "/src/unicode/case_folding.rs");
+    std::fs::write(out, generated).unwrap();
+}
@@ -0,0 +1,297 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::{Attribute, Error, Expr, Ident, LitByteStr, LitStr, Pat, Result, Type};
+
+#[derive(Copy, Clone)]
+pub(crate) enum Endian {
+    Big,
+    Little,
+    Native,
+}
+
+impl Endian {
+    /// The validated `endian = ...` identifier, kept for its span
+    fn parse(meta: &ParseNestedMeta<'_>) -> Result<Ident> {
+        let ident: Ident = meta.value()?.parse()?;
+        if ident != "big" && ident != "little" && ident != "native" {
+            return Err(Error::new_spanned(
+                ident,
+                "expected `big`, `little` or `native`",
+            ));
+        }
+        Ok(ident)
+    }
+
+    pub(crate) fn new(ident: &Ident) -> Self {
+        if ident == "little" {
+            Self::Little
+        } else if ident == "native" {
+            Self::Native
+        } else {
+            Self::Big
+        }
+    }
+
+    pub(crate) fn to_tokens(self) -> TokenStream {
+        match self {
+            Self::Big => quote!(::winnow::binary::Endianness::Big),
+            Self::Little => quote!(::winnow::binary::Endianness::Little),
+            Self::Native => quote!(::winnow::binary::Endianness::Native),
+        }
+    }
+}
+
+/// `#[winnow(...)]` on a struct or enum
+#[derive(Default)]
+pub(crate) struct ContainerAttrs {
+    pub(crate) text: bool,
+    pub(crate) input: Option<Type>,
+    pub(crate) error: Option<Type>,
+    pub(crate) endian: Option<Ident>,
+    pub(crate) magic: Option<LitByteStr>,
+    pub(crate) tag: Option<Type>,
+    pub(crate) skip: Option<Expr>,
+    pub(crate) token: Token,
+}
+
+impl ContainerAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut parsed = Self::default();
+        for_each_meta(attrs, |meta| {
+            if meta.path.is_ident("text") {
+                if parsed.text {
+                    return Err(meta.error("duplicate `winnow` attribute"));
+                }
+                parsed.text = true;
+                Ok(())
+            } else if meta.path.is_ident("input") {
+                set(&mut parsed.input, &meta, meta.value()?.parse()?)
+            } else if meta.path.is_ident("error") {
+                set(&mut parsed.error, &meta, meta.value()?.parse()?)
+            } else if meta.path.is_ident("endian") {
+                set(&mut parsed.endian, &meta, Endian::parse(&meta)?)
+            } else if meta.path.is_ident("magic") {
+                set(&mut parsed.magic, &meta, meta.value()?.parse()?)
+            } else if meta.path.is_ident("tag") {
+                set(&mut parsed.tag, &meta, meta.value()?.parse()?)
+            } else if meta.path.is_ident("skip") {
+                set(&mut parsed.skip, &meta, meta.value()?.parse()?)
+            } else if parsed.token.parse(&meta)? {
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "unknown `winnow` attribute, expected `text`, `input`, `error`, `endian`, `magic`, `tag`, `skip`, `literal` or `keyword`",
+                ))
+            }
+        })?;
+        Ok(parsed)
+    }
+
+    /// The first binary-only attribute, as an error for text grammars
+    pub(crate) fn binary_only(&self) -> Option<Error> {
+        only(&self.endian, "endian", false)
+            .or_else(|| only(&self.magic, "magic", false))
+            .or_else(|| only(&self.tag, "tag", false))
+    }
+
+    /// The first text-only attribute, as an error for binary formats
+    pub(crate) fn text_only(&self) -> Option<Error> {
+        only(&self.skip, "skip", true).or_else(|| self.token.text_only())
+    }
+}
+
+/// `#[winnow(...)]` on an enum variant
+#[derive(Default)]
+pub(crate) struct VariantAttrs {
+    pub(crate) tag: Option<Pat>,
+    pub(crate) token: Token,
+    pub(crate) parser: Option<Expr>,
+}
+
+impl VariantAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut parsed = Self::default();
+        for_each_meta(attrs, |meta| {
+            if meta.path.is_ident("tag") {
+                set(&mut parsed.tag, &meta, Pat::parse_multi(meta.value()?)?)
+            } else if meta.path.is_ident("parser") {
+                set(&mut parsed.parser, &meta, meta.value()?.parse()?)
+            } else if parsed.token.parse(&meta)? {
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "unknown `winnow` attribute, expected `tag`, `literal`, `keyword` or `parser`",
+                ))
+            }
+        })?;
+        Ok(parsed)
+    }
+
+    pub(crate) fn binary_only(&self) -> Option<Error> {
+        only(&self.tag, "tag", false)
+    }
+
+    pub(crate) fn text_only(&self) -> Option<Error> {
+        only(&self.parser, "parser", true).or_else(|| self.token.text_only())
+    }
+}
+
+/// `#[winnow(...)]` on a field
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    pub(crate) endian: Option<Ident>,
+    pub(crate) magic: Option<LitByteStr>,
+    pub(crate) take: Option<Expr>,
+    pub(crate) length: Option<Type>,
+    pub(crate) count: Option<Expr>,
+    pub(crate) cond: Option<Expr>,
+    pub(crate) bits: Option<Expr>,
+    pub(crate) token: Token,
+    pub(crate) parser: Option<Expr>,
+    pub(crate) separator: Option<LitStr>,
+}
+
+impl FieldAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut parsed = Self::default();
+        for_each_meta(attrs, |meta| {
+            if meta.path.is_ident("endian") {
+                set(&mut parsed.endian, &meta, Endian::parse(&meta)?)
+            } else if meta.path.is_ident("magic") {
+                set(&mut parsed.magic, &meta, meta.value()?.parse()?)
+            } else if meta.path.is_ident("take") {
+                set(&mut parsed.take, &meta, meta.value()?.parse()?)
+            } else if meta.path.is_ident("length") {
+                set(&mut parsed.length, &meta, meta.value()?.parse()?)
+            } else if meta.path.is_ident("count") {
+                set(&mut parsed.count, &meta, meta.value()?.parse()?)
+            } else if meta.path.is_ident("cond") {
+                set(&mut parsed.cond, &meta, meta.value()?.parse()?)
+            } else if meta.path.is_ident("bits") {
+                set(&mut parsed.bits, &meta, meta.value()?.parse()?)
+            } else if meta.path.is_ident("parser") {
+                set(&mut parsed.parser, &meta, meta.value()?.parse()?)
+            } else if meta.path.is_ident("separator") {
+                set(&mut parsed.separator, &meta, meta.value()?.parse()?)
+            } else if parsed.token.parse(&meta)? {
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "unknown `winnow` attribute, expected `endian`, `magic`, `take`, `length`, `count`, `cond`, `bits`, `literal`, `keyword`, `parser` or `separator`",
+                ))
+            }
+        })?;
+        Ok(parsed)
+    }
+
+    /// How many of the mutually exclusive ways of parsing a value were given
+    pub(crate) fn value_kinds(&self) -> usize {
+        [
+            self.magic.is_some(),
+            self.take.is_some(),
+            self.length.is_some(),
+            self.count.is_some(),
+            self.bits.is_some(),
+        ]
+        .into_iter()
+        .filter(|kind| *kind)
+        .count()
+    }
+
+    pub(crate) fn binary_only(&self) -> Option<Error> {
+        only(&self.endian, "endian", false)
+            .or_else(|| only(&self.magic, "magic", false))
+            .or_else(|| only(&self.take, "take", false))
+            .or_else(|| only(&self.length, "length", false))
+            .or_else(|| only(&self.count, "count", false))
+            .or_else(|| only(&self.bits, "bits", false))
+    }
+
+    pub(crate) fn text_only(&self) -> Option<Error> {
+        only(&self.parser, "parser", true)
+            .or_else(|| only(&self.separator, "separator", true))
+            .or_else(|| self.token.text_only())
+    }
+}
+
+/// `literal = "..."` or `keyword = "..."`, matching text
+#[derive(Default)]
+pub(crate) struct Token {
+    pub(crate) literal: Option<LitStr>,
+    pub(crate) keyword: Option<LitStr>,
+}
+
+impl Token {
+    /// Whether `meta` was a token attribute
+    fn parse(&mut self, meta: &ParseNestedMeta<'_>) -> Result<bool> {
+        if meta.path.is_ident("literal") {
+            let literal: LitStr = meta.value()?.parse()?;
+            self.set(meta, literal, false)?;
+        } else if meta.path.is_ident("keyword") {
+            let keyword: LitStr = meta.value()?.parse()?;
+            self.set(meta, keyword, true)?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    fn set(&mut self, meta: &ParseNestedMeta<'_>, text: LitStr, keyword: bool) -> Result<()> {
+        if self.literal.is_some() || self.keyword.is_some() {
+            return Err(meta.error("only one of `literal` and `keyword` may be used"));
+        }
+        if text.value().is_empty() {
+            return Err(Error::new_spanned(text, "expected non-empty text"));
+        }
+        if keyword {
+            self.keyword = Some(text);
+        } else {
+            self.literal = Some(text);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn text(&self) -> Option<&LitStr> {
+        self.literal.as_ref().or(self.keyword.as_ref())
+    }
+
+    fn text_only(&self) -> Option<Error> {
+        only(&self.literal, "literal", true).or_else(|| only(&self.keyword, "keyword", true))
+    }
+}
+
+fn for_each_meta(
+    attrs: &[Attribute],
+    mut f: impl FnMut(ParseNestedMeta<'_>) -> Result<()>,
+) -> Result<()> {
+    for attr in attrs {
+        if attr.path().is_ident("winnow") {
+            attr.parse_nested_meta(&mut f)?;
+        }
+    }
+    Ok(())
+}
+
+fn set<T>(slot: &mut Option<T>, meta: &ParseNestedMeta<'_>, value: T) -> Result<()> {
+    if slot.is_some() {
+        return Err(meta.error("duplicate `winnow` attribute"));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+/// An error for an attribute only used by one kind of grammar
+fn only<T: quote::ToTokens>(value: &Option<T>, name: &str, text: bool) -> Option<Error> {
+    value.as_ref().map(|value| {
+        let (only, other) = if text {
+            ("text grammars", "binary formats")
+        } else {
+            ("binary formats", "text grammars")
+        };
+        Error::new_spanned(
+            value,
+            format!("`{name}` is only supported by {only}, not {other}"),
+        )
+    })
+}
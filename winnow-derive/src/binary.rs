@@ -0,0 +1,287 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Error, Fields, Ident, LitByteStr, Result, Type};
+
+use crate::attr::{ContainerAttrs, Endian, FieldAttrs, VariantAttrs};
+use crate::expand::{construct, field_local, generic_arg, is_unit, Cx};
+
+pub(crate) fn expand(
+    input: &DeriveInput,
+    attrs: &ContainerAttrs,
+    cx: &mut Cx,
+) -> Result<TokenStream> {
+    if let Some(unsupported) = attrs.text_only() {
+        return Err(unsupported);
+    }
+    let endian = attrs
+        .endian
+        .as_ref()
+        .map(Endian::new)
+        .unwrap_or(Endian::Big);
+    let (i, e) = (cx.input.clone(), cx.error.clone());
+    cx.bound(parse_quote! {
+        #i: ::winnow::stream::StreamIsPartial + ::winnow::stream::Stream<Token = u8>
+    });
+    cx.bound(parse_quote! {
+        #e: ::winnow::error::ParserError<#i>
+            + ::winnow::error::AddContext<#i, ::winnow::error::StrContext>
+    });
+
+    let magic = attrs.magic.as_ref().map(|magic| {
+        let parser = magic_parser(magic, None, cx);
+        quote! {
+            #parser.context(::winnow::error::StrContext::Label("magic")).parse_next(input)?;
+        }
+    });
+    match &input.data {
+        Data::Struct(data) => {
+            if let Some(tag) = &attrs.tag {
+                return Err(Error::new_spanned(tag, "`tag` is only supported on enums"));
+            }
+            let (stmts, construct) = fields(&data.fields, quote!(Self), endian, cx)?;
+            Ok(quote! {
+                #magic
+                #stmts
+                Ok(#construct)
+            })
+        }
+        Data::Enum(data) => {
+            let tag = attrs.tag.as_ref().ok_or_else(|| {
+                Error::new_spanned(
+                    &input.ident,
+                    "enums need a `#[winnow(tag = <integer type>)]` to select the variant",
+                )
+            })?;
+            let tag_parser = primitive(tag, endian)
+                .ok_or_else(|| Error::new_spanned(tag, "`tag` must be an integer type"))?;
+            let mut arms = TokenStream::new();
+            for variant in &data.variants {
+                let variant_attrs = VariantAttrs::parse(&variant.attrs)?;
+                if let Some(unsupported) = variant_attrs.text_only() {
+                    return Err(unsupported);
+                }
+                let pat = variant_attrs.tag.ok_or_else(|| {
+                    Error::new_spanned(
+                        &variant.ident,
+                        "variants need a `#[winnow(tag = <pattern>)]`",
+                    )
+                })?;
+                let ident = &variant.ident;
+                let label = ident.to_string();
+                let (stmts, construct) = fields(&variant.fields, quote!(Self::#ident), endian, cx)?;
+                arms.extend(quote! {
+                    #pat => (|input: &mut #i| -> ::winnow::Result<Self, #e> {
+                        #stmts
+                        Ok(#construct)
+                    })
+                    .context(::winnow::error::StrContext::Label(#label))
+                    .parse_next(input),
+                });
+            }
+            Ok(quote! {
+                #magic
+                let start = ::winnow::stream::Stream::checkpoint(input);
+                let tag = #tag_parser
+                    .context(::winnow::error::StrContext::Label("tag"))
+                    .parse_next(input)?;
+                #[allow(unreachable_patterns)]
+                match tag {
+                    #arms
+                    _ => {
+                        ::winnow::stream::Stream::reset(input, &start);
+                        ::winnow::combinator::fail
+                            .context(::winnow::error::StrContext::Label("tag"))
+                            .parse_next(input)
+                    }
+                }
+            })
+        }
+        Data::Union(data) => Err(Error::new_spanned(
+            data.union_token,
+            "`Parse` can't be derived for unions",
+        )),
+    }
+}
+
+/// Parses each field into a local of the same name, returning those statements and the
+/// expression constructing `path` from the locals
+fn fields(
+    fields: &Fields,
+    path: TokenStream,
+    endian: Endian,
+    cx: &mut Cx,
+) -> Result<(TokenStream, TokenStream)> {
+    let mut stmts = TokenStream::new();
+    let mut locals = Vec::new();
+    let mut bit_group = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        if let Some(unsupported) = attrs.text_only() {
+            return Err(unsupported);
+        }
+        if attrs.value_kinds() > 1 {
+            return Err(Error::new_spanned(
+                field,
+                "only one of `magic`, `take`, `length`, `count` and `bits` may be used",
+            ));
+        }
+        let (local, label) = field_local(i, field);
+        locals.push(local.clone());
+
+        if let Some(bits) = attrs.bits {
+            if let Some(cond) = &attrs.cond {
+                return Err(Error::new_spanned(cond, "`cond` can't be used with `bits`"));
+            }
+            bit_group.push((
+                local,
+                field.ty.clone(),
+                quote! {
+                    ::winnow::binary::bits::take::<_, _, usize, _>(#bits)
+                        .context(::winnow::error::StrContext::Label(#label))
+                },
+            ));
+            continue;
+        }
+        parse_bits(&mut stmts, &mut bit_group, cx);
+
+        let ty = &field.ty;
+        let value_ty = match &attrs.cond {
+            Some(cond) => generic_arg(ty, "Option")
+                .ok_or_else(|| Error::new_spanned(cond, "`cond` needs an `Option` field"))?,
+            None => ty,
+        };
+        let endian = attrs.endian.as_ref().map(Endian::new).unwrap_or(endian);
+        let parser = value_parser(&attrs, value_ty, endian, cx)?;
+        let parse = quote! {
+            #parser
+                .context(::winnow::error::StrContext::Label(#label))
+                .parse_next(input)?
+        };
+        stmts.extend(match &attrs.cond {
+            Some(cond) => quote! {
+                let #local: #ty = if #cond {
+                    ::core::option::Option::Some(#parse)
+                } else {
+                    ::core::option::Option::None
+                };
+            },
+            None => quote! {
+                let #local: #ty = #parse;
+            },
+        });
+    }
+    parse_bits(&mut stmts, &mut bit_group, cx);
+
+    let construct = construct(fields, path, &locals);
+    Ok((stmts, construct))
+}
+
+/// Parses the pending run of consecutive `bits` fields as one bit stream
+fn parse_bits(stmts: &mut TokenStream, group: &mut Vec<(Ident, Type, TokenStream)>, cx: &mut Cx) {
+    if group.is_empty() {
+        return;
+    }
+    let (i, e) = (cx.input.clone(), cx.error.clone());
+    cx.bound(parse_quote!(#i: ::core::clone::Clone));
+    cx.bound(parse_quote! {
+        #e: ::winnow::error::ParserError<(#i, usize)>
+            + ::winnow::error::AddContext<(#i, usize), ::winnow::error::StrContext>
+            + ::winnow::error::ErrorConvert<#e>
+    });
+    let mut locals = Vec::new();
+    let mut tys = Vec::new();
+    let mut parsers = Vec::new();
+    for (local, ty, parser) in group.drain(..) {
+        locals.push(local);
+        tys.push(ty);
+        parsers.push(parser);
+    }
+    stmts.extend(quote! {
+        let (#(#locals,)*): (#(#tys,)*) =
+            ::winnow::binary::bits::bits::<_, _, #e, #e, _>((#(#parsers,)*))
+                .parse_next(input)?;
+    });
+}
+
+/// The parser for a field's value, ignoring `cond`
+fn value_parser(attrs: &FieldAttrs, ty: &Type, endian: Endian, cx: &mut Cx) -> Result<TokenStream> {
+    if let Some(magic) = &attrs.magic {
+        return Ok(magic_parser(magic, Some(ty), cx));
+    }
+    if let Some(take) = &attrs.take {
+        slice_into(ty, cx);
+        return Ok(quote! {
+            ::winnow::token::take(#take).map(::core::convert::Into::into)
+        });
+    }
+    if let Some(length) = &attrs.length {
+        let length = primitive(length, endian)
+            .ok_or_else(|| Error::new_spanned(length, "`length` must be an integer type"))?;
+        return Ok(match generic_arg(ty, "Vec") {
+            Some(item) => {
+                let item = item_parser(item, endian, cx);
+                quote!(::winnow::binary::length_repeat(#length, #item))
+            }
+            None => {
+                slice_into(ty, cx);
+                quote! {
+                    ::winnow::binary::length_take(#length).map(::core::convert::Into::into)
+                }
+            }
+        });
+    }
+    if let Some(count) = &attrs.count {
+        let item = generic_arg(ty, "Vec")
+            .ok_or_else(|| Error::new_spanned(count, "`count` needs a `Vec` field"))?;
+        let item = item_parser(item, endian, cx);
+        return Ok(quote! {
+            ::winnow::combinator::repeat(::winnow::stream::ToUsize::to_usize(&(#count)), #item)
+        });
+    }
+    Ok(item_parser(ty, endian, cx))
+}
+
+/// Parses the bytes of `magic`, returning the matched slice as `ty`, if not `()`
+fn magic_parser(magic: &LitByteStr, ty: Option<&Type>, cx: &mut Cx) -> TokenStream {
+    let i = &cx.input;
+    cx.bound(parse_quote!(#i: ::winnow::stream::Compare<&'static [u8]>));
+    let literal = quote!(::winnow::token::literal(&#magic[..]));
+    match ty {
+        Some(ty) if !is_unit(ty) => {
+            slice_into(ty, cx);
+            quote!(#literal.map(::core::convert::Into::into))
+        }
+        _ => quote!(#literal.void()),
+    }
+}
+
+/// A primitive's `binary` parser, or the type's [`Parse`] implementation
+fn item_parser(ty: &Type, endian: Endian, cx: &mut Cx) -> TokenStream {
+    primitive(ty, endian).unwrap_or_else(|| cx.nested(ty))
+}
+
+fn primitive(ty: &Type, endian: Endian) -> Option<TokenStream> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    if path.qself.is_some() {
+        return None;
+    }
+    let ident = path.path.get_ident()?;
+    match ident.to_string().as_str() {
+        "u8" | "i8" => Some(quote!(::winnow::binary::#ident)),
+        "u16" | "u32" | "u64" | "u128" | "i16" | "i32" | "i64" | "i128" | "f32" | "f64" => {
+            let endian = endian.to_tokens();
+            Some(quote!(::winnow::binary::#ident(#endian)))
+        }
+        _ => None,
+    }
+}
+
+/// Require the input's slices to convert to `ty`
+fn slice_into(ty: &Type, cx: &mut Cx) {
+    let i = &cx.input;
+    cx.bound(parse_quote! {
+        <#i as ::winnow::stream::Stream>::Slice: ::core::convert::Into<#ty>
+    });
+}
@@ -0,0 +1,190 @@
+use proc_macro2::{Spacing, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse_quote, DeriveInput, Field, Fields, GenericArgument, GenericParam, Ident, Lifetime,
+    PathArguments, Result, Type, WherePredicate,
+};
+
+use crate::attr::ContainerAttrs;
+use crate::{binary, text};
+
+pub(crate) fn derive(input: &DeriveInput) -> Result<TokenStream> {
+    let attrs = ContainerAttrs::parse(&input.attrs)?;
+    let mut cx = Cx::new(input, &attrs);
+    let body = if attrs.text {
+        text::expand(input, &attrs, &mut cx)?
+    } else {
+        binary::expand(input, &attrs, &mut cx)?
+    };
+
+    let name = &input.ident;
+    let trace_name = name.to_string();
+    let mut generics = input.generics.clone();
+    for lifetime in [&attrs.input, &attrs.error]
+        .into_iter()
+        .flatten()
+        .flat_map(|ty| lifetimes(ty.to_token_stream()))
+    {
+        if !generics.lifetimes().any(|param| param.lifetime == lifetime) {
+            generics
+                .params
+                .insert(0, GenericParam::Lifetime(parse_quote!(#lifetime)));
+        }
+    }
+    if attrs.input.is_none() {
+        generics.params.push(parse_quote!(__I));
+    }
+    if attrs.error.is_none() {
+        generics.params.push(parse_quote!(__E));
+    }
+    generics.make_where_clause().predicates.extend(cx.bounds);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let (i, e) = (&cx.input, &cx.error);
+
+    Ok(quote! {
+        impl #impl_generics ::winnow::Parse<#i, #e> for #name #ty_generics #where_clause {
+            fn parse_next(input: &mut #i) -> ::winnow::Result<Self, #e> {
+                use ::winnow::Parser as _;
+                ::winnow::combinator::trace(#trace_name, |input: &mut #i| -> ::winnow::Result<Self, #e> {
+                    #body
+                })
+                .parse_next(input)
+            }
+        }
+    })
+}
+
+/// Code generation state shared by all fields of the type being derived
+pub(crate) struct Cx {
+    /// The input type, either `__I` or the container's `input`
+    pub(crate) input: TokenStream,
+    /// The error type, either `__E` or the container's `error`
+    pub(crate) error: TokenStream,
+    /// Type parameters of the impl, leaving out bounds that don't involve them
+    generic: Vec<Ident>,
+    bounds: Vec<WherePredicate>,
+}
+
+impl Cx {
+    fn new(input: &DeriveInput, attrs: &ContainerAttrs) -> Self {
+        Self {
+            input: attrs
+                .input
+                .as_ref()
+                .map(ToTokens::to_token_stream)
+                .unwrap_or_else(|| quote!(__I)),
+            error: attrs
+                .error
+                .as_ref()
+                .map(ToTokens::to_token_stream)
+                .unwrap_or_else(|| quote!(__E)),
+            generic: input
+                .generics
+                .type_params()
+                .map(|param| param.ident.clone())
+                .chain(attrs.input.is_none().then(|| format_ident!("__I")))
+                .chain(attrs.error.is_none().then(|| format_ident!("__E")))
+                .collect(),
+            bounds: Vec::new(),
+        }
+    }
+
+    /// Require `predicate` of the impl
+    ///
+    /// Bounds on concrete types are left for the compiler to check in the body as they would
+    /// otherwise hide associated types, like `<&str as Stream>::Slice`, and make recursive types,
+    /// like `Box<Self>`, require themselves.
+    pub(crate) fn bound(&mut self, predicate: WherePredicate) {
+        let tokens = predicate.to_token_stream();
+        if !mentions(tokens.clone(), &self.generic) {
+            return;
+        }
+        let key = tokens.to_string();
+        if !self
+            .bounds
+            .iter()
+            .any(|bound| bound.to_token_stream().to_string() == key)
+        {
+            self.bounds.push(predicate);
+        }
+    }
+
+    /// Parse `ty` with its own `Parse` implementation
+    pub(crate) fn nested(&mut self, ty: &Type) -> TokenStream {
+        let (i, e) = (self.input.clone(), self.error.clone());
+        self.bound(parse_quote!(#ty: ::winnow::Parse<#i, #e>));
+        quote!(<#ty as ::winnow::Parse<#i, #e>>::parse_next)
+    }
+}
+
+fn mentions(tokens: TokenStream, idents: &[Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => idents.contains(&ident),
+        TokenTree::Group(group) => mentions(group.stream(), idents),
+        _ => false,
+    })
+}
+
+fn lifetimes(tokens: TokenStream) -> Vec<Lifetime> {
+    let mut found = Vec::new();
+    let mut tokens = tokens.into_iter();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct)
+                if punct.as_char() == '\'' && punct.spacing() == Spacing::Joint =>
+            {
+                if let Some(TokenTree::Ident(ident)) = tokens.next() {
+                    if ident != "static" {
+                        found.push(Lifetime {
+                            apostrophe: punct.span(),
+                            ident,
+                        });
+                    }
+                }
+            }
+            TokenTree::Group(group) => found.extend(lifetimes(group.stream())),
+            _ => {}
+        }
+    }
+    found
+}
+
+/// The local a field is parsed into and its name for error context
+pub(crate) fn field_local(index: usize, field: &Field) -> (Ident, String) {
+    match &field.ident {
+        Some(ident) => (ident.clone(), ident.to_string()),
+        None => (format_ident!("__field{}", index), index.to_string()),
+    }
+}
+
+/// Construct `path` from the locals of its fields
+pub(crate) fn construct(fields: &Fields, path: TokenStream, locals: &[Ident]) -> TokenStream {
+    match fields {
+        Fields::Named(_) => quote!(#path { #(#locals),* }),
+        Fields::Unnamed(_) => quote!(#path(#(#locals),*)),
+        Fields::Unit => path,
+    }
+}
+
+/// `T` from `wrapper<T>`
+pub(crate) fn generic_arg<'t>(ty: &'t Type, wrapper: &str) -> Option<&'t Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
+
+pub(crate) fn is_unit(ty: &Type) -> bool {
+    matches!(ty, Type::Tuple(tuple) if tuple.elems.is_empty())
+}
@@ -0,0 +1,158 @@
+//! Derive macros for [`winnow`](https://docs.rs/winnow)
+//!
+//! Rather than depending on this crate directly, enable `winnow`'s `derive` feature and use
+//! `winnow::Parse`.
+
+#![warn(missing_docs)]
+
+mod attr;
+mod binary;
+mod expand;
+mod text;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Implement `winnow::Parse`, parsing each field in declaration order
+///
+/// The impl is generic over the input and error types unless given with
+/// `#[winnow(input = <type>, error = <type>)]`, which is needed when fields use parsers written for
+/// a specific input or error, or the type contains itself, like `Box<Self>`.
+/// Lifetimes in these types that the container doesn't declare are added to the impl.
+///
+/// # Binary formats
+///
+/// Primitive integers and floats use the matching [`winnow::binary`](https://docs.rs/winnow/latest/winnow/binary/index.html)
+/// parser and any other field type is parsed with its own `Parse` implementation.
+/// Errors are given a `StrContext::Label` naming the field that failed.
+///
+/// Container attributes:
+/// - `#[winnow(endian = big | little | native)]`: byte order for all fields, defaulting to `big`
+/// - `#[winnow(magic = b"...")]`: bytes that must precede the fields (or the tag)
+/// - `#[winnow(tag = <integer type>)]`: required for enums, read before selecting the variant
+///
+/// Variant attributes:
+/// - `#[winnow(tag = <pattern>)]`: the tag values selecting this variant, where `_` matches any
+///   tag not claimed by an earlier variant
+///
+/// Field attributes, where expressions may refer to earlier fields by name:
+/// - `#[winnow(endian = big | little | native)]`: override the container's byte order
+/// - `#[winnow(magic = b"...")]`: bytes that must be present, stored in a `()` or slice field
+/// - `#[winnow(take = <expr>)]`: a slice of `<expr>` bytes
+/// - `#[winnow(length = <integer type>)]`: a length prefix followed by that many bytes (slice
+///   fields) or items (`Vec` fields)
+/// - `#[winnow(count = <expr>)]`: a `Vec` of `<expr>` items
+/// - `#[winnow(cond = <expr>)]`: an `Option` field that is only parsed when `<expr>` is `true`
+/// - `#[winnow(bits = <expr>)]`: an integer of `<expr>` bits, where consecutive `bits` fields
+///   are read from the same bit stream, starting at the most significant bit
+///
+/// `bits` fields require an error type that works with any input, like `ContextError`.
+///
+/// # Text grammars
+///
+/// With `#[winnow(text)]`, fields are parsed with a `parser` or their own `Parse` implementation.
+/// `Option` fields are optional and `Vec` fields repeat zero or more times.
+///
+/// An enum peeks at the next character to select among the variants with a `literal` or
+/// `keyword` that starts with it, trying longer text first, and falls back to the remaining
+/// variants in declaration order.
+/// Once a `literal` or `keyword` matches, the rest of the variant is committed with `cut_err`,
+/// requiring a `ModalError` like `ErrMode<ContextError>`.
+/// If no variant matches, the error is labeled with a `StrContext::Expected` for each variant.
+///
+/// Container attributes:
+/// - `#[winnow(text)]`: derive a text grammar
+/// - `#[winnow(skip = <parser>)]`: run before each field and token, like `multispace0`
+/// - `#[winnow(literal = "...")]` or `#[winnow(keyword = "...")]`: text that must precede the
+///   fields, committing to the rest
+///
+/// Variant attributes:
+/// - `#[winnow(literal = "...")]`: text selecting this variant
+/// - `#[winnow(keyword = "...")]`: text selecting this variant if not followed by an identifier
+///   character, so `keyword = "in"` won't match `int`
+/// - `#[winnow(parser = <parser>)]`: parse the variant's only field, if any
+///
+/// Field attributes, where expressions may refer to earlier fields by name:
+/// - `#[winnow(literal = "...")]` or `#[winnow(keyword = "...")]`: text that must be present,
+///   stored in a `()` field
+/// - `#[winnow(parser = <parser>)]`: parse the field, or its items for `Option` and `Vec` fields
+/// - `#[winnow(separator = "...")]`: text between the items of a `Vec` field
+/// - `#[winnow(cond = <expr>)]`: an `Option` field that is only parsed when `<expr>` is `true`
+///
+/// # Examples
+///
+/// ```rust
+/// use winnow::prelude::*;
+/// use winnow::Parse;
+///
+/// #[derive(Parse, Debug, PartialEq)]
+/// #[winnow(magic = b"PKT", endian = little)]
+/// struct Packet<'i> {
+///     #[winnow(bits = 4)]
+///     version: u8,
+///     #[winnow(bits = 4)]
+///     flags: u8,
+///     len: u16,
+///     #[winnow(take = len)]
+///     payload: &'i [u8],
+///     #[winnow(cond = flags & 0x1 != 0)]
+///     checksum: Option<u32>,
+/// }
+///
+/// fn packet<'i>(input: &mut &'i [u8]) -> ModalResult<Packet<'i>> {
+///     Packet::parse_next(input)
+/// }
+///
+/// let input = &[b'P', b'K', b'T', 0x21, 0x02, 0x00, 0xaa, 0xbb, 0x78, 0x56, 0x34, 0x12][..];
+/// assert_eq!(
+///     packet.parse_peek(input),
+///     Ok((
+///         &[][..],
+///         Packet {
+///             version: 2,
+///             flags: 1,
+///             len: 2,
+///             payload: &[0xaa, 0xbb],
+///             checksum: Some(0x12345678),
+///         }
+///     ))
+/// );
+/// ```
+///
+/// ```rust
+/// use winnow::ascii::{alpha1, dec_uint, multispace0};
+/// use winnow::error::{ContextError, ErrMode};
+/// use winnow::prelude::*;
+/// use winnow::Parse;
+///
+/// #[derive(Parse, Debug, PartialEq)]
+/// #[winnow(text, skip = multispace0, input = &'i str, error = ErrMode<ContextError>)]
+/// enum Value<'i> {
+///     #[winnow(keyword = "null")]
+///     Null,
+///     #[winnow(literal = "[")]
+///     List(
+///         #[winnow(separator = ",")] Vec<Value<'i>>,
+///         #[winnow(literal = "]")] (),
+///     ),
+///     #[winnow(parser = dec_uint)]
+///     Number(u32),
+///     #[winnow(parser = alpha1)]
+///     Word(&'i str),
+/// }
+///
+/// assert_eq!(
+///     Value::parse_next.parse("[1, null, nullable]"),
+///     Ok(Value::List(
+///         vec![Value::Number(1), Value::Null, Value::Word("nullable")],
+///         (),
+///     ))
+/// );
+/// ```
+#[proc_macro_derive(Parse, attributes(winnow))]
+pub fn derive_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand::derive(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
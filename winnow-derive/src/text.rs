@@ -0,0 +1,413 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Data, DataEnum, DeriveInput, Error, Expr, Fields, Result, Type};
+
+use crate::attr::{ContainerAttrs, FieldAttrs, Token, VariantAttrs};
+use crate::expand::{construct, field_local, generic_arg, is_unit, Cx};
+
+/// The most parsers a single `alt` tuple accepts
+const MAX_ALT: usize = 21;
+
+pub(crate) fn expand(
+    input: &DeriveInput,
+    attrs: &ContainerAttrs,
+    cx: &mut Cx,
+) -> Result<TokenStream> {
+    if let Some(unsupported) = attrs.binary_only() {
+        return Err(unsupported);
+    }
+    let (i, e) = (cx.input.clone(), cx.error.clone());
+    cx.bound(parse_quote! {
+        #i: ::winnow::stream::StreamIsPartial
+            + ::winnow::stream::Stream
+            + ::winnow::stream::Compare<&'static str>
+    });
+    cx.bound(parse_quote! {
+        <#i as ::winnow::stream::Stream>::Token: ::winnow::stream::AsChar + ::core::clone::Clone
+    });
+    cx.bound(parse_quote! {
+        #e: ::winnow::error::ParserError<#i>
+            + ::winnow::error::AddContext<#i, ::winnow::error::StrContext>
+    });
+
+    let skip = attrs.skip.as_ref();
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (stmts, construct) = fields(&data.fields, quote!(Self), None, skip, cx)?;
+            quote! {
+                #stmts
+                Ok(#construct)
+            }
+        }
+        Data::Enum(data) => variants(data, skip, cx)?,
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "`Parse` can't be derived for unions",
+            ));
+        }
+    };
+
+    // Everything after the container's literal or keyword is committed
+    Ok(match token_parser(&attrs.token, true, cx) {
+        Some(prefix) => {
+            cx.bound(parse_quote!(#e: ::winnow::error::ModalError));
+            let skip = skip_stmt(skip);
+            quote! {
+                #skip
+                #prefix.parse_next(input)?;
+                ::winnow::combinator::cut_err(|input: &mut #i| -> ::winnow::Result<Self, #e> {
+                    #body
+                })
+                .parse_next(input)
+            }
+        }
+        None => body,
+    })
+}
+
+/// Selects a variant by the first character of its literal or keyword, falling back to
+/// variants without one
+fn variants(data: &DataEnum, skip: Option<&Expr>, cx: &mut Cx) -> Result<TokenStream> {
+    let (i, e) = (cx.input.clone(), cx.error.clone());
+    let mut defs = TokenStream::new();
+    let mut groups: Vec<(char, Vec<(usize, TokenStream)>)> = Vec::new();
+    let mut fallbacks = Vec::new();
+    let mut expected = Vec::new();
+    for (index, variant) in data.variants.iter().enumerate() {
+        let attrs = VariantAttrs::parse(&variant.attrs)?;
+        if let Some(unsupported) = attrs.binary_only() {
+            return Err(unsupported);
+        }
+        if let (Some(parser), Some(_)) = (&attrs.parser, attrs.token.text()) {
+            return Err(Error::new_spanned(
+                parser,
+                "only one of `literal`, `keyword` and `parser` may be used",
+            ));
+        }
+        let ident = &variant.ident;
+        let label = ident.to_string();
+        let local = format_ident!("__variant{}", index);
+
+        let (stmts, construct) = match (&attrs.parser, &variant.fields) {
+            (Some(parser), Fields::Unit) => (
+                quote! {
+                    #parser.void().parse_next(input)?;
+                },
+                quote!(Self::#ident),
+            ),
+            (parser, variant_fields) => fields(
+                variant_fields,
+                quote!(Self::#ident),
+                parser.as_ref(),
+                skip,
+                cx,
+            )?,
+        };
+        let body = if variant.fields.is_empty() {
+            quote! {
+                #stmts
+                Ok(#construct)
+            }
+        } else {
+            quote! {
+                (|input: &mut #i| -> ::winnow::Result<Self, #e> {
+                    #stmts
+                    Ok(#construct)
+                })
+                .context(::winnow::error::StrContext::Label(#label))
+                .parse_next(input)
+            }
+        };
+
+        match token_parser(&attrs.token, false, cx) {
+            Some(prefix) => {
+                let text = attrs.token.text().expect("token_parser requires text");
+                let first = text.value().chars().next().expect("tokens are non-empty");
+                let body = if variant.fields.is_empty() {
+                    body
+                } else {
+                    cx.bound(parse_quote!(#e: ::winnow::error::ModalError));
+                    quote! {
+                        ::winnow::combinator::cut_err(move |input: &mut #i| -> ::winnow::Result<Self, #e> {
+                            #body
+                        })
+                        .parse_next(input)
+                    }
+                };
+                defs.extend(quote! {
+                    let #local = move |input: &mut #i| -> ::winnow::Result<Self, #e> {
+                        #prefix.parse_next(input)?;
+                        #body
+                    };
+                });
+                let len = text.value().len();
+                match groups.iter_mut().find(|(c, _)| *c == first) {
+                    Some((_, group)) => group.push((len, quote!(#local))),
+                    None => groups.push((first, vec![(len, quote!(#local))])),
+                }
+                expected.push(quote! {
+                    ::winnow::error::StrContextValue::StringLiteral(#text)
+                });
+            }
+            None => {
+                defs.extend(quote! {
+                    let #local = move |input: &mut #i| -> ::winnow::Result<Self, #e> {
+                        #body
+                    };
+                });
+                fallbacks.push(quote!(#local));
+                expected.push(quote! {
+                    ::winnow::error::StrContextValue::Description(#label)
+                });
+            }
+        }
+    }
+
+    fallbacks.push(quote!(__expected));
+    let fallback = alt_tree(fallbacks);
+    let skip = skip_stmt(skip);
+    let dispatch = if groups.is_empty() {
+        quote!(__fallback(input))
+    } else {
+        let arms = groups.into_iter().map(|(first, mut group)| {
+            // Longer tokens first so `<=` isn't shadowed by `<`
+            group.sort_by(|(a, _), (b, _)| b.cmp(a));
+            let mut parsers: Vec<_> = group.into_iter().map(|(_, parser)| parser).collect();
+            parsers.push(quote!(__fallback));
+            let parser = alt_tree(parsers);
+            let first = Literal::character(first);
+            quote!(::core::option::Option::Some(#first) => #parser,)
+        });
+        quote! {
+            ::winnow::combinator::dispatch! {
+                ::winnow::combinator::opt(::winnow::combinator::peek(::winnow::token::any)).map(
+                    |token: ::core::option::Option<<#i as ::winnow::stream::Stream>::Token>| {
+                        token.map(::winnow::stream::AsChar::as_char)
+                    }
+                );
+                #(#arms)*
+                // A copy, as `dispatch!` needs a mutable parser
+                _ => { __fallback },
+            }
+            .parse_next(input)
+        }
+    };
+    Ok(quote! {
+        #defs
+        let __expected = move |input: &mut #i| -> ::winnow::Result<Self, #e> {
+            ::winnow::combinator::fail
+                #(.context(::winnow::error::StrContext::Expected(#expected)))*
+                .parse_next(input)
+        };
+        let __fallback = move |input: &mut #i| -> ::winnow::Result<Self, #e> {
+            let mut parser = #fallback;
+            parser.parse_next(input)
+        };
+        #skip
+        #dispatch
+    })
+}
+
+/// Parses each field into a local of the same name, returning those statements and the
+/// expression constructing `path` from the locals
+///
+/// `parser` is a variant's `parser`, for its only field.
+fn fields(
+    fields: &Fields,
+    path: TokenStream,
+    parser: Option<&Expr>,
+    skip: Option<&Expr>,
+    cx: &mut Cx,
+) -> Result<(TokenStream, TokenStream)> {
+    if let Some(parser) = parser {
+        if fields.len() != 1 {
+            return Err(Error::new_spanned(
+                parser,
+                "a variant's `parser` requires it to have at most one field",
+            ));
+        }
+    }
+    let mut stmts = TokenStream::new();
+    let mut locals = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let mut attrs = FieldAttrs::parse(&field.attrs)?;
+        if let Some(unsupported) = attrs.binary_only() {
+            return Err(unsupported);
+        }
+        if let Some(parser) = parser {
+            if let Some(field_parser) = &attrs.parser {
+                return Err(Error::new_spanned(
+                    field_parser,
+                    "`parser` was already given for the variant",
+                ));
+            }
+            attrs.parser = Some(parser.clone());
+        }
+        let (local, label) = field_local(i, field);
+        locals.push(local.clone());
+
+        let ty = &field.ty;
+        let value_ty = match &attrs.cond {
+            Some(cond) => generic_arg(ty, "Option")
+                .ok_or_else(|| Error::new_spanned(cond, "`cond` needs an `Option` field"))?,
+            None => ty,
+        };
+        let parse = match token_parser(&attrs.token, true, cx) {
+            Some(token) => {
+                if let Some(parser) = &attrs.parser {
+                    return Err(Error::new_spanned(
+                        parser,
+                        "only one of `literal`, `keyword` and `parser` may be used",
+                    ));
+                }
+                if !is_unit(value_ty) {
+                    return Err(Error::new_spanned(
+                        value_ty,
+                        "`literal` and `keyword` need a `()` field",
+                    ));
+                }
+                quote!(#token.parse_next(input)?)
+            }
+            None => {
+                let parser = value_parser(&attrs, value_ty, skip, cx)?;
+                quote! {
+                    #parser
+                        .context(::winnow::error::StrContext::Label(#label))
+                        .parse_next(input)?
+                }
+            }
+        };
+        let skip = skip_stmt(skip);
+        stmts.extend(match &attrs.cond {
+            Some(cond) => quote! {
+                let #local: #ty = if #cond {
+                    #skip
+                    ::core::option::Option::Some(#parse)
+                } else {
+                    ::core::option::Option::None
+                };
+            },
+            None => quote! {
+                #skip
+                let #local: #ty = #parse;
+            },
+        });
+    }
+
+    Ok((stmts, construct(fields, path, &locals)))
+}
+
+/// The parser for a field's value, ignoring `cond`
+///
+/// `Vec` fields repeat their item, optionally with a `separator`, and `Option` fields make it
+/// optional.
+fn value_parser(
+    attrs: &FieldAttrs,
+    ty: &Type,
+    skip: Option<&Expr>,
+    cx: &mut Cx,
+) -> Result<TokenStream> {
+    let item = |ty: &Type, cx: &mut Cx| {
+        let parser = match &attrs.parser {
+            Some(parser) => quote!(#parser),
+            None => cx.nested(ty),
+        };
+        match skip {
+            Some(skip) => quote!(::winnow::combinator::preceded(#skip, #parser)),
+            None => parser,
+        }
+    };
+    if let Some(item_ty) = generic_arg(ty, "Vec") {
+        let item = item(item_ty, cx);
+        return Ok(match &attrs.separator {
+            Some(separator) => {
+                let separator = token_parser(
+                    &Token {
+                        literal: Some(separator.clone()),
+                        keyword: None,
+                    },
+                    true,
+                    cx,
+                )
+                .expect("separator is a literal");
+                let separator = match skip {
+                    Some(skip) => quote!(::winnow::combinator::preceded(#skip, #separator)),
+                    None => separator,
+                };
+                quote!(::winnow::combinator::separated(0.., #item, #separator))
+            }
+            None => quote!(::winnow::combinator::repeat(0.., #item)),
+        });
+    }
+    if let Some(separator) = &attrs.separator {
+        return Err(Error::new_spanned(
+            separator,
+            "`separator` needs a `Vec` field",
+        ));
+    }
+    if attrs.cond.is_none() {
+        if let Some(item_ty) = generic_arg(ty, "Option") {
+            let item = item(item_ty, cx);
+            return Ok(quote!(::winnow::combinator::opt(#item)));
+        }
+    }
+    Ok(match &attrs.parser {
+        Some(parser) => quote!(#parser),
+        None => cx.nested(ty),
+    })
+}
+
+/// Matches a `literal` or a `keyword`, which can't be followed by more of an identifier
+///
+/// `expected` labels failures with the text; enums instead report every variant's text at once.
+fn token_parser(token: &Token, expected: bool, cx: &Cx) -> Option<TokenStream> {
+    let i = &cx.input;
+    let (text, parser) = match (&token.literal, &token.keyword) {
+        (Some(literal), _) => (literal, quote!(::winnow::token::literal(#literal))),
+        (None, Some(keyword)) => (
+            keyword,
+            quote! {
+                ::winnow::combinator::terminated(
+                    ::winnow::token::literal(#keyword),
+                    ::winnow::combinator::not(::winnow::token::any.verify(
+                        |c: &<#i as ::winnow::stream::Stream>::Token| {
+                            ::winnow::stream::AsChar::is_alphanum(c.clone())
+                                || ::winnow::stream::AsChar::as_char(c.clone()) == '_'
+                        },
+                    )),
+                )
+            },
+        ),
+        (None, None) => return None,
+    };
+    Some(if expected {
+        quote! {
+            #parser.void().context(::winnow::error::StrContext::Expected(
+                ::winnow::error::StrContextValue::StringLiteral(#text),
+            ))
+        }
+    } else {
+        quote!(#parser.void())
+    })
+}
+
+fn skip_stmt(skip: Option<&Expr>) -> Option<TokenStream> {
+    skip.map(|skip| {
+        quote! {
+            #skip.void().parse_next(input)?;
+        }
+    })
+}
+
+/// `alt` over `parsers`, nesting to stay within the tuple sizes `alt` supports
+fn alt_tree(mut parsers: Vec<TokenStream>) -> TokenStream {
+    if parsers.len() == 1 {
+        return parsers.pop().expect("checked length");
+    }
+    if parsers.len() > MAX_ALT {
+        let rest = parsers.split_off(MAX_ALT - 1);
+        parsers.push(alt_tree(rest));
+    }
+    quote!(::winnow::combinator::alt((#(#parsers,)*)))
+}
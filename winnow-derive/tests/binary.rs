@@ -0,0 +1,203 @@
+use winnow::error::{ContextError, ErrMode, InputError, Needed, StrContext};
+use winnow::prelude::*;
+use winnow::Parse;
+use winnow::Partial;
+
+#[derive(Parse, Debug, PartialEq)]
+#[winnow(magic = b"\x89HDR", endian = little)]
+struct Header<'i> {
+    version: u8,
+    #[winnow(endian = big)]
+    flags: u16,
+    size: u32,
+    #[winnow(take = size)]
+    body: &'i [u8],
+}
+
+#[test]
+fn struct_fields() {
+    let input = &b"\x89HDR\x01\x00\x02\x03\x00\x00\x00abcrest"[..];
+    assert_eq!(
+        Header::parse_next.parse_peek(input),
+        Ok::<_, InputError<_>>((
+            &b"rest"[..],
+            Header {
+                version: 1,
+                flags: 2,
+                size: 3,
+                body: b"abc",
+            }
+        ))
+    );
+
+    let bad_magic = &b"\x89HDX\x01"[..];
+    assert!(Header::parse_next
+        .parse_peek(bad_magic)
+        .map_err(|_: InputError<_>| ())
+        .is_err());
+}
+
+#[derive(Parse, Debug, PartialEq)]
+struct Lists<'i> {
+    #[winnow(length = u8)]
+    name: &'i [u8],
+    #[winnow(length = u16)]
+    words: Vec<u16>,
+    count: u8,
+    #[winnow(count = count)]
+    items: Vec<i8>,
+    #[winnow(magic = b"END")]
+    end: (),
+}
+
+#[test]
+fn length_and_count() {
+    let input = &b"\x02ab\x00\x02\x00\x01\x00\x02\x03\xff\x00\x01END"[..];
+    assert_eq!(
+        Lists::parse_next.parse_peek(input),
+        Ok::<_, InputError<_>>((
+            &b""[..],
+            Lists {
+                name: b"ab",
+                words: vec![1, 2],
+                count: 3,
+                items: vec![-1, 0, 1],
+                end: (),
+            }
+        ))
+    );
+}
+
+#[derive(Parse, Debug, PartialEq)]
+struct Flags {
+    #[winnow(bits = 1)]
+    present: u8,
+    #[winnow(bits = 3)]
+    kind: u8,
+    #[winnow(bits = 12)]
+    id: u16,
+    #[winnow(cond = present == 1)]
+    extra: Option<u8>,
+}
+
+#[test]
+fn bits_and_cond() {
+    let input = &b"\xa1\x23\x42"[..];
+    assert_eq!(
+        Flags::parse_next.parse_peek(input),
+        Ok::<_, ContextError>((
+            &b""[..],
+            Flags {
+                present: 1,
+                kind: 2,
+                id: 0x123,
+                extra: Some(0x42),
+            }
+        ))
+    );
+
+    let input = &b"\x21\x23\x42"[..];
+    assert_eq!(
+        Flags::parse_next.parse_peek(input),
+        Ok::<_, ContextError>((
+            &b"\x42"[..],
+            Flags {
+                present: 0,
+                kind: 2,
+                id: 0x123,
+                extra: None,
+            }
+        ))
+    );
+}
+
+#[derive(Parse, Debug, PartialEq)]
+struct Point(i16, i16);
+
+#[derive(Parse, Debug, PartialEq)]
+#[winnow(tag = u8)]
+enum Shape {
+    #[winnow(tag = 0)]
+    Empty,
+    #[winnow(tag = 1)]
+    Dot(Point),
+    #[winnow(tag = 2 | 3)]
+    Line { from: Point, to: Point },
+}
+
+#[derive(Parse, Debug, PartialEq)]
+#[winnow(tag = u16, endian = little)]
+enum Record {
+    #[winnow(tag = 1)]
+    Known(u8),
+    #[winnow(tag = _)]
+    Other,
+}
+
+#[test]
+fn enum_tags() {
+    assert_eq!(
+        Shape::parse_next.parse_peek(&b"\x00"[..]),
+        Ok::<_, InputError<_>>((&b""[..], Shape::Empty))
+    );
+    assert_eq!(
+        Shape::parse_next.parse_peek(&b"\x01\x00\x01\xff\xff"[..]),
+        Ok::<_, InputError<_>>((&b""[..], Shape::Dot(Point(1, -1))))
+    );
+    assert_eq!(
+        Shape::parse_next.parse_peek(&b"\x03\x00\x01\x00\x02\x00\x03\x00\x04"[..]),
+        Ok::<_, InputError<_>>((
+            &b""[..],
+            Shape::Line {
+                from: Point(1, 2),
+                to: Point(3, 4),
+            }
+        ))
+    );
+    assert_eq!(
+        Shape::parse_next.parse_peek(&b"\x04"[..]),
+        Err(InputError::at(&b"\x04"[..]))
+    );
+
+    assert_eq!(
+        Record::parse_next.parse_peek(&b"\x01\x00\x05"[..]),
+        Ok::<_, InputError<_>>((&b""[..], Record::Known(5)))
+    );
+    assert_eq!(
+        Record::parse_next.parse_peek(&b"\x02\x00\x05"[..]),
+        Ok::<_, InputError<_>>((&b"\x05"[..], Record::Other))
+    );
+}
+
+#[test]
+fn field_context() {
+    let input = &b"\x01\x00\x01\xff"[..];
+    let err = Shape::parse_next
+        .parse_peek(input)
+        .map_err(|e: ContextError| e)
+        .unwrap_err();
+    let labels = err.context().cloned().collect::<Vec<_>>();
+    assert_eq!(
+        labels,
+        vec![
+            StrContext::Label("1"),
+            StrContext::Label("0"),
+            StrContext::Label("Dot"),
+        ]
+    );
+}
+
+#[test]
+fn partial() {
+    let input = Partial::new(&b"\x89HDR\x01\x00\x02\x03\x00\x00\x00ab"[..]);
+    assert_eq!(
+        Header::parse_next.parse_peek(input),
+        Err::<(_, Header<'_>), _>(ErrMode::<InputError<_>>::Incomplete(Needed::new(1)))
+    );
+
+    let input = Partial::new(&b"\x02\x00"[..]);
+    assert_eq!(
+        Shape::parse_next.parse_peek(input),
+        Err::<(_, Shape), _>(ErrMode::<InputError<_>>::Incomplete(Needed::new(1)))
+    );
+}
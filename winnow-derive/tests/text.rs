@@ -0,0 +1,223 @@
+use winnow::ascii::{alpha1, dec_int, multispace0};
+use winnow::error::{ContextError, ErrMode, InputError, Needed, StrContext, StrContextValue};
+use winnow::prelude::*;
+use winnow::Parse;
+use winnow::Partial;
+
+fn ident(input: &mut &str) -> ModalResult<String> {
+    alpha1.map(String::from).parse_next(input)
+}
+
+#[derive(Parse, Debug, PartialEq)]
+#[winnow(text, skip = multispace0, input = &'i str, error = ErrMode<ContextError>)]
+enum Stmt {
+    #[winnow(keyword = "let")]
+    Let {
+        #[winnow(parser = ident)]
+        name: String,
+        #[winnow(literal = "=")]
+        eq: (),
+        value: Expr,
+    },
+    #[winnow(keyword = "print")]
+    Print(#[winnow(separator = ",")] Vec<Expr>),
+    #[winnow(literal = ";")]
+    Empty,
+    Expr(Expr),
+}
+
+#[derive(Parse, Debug, PartialEq)]
+#[winnow(text, skip = multispace0, input = &'i str, error = ErrMode<ContextError>)]
+enum Expr {
+    #[winnow(literal = "(")]
+    Group(Box<Expr>, #[winnow(literal = ")")] ()),
+    #[winnow(literal = "-")]
+    Neg(Box<Expr>),
+    #[winnow(parser = dec_int)]
+    Int(i64),
+    #[winnow(parser = ident)]
+    Var(String),
+}
+
+fn stmt(input: &mut &str) -> ModalResult<Stmt> {
+    Stmt::parse_next(input)
+}
+
+#[test]
+fn variants() {
+    assert_eq!(
+        stmt.parse_peek("let x = -(1)"),
+        Ok((
+            "",
+            Stmt::Let {
+                name: "x".into(),
+                eq: (),
+                value: Expr::Neg(Box::new(Expr::Group(Box::new(Expr::Int(1)), ()))),
+            }
+        ))
+    );
+    assert_eq!(
+        stmt.parse_peek("print 1, y ,2"),
+        Ok((
+            "",
+            Stmt::Print(vec![Expr::Int(1), Expr::Var("y".into()), Expr::Int(2)])
+        ))
+    );
+    assert_eq!(stmt.parse_peek("  ;"), Ok(("", Stmt::Empty)));
+    assert_eq!(stmt.parse_peek("print"), Ok(("", Stmt::Print(vec![]))));
+}
+
+#[test]
+fn keyword_boundary() {
+    assert_eq!(
+        stmt.parse_peek("letter"),
+        Ok(("", Stmt::Expr(Expr::Var("letter".into()))))
+    );
+    assert_eq!(
+        stmt.parse_peek("printer"),
+        Ok(("", Stmt::Expr(Expr::Var("printer".into()))))
+    );
+}
+
+#[test]
+fn committed_prefix() {
+    let err = stmt.parse_peek("let x 1").unwrap_err();
+    let ErrMode::Cut(err) = err else {
+        panic!("expected a cut error, got {err:?}");
+    };
+    assert_eq!(
+        err.context().cloned().collect::<Vec<_>>(),
+        vec![
+            StrContext::Expected(StrContextValue::StringLiteral("=")),
+            StrContext::Label("Let"),
+        ]
+    );
+}
+
+#[test]
+fn expected_labels() {
+    let err = stmt.parse_peek("+").unwrap_err();
+    let ErrMode::Backtrack(err) = err else {
+        panic!("expected a backtrack error, got {err:?}");
+    };
+    assert_eq!(
+        err.context().cloned().collect::<Vec<_>>(),
+        vec![
+            StrContext::Expected(StrContextValue::StringLiteral("let")),
+            StrContext::Expected(StrContextValue::StringLiteral("print")),
+            StrContext::Expected(StrContextValue::StringLiteral(";")),
+            StrContext::Expected(StrContextValue::Description("Expr")),
+        ]
+    );
+}
+
+#[derive(Parse, Debug, PartialEq)]
+#[winnow(text)]
+enum Op {
+    #[winnow(literal = "<")]
+    Lt,
+    #[winnow(literal = "<=")]
+    Le,
+    #[winnow(literal = "<<")]
+    Shl,
+    #[winnow(literal = "=")]
+    Eq,
+}
+
+#[test]
+fn longest_literal() {
+    fn op<'i>(input: &mut &'i str) -> Result<Op, InputError<&'i str>> {
+        Op::parse_next(input)
+    }
+    assert_eq!(op.parse_peek("<= 1"), Ok((" 1", Op::Le)));
+    assert_eq!(op.parse_peek("<< 1"), Ok((" 1", Op::Shl)));
+    assert_eq!(op.parse_peek("< 1"), Ok((" 1", Op::Lt)));
+    assert_eq!(op.parse_peek("= 1"), Ok((" 1", Op::Eq)));
+    assert_eq!(op.parse_peek("!"), Err(InputError::at("!")));
+}
+
+#[derive(Parse, Debug, PartialEq)]
+#[winnow(text, keyword = "fn", skip = multispace0)]
+struct Signature<Arg> {
+    #[winnow(literal = "(")]
+    open: (),
+    #[winnow(separator = ",")]
+    args: Vec<Arg>,
+    #[winnow(literal = ")")]
+    close: (),
+    ret: Option<Ret>,
+}
+
+#[derive(Parse, Debug, PartialEq)]
+#[winnow(text, literal = "->", skip = multispace0)]
+struct Ret {
+    #[winnow(keyword = "int")]
+    int: (),
+}
+
+#[derive(Parse, Debug, PartialEq)]
+#[winnow(text)]
+enum Ty {
+    #[winnow(keyword = "int")]
+    Int,
+    #[winnow(keyword = "bool")]
+    Bool,
+}
+
+#[test]
+fn generic_struct() {
+    fn signature(input: &mut &str) -> ModalResult<Signature<Ty>> {
+        Signature::parse_next(input)
+    }
+    assert_eq!(
+        signature.parse_peek("fn (int, bool) -> int"),
+        Ok((
+            "",
+            Signature {
+                open: (),
+                args: vec![Ty::Int, Ty::Bool],
+                close: (),
+                ret: Some(Ret { int: () }),
+            }
+        ))
+    );
+    assert_eq!(
+        signature.parse_peek("fn ()"),
+        Ok((
+            "",
+            Signature {
+                open: (),
+                args: vec![],
+                close: (),
+                ret: None,
+            }
+        ))
+    );
+    assert!(matches!(
+        signature.parse_peek("fn (int"),
+        Err(ErrMode::Cut(_))
+    ));
+    assert!(matches!(
+        signature.parse_peek("fun"),
+        Err(ErrMode::Backtrack(_))
+    ));
+}
+
+#[test]
+fn partial() {
+    fn op(input: &mut Partial<&str>) -> ModalResult<Op> {
+        Op::parse_next(input)
+    }
+    assert_eq!(
+        op.parse_peek(Partial::new("")),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+    assert!(matches!(
+        op.parse_peek(Partial::new("<")),
+        Err(ErrMode::Incomplete(_))
+    ));
+    assert_eq!(
+        op.parse_peek(Partial::new("<1")),
+        Ok((Partial::new("1"), Op::Lt))
+    );
+}